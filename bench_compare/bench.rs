@@ -1,22 +1,39 @@
 extern crate wana_kana;
-use wana_kana::to_hiragana::to_hiragana;
-use wana_kana::to_katakana::to_katakana;
-use wana_kana::to_romaji::to_romaji;
-use wana_kana::to_kana::*;
+use std::hint::black_box;
 
+use wana_kana::{ConvertJapanese, IsJapaneseStr};
+
+const ASCII: &str = "Watashi wa kyou, Toukyou eki de tomodachi to atte, keeki wo tabemashita.";
+const HIRAGANA: &str = "わたしはきょうとうきょうえきでともだちとあってけえきをたべました";
+
+/// Runs `f` in a loop and prints the elapsed milliseconds
+fn measure(name: &str, loops: usize, f: impl Fn()) {
+    let start = std::time::Instant::now();
+    for _ in 0..loops {
+        f();
+    }
+    println!("{:<24} {:>10.2}", name, start.elapsed().as_nanos() as f32/1_000_000_f32);
+}
 
 fn main() {
     let start = std::time::Instant::now();
     for _ in 0..1000 {
-        to_kana("aiueosashisusesonaninunenokakikukeko");
-        to_kana("AIUEOSASHISUSESONANINUNENOKAKIKUKEKO");
-        to_hiragana("aiueosashisusesonaninunenokakikukeko");
-        to_hiragana("アイウエオサシスセソナニヌネノカキクケコ");
-        to_katakana("aiueosashisusesonaninunenokakikukeko");
-        to_katakana("あいうえおさしすせそなにぬねのかきくけこ");
-        to_romaji("あいうえおさしすせそなにぬねのかきくけこ");
-        to_romaji("アイウエオサシスセソナニヌネノカキクケコ");
+        "aiueosashisusesonaninunenokakikukeko".to_kana();
+        "AIUEOSASHISUSESONANINUNENOKAKIKUKEKO".to_kana();
+        "aiueosashisusesonaninunenokakikukeko".to_hiragana();
+        "アイウエオサシスセソナニヌネノカキクケコ".to_hiragana();
+        "aiueosashisusesonaninunenokakikukeko".to_katakana();
+        "あいうえおさしすせそなにぬねのかきくけこ".to_katakana();
+        "あいうえおさしすせそなにぬねのかきくけこ".to_romaji();
+        "アイウエオサシスセソナニヌネノカキクケコ".to_romaji();
     }
 
     println!("{:.2}", start.elapsed().as_nanos() as f32/1_000_000_f32);
-}
\ No newline at end of file
+
+    // Pure ASCII and pure hiragana input, see "Fast Paths" in the README
+    measure("is_romaji(ascii)", 1_000_000, || { black_box(black_box(ASCII).is_romaji()); });
+    measure("is_japanese(ascii)", 1_000_000, || { black_box(black_box(ASCII).is_japanese()); });
+    measure("is_japanese(hiragana)", 1_000_000, || { black_box(black_box(HIRAGANA).is_japanese()); });
+    measure("to_romaji(ascii)", 100_000, || { black_box(black_box(ASCII).to_romaji()); });
+    measure("to_romaji(hiragana)", 100_000, || { black_box(black_box(HIRAGANA).to_romaji()); });
+}
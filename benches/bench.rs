@@ -5,45 +5,88 @@ extern crate test;
 #[cfg(test)]
 mod tests {
     use test::Bencher;
-    use wana_kana::{to_kana, to_romaji, *};
+    use wana_kana::*;
+
+    const LONG_ROMAJI: &str = "Watashi wa kyou, Toukyou eki de tomodachi to atte, \
+                               CHOKORE-TO KE-KI wo tabemashita. Sorekara shinkansen de \
+                               Oosaka ni ikimashita. Totemo tanoshikatta desu!";
+
+    const LONG_KANA: &str = "わたしは きょう、とうきょうえきで ともだちと あって、\
+                             チョコレート ケーキを たべました。それから しんかんせんで \
+                             おおさかに いきました。とても たのしかった です！";
+
+    #[bench]
+    fn bench_kana_1(b: &mut Bencher) {
+        b.iter(|| "aiueosashisusesonaninunenokakikukeko".to_kana())
+    }
 
     #[bench]
-    fn bench_kana_1(b: &mut test::Bencher) {
-        b.iter(|| to_kana::to_kana("aiueosashisusesonaninunenokakikukeko"))
+    fn bench_kana_2(b: &mut Bencher) {
+        b.iter(|| "AIUEOSASHISUSESONANINUNENOKAKIKUKEKO".to_kana())
     }
 
     #[bench]
-    fn bench_kana_2(b: &mut test::Bencher) {
-        b.iter(|| to_kana::to_kana("AIUEOSASHISUSESONANINUNENOKAKIKUKEKO"))
+    fn bench_kana_long(b: &mut Bencher) {
+        b.iter(|| LONG_ROMAJI.to_kana())
     }
 
     #[bench]
-    fn bench_romaji_to_hiragana(b: &mut test::Bencher) {
+    fn bench_kana_imemode(b: &mut Bencher) {
+        b.iter(|| {
+            LONG_ROMAJI.to_kana_with_opt(Options {
+                imemode: ImeMode::ToHiragana,
+                ..Default::default()
+            })
+        })
+    }
+
+    #[bench]
+    fn bench_romaji_to_hiragana(b: &mut Bencher) {
         b.iter(|| "aiueosashisusesonaninunenokakikukeko".to_hiragana())
     }
 
     #[bench]
-    fn bench_katakana_to_hiragana(b: &mut test::Bencher) {
+    fn bench_katakana_to_hiragana(b: &mut Bencher) {
         b.iter(|| "アイウエオサシスセソナニヌネノカキクケコ".to_hiragana())
     }
 
     #[bench]
-    fn bench_romaji_to_katakana(b: &mut test::Bencher) {
+    fn bench_romaji_to_katakana(b: &mut Bencher) {
         b.iter(|| "aiueosashisusesonaninunenokakikukeko".to_katakana())
     }
 
     #[bench]
-    fn bench_katakana_to_katakana(b: &mut test::Bencher) {
+    fn bench_katakana_to_katakana(b: &mut Bencher) {
         b.iter(|| "あいうえおさしすせそなにぬねのかきくけこ".to_katakana())
     }
 
     #[bench]
-    fn bench_hiragana_to_romaji(b: &mut test::Bencher) {
-        b.iter(|| to_romaji::to_romaji("あいうえおさしすせそなにぬねのかきくけこ"))
+    fn bench_hiragana_to_romaji(b: &mut Bencher) {
+        b.iter(|| "あいうえおさしすせそなにぬねのかきくけこ".to_romaji())
+    }
+
+    #[bench]
+    fn bench_katakana_to_romaji(b: &mut Bencher) {
+        b.iter(|| "アイウエオサシスセソナニヌネノカキクケコ".to_romaji())
+    }
+
+    #[bench]
+    fn bench_romaji_long(b: &mut Bencher) {
+        b.iter(|| LONG_KANA.to_romaji())
+    }
+
+    #[bench]
+    fn bench_romaji_long_kunrei(b: &mut Bencher) {
+        b.iter(|| {
+            LONG_KANA.to_romaji_with_opt(Options {
+                romanization: Romanization::Kunrei,
+                ..Default::default()
+            })
+        })
     }
 
     #[bench]
-    fn bench_katakana_to_romaji(b: &mut test::Bencher) {
-        b.iter(|| to_romaji::to_romaji("アイウエオサシスセソナニヌネノカキクケコ"))
+    fn bench_ukrainian_long(b: &mut Bencher) {
+        b.iter(|| LONG_KANA.to_ukrainian())
     }
 }
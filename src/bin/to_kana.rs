@@ -1,6 +1,7 @@
 use std::env;
-use std::io::{self, Read};
+use std::io;
 
+use wana_kana::stream::KanaReader;
 use wana_kana::ConvertJapanese;
 
 fn main() {
@@ -8,10 +9,7 @@ fn main() {
     if !args.is_empty() {
         println!("{}", (&args.to_kana()));
     } else {
-        let mut buffer = String::new();
-        io::stdin().read_to_string(&mut buffer).unwrap();
-        if !buffer.is_empty() {
-            print!("{}", (&buffer.to_kana()));
-        }
+        let mut reader = KanaReader::new(io::stdin().lock());
+        io::copy(&mut reader, &mut io::stdout().lock()).unwrap();
     }
 }
@@ -1,5 +1,5 @@
 use std::env;
-use std::io::{self, Read};
+use std::io::{self, BufRead, Write};
 
 use wana_kana::ConvertJapanese;
 
@@ -8,10 +8,13 @@ fn main() {
     if !args.is_empty() {
         println!("{}", (&args).to_ukrainian());
     } else {
-        let mut buffer = String::new();
-        io::stdin().read_to_string(&mut buffer).unwrap();
-        if !buffer.is_empty() {
-            print!("{}", (&buffer).to_ukrainian());
+        // No conversion spans a line break, so the input is converted line by line
+        let mut input = io::stdin().lock();
+        let mut ouput = io::stdout().lock();
+        let mut line = String::new();
+        while input.read_line(&mut line).unwrap() != 0 {
+            ouput.write_all(line.to_ukrainian().as_bytes()).unwrap();
+            line.clear();
         }
     }
 }
@@ -19,6 +19,10 @@ pub const ZENKAKU_PUNCTUATION_4: [u32; 2] = [0xFF5B, 0xFF60];
 pub const ZENKAKU_UPPERCASE: [u32; 2] = [UPPERCASE_ZENKAKU_START, UPPERCASE_ZENKAKU_END];
 pub const ZENKAKU_LOWERCASE: [u32; 2] = [LOWERCASE_ZENKAKU_START, LOWERCASE_ZENKAKU_END];
 pub const ZENKAKU_SYMBOLS_CURRENCY: [u32; 2] = [0xFFE0, 0xFFEE];
+/// ！ to ～, the full-width forms of the ASCII chars ! to ~
+pub const ZENKAKU_ASCII: [u32; 2] = [0xFF01, 0xFF5E];
+pub const ZENKAKU_ASCII_OFFSET: u32 = ZENKAKU_ASCII[0] - 0x21;
+pub const IDEOGRAPHIC_SPACE: u32 = 0x3000;
 pub const KANA_PUNCTUATION: [u32; 2] = [0xFF61, 0xFF65];
 pub const HANKAKU_KATAKANA: [u32; 2] = [0xFF66, 0xFF9F];
 pub const COMMON_CJK: [u32; 2] = [0x4E00, 0x9FFF];
@@ -63,29 +67,48 @@ pub const KANA_RANGES: [[u32; 2]; 4] = [
     HANKAKU_KATAKANA,
 ];
 
-lazy_static! {
-    /// All Japanese unicode start and end ranges
-    /// Includes full-width punctuation and number ranges.
-    pub static ref JAPANESE_RANGES: Vec<[u32; 2]> = {
-        let mut m = vec![ZENKAKU_UPPERCASE, ZENKAKU_LOWERCASE, ZENKAKU_NUMBERS, COMMON_CJK, RARE_CJK,];
-        m.extend(KANA_RANGES);
-        m.extend(JA_PUNCTUATION_RANGES);
-        m
-    };
+/// All Japanese unicode start and end ranges
+/// Includes full-width punctuation and number ranges.
+pub const JAPANESE_RANGES: [[u32; 2]; 17] = concat_ranges(
+    concat_ranges::<5, 4, 9>(
+        [
+            ZENKAKU_UPPERCASE,
+            ZENKAKU_LOWERCASE,
+            ZENKAKU_NUMBERS,
+            COMMON_CJK,
+            RARE_CJK,
+        ],
+        KANA_RANGES,
+    ),
+    JA_PUNCTUATION_RANGES,
+);
 
-    /// Basic Latin unicode regex, for determining Romaji + Hepburn romanisation
-    /// Includes upper/lowercase long vowels like "ā, ī, ū, ē, ō"
-    pub static ref ROMAJI_RANGES: Vec<[u32; 2]> = {
-        let mut m = vec![MODERN_ENGLISH,];
-        m.extend(HEPBURN_MACRON_RANGES);
-        m
-    };
-    pub static ref EN_PUNCTUATION_RANGES: Vec<[u32; 2]> = {
-        let mut m = vec![[0x20, 0x2F], [0x3A, 0x3F], [0x5B, 0x60], [0x7B, 0x7E],];
-        m.extend(SMART_QUOTE_RANGES);
-        m
-    };
+/// Basic Latin unicode regex, for determining Romaji + Hepburn romanisation
+/// Includes upper/lowercase long vowels like "ā, ī, ū, ē, ō"
+pub const ROMAJI_RANGES: [[u32; 2]; 6] = concat_ranges([MODERN_ENGLISH], HEPBURN_MACRON_RANGES);
 
+pub const EN_PUNCTUATION_RANGES: [[u32; 2]; 6] = concat_ranges(
+    [[0x20, 0x2F], [0x3A, 0x3F], [0x5B, 0x60], [0x7B, 0x7E]],
+    SMART_QUOTE_RANGES,
+);
+
+/// Returns the ranges of `first` followed by those of `second`
+const fn concat_ranges<const A: usize, const B: usize, const N: usize>(
+    first: [[u32; 2]; A],
+    second: [[u32; 2]; B],
+) -> [[u32; 2]; N] {
+    assert!(A + B == N);
+    let mut ranges = [[0; 2]; N];
+    let mut i = 0;
+    while i < A {
+        ranges[i] = first[i];
+        i += 1;
+    }
+    while i < N {
+        ranges[i] = second[i - A];
+        i += 1;
+    }
+    ranges
 }
 
 pub const UPPERCASE_START: u32 = 0x41;
@@ -95,4 +118,23 @@ pub const HIRAGANA_END: u32 = 0x3096;
 pub const KATAKANA_START: u32 = 0x30A1;
 pub const KATAKANA_END: u32 = 0x30FC;
 pub const PROLONGED_SOUND_MARK: u32 = 0x30FC;
+pub const HANKAKU_PROLONGED_SOUND_MARK: u32 = 0xFF70;
+/// Dashes and lines used in place of the hyphen or of 'ー'
+pub const DASH_VARIANTS: [u32; 13] = [
+    0x002D, // - hyphen-minus
+    0x2010, // ‐ hyphen
+    0x2011, // ‑ non-breaking hyphen
+    0x2012, // ‒ figure dash
+    0x2013, // – en dash
+    0x2014, // — em dash
+    0x2015, // ― horizontal bar
+    0x2212, // − minus sign
+    0x2500, // ─ box drawings light horizontal
+    0x2501, // ━ box drawings heavy horizontal
+    0xFE63, // ﹣ small hyphen-minus
+    0xFF0D, // － fullwidth hyphen-minus
+    HANKAKU_PROLONGED_SOUND_MARK,
+];
+pub const WAVE_DASH: u32 = 0x301C;
+pub const ZENKAKU_TILDE: u32 = 0xFF5E;
 pub const KANA_SLASH_DOT: u32 = 0x30FB;
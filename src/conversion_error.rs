@@ -0,0 +1,131 @@
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+use crate::traits::Span;
+
+/// Why a part of the input could not be converted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// Latin letters which are not a romaji syllable, like "kq" or a trailing "ky"
+    InvalidRomaji,
+    /// Chars of another script, which the conversion passes through, like kanji
+    UnsupportedChar,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::InvalidRomaji => write!(f, "invalid romaji"),
+            ErrorKind::UnsupportedChar => write!(f, "unsupported characters"),
+        }
+    }
+}
+
+/// A part of the input which could not be converted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidSpan {
+    /// Byte range in the input
+    pub range: Range<usize>,
+    /// The text of the input in `range`
+    pub text: String,
+    /// Why the text could not be converted
+    pub kind: ErrorKind,
+}
+
+/// Error returned by the `try_*` conversions, listing every part of the input which could not be
+/// converted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversionError {
+    /// The invalid parts of the input, in order
+    pub invalid: Vec<InvalidSpan>,
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "could not convert")?;
+        for (i, span) in self.invalid.iter().enumerate() {
+            let separator = if i == 0 { "" } else { "," };
+            write!(
+                f,
+                "{} {:?} at {}..{} ({})",
+                separator, span.text, span.range.start, span.range.end, span.kind
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConversionError {}
+
+/// Returns `output`, or the error listing the segments which were passed through unchanged and
+/// for which `error_kind` returns a kind. Adjacent invalid chars of the same kind are reported
+/// as one span.
+pub(crate) fn check_spans(
+    input: &str,
+    output: String,
+    spans: &[Span],
+    error_kind: impl Fn(char) -> Option<ErrorKind>,
+) -> Result<String, ConversionError> {
+    let mut invalid: Vec<InvalidSpan> = Vec::new();
+    for (input_range, output_range) in spans {
+        let text = &input[input_range.clone()];
+        if text != &output[output_range.clone()] {
+            continue;
+        }
+        for (offset, char) in text.char_indices() {
+            let kind = match error_kind(char) {
+                Some(kind) => kind,
+                None => continue,
+            };
+            let start = input_range.start + offset;
+            let end = start + char.len_utf8();
+            match invalid.last_mut() {
+                Some(last) if last.range.end == start && last.kind == kind => {
+                    last.range.end = end;
+                    last.text.push(char);
+                }
+                _ => invalid.push(InvalidSpan {
+                    range: start..end,
+                    text: char.to_string(),
+                    kind,
+                }),
+            }
+        }
+    }
+    if invalid.is_empty() {
+        Ok(output)
+    } else {
+        Err(ConversionError { invalid })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let error = ConversionError {
+            invalid: vec![
+                InvalidSpan {
+                    range: 0..2,
+                    text: "kq".to_string(),
+                    kind: ErrorKind::InvalidRomaji,
+                },
+                InvalidSpan {
+                    range: 3..6,
+                    text: "字".to_string(),
+                    kind: ErrorKind::UnsupportedChar,
+                },
+            ],
+        };
+        assert_eq!(
+            error.to_string(),
+            "could not convert \"kq\" at 0..2 (invalid romaji), \"字\" at 3..6 (unsupported characters)"
+        );
+    }
+}
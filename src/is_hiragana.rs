@@ -1,11 +1,12 @@
 use crate::utils::is_char_hiragana::*;
+use crate::utils::voiced_sound_marks::all_kana_with_marks;
 
 /// Test if all chars of `input` are [Hiragana](https://en.wikipedia.org/wiki/Hiragana)
 pub fn is_hiragana(input: &str) -> bool {
     if input.is_empty() {
         return false;
     }
-    input.chars().all(is_char_hiragana)
+    all_kana_with_marks(input, is_char_hiragana)
 }
 
 #[test]
@@ -47,4 +48,11 @@ mod tests {
     fn ignores_long_dash_in_hiragana() {
         assert_eq!(is_hiragana("げーむ"), true);
     }
+    #[test]
+    fn accepts_voiced_sound_marks_after_hiragana() {
+        assert_eq!(is_hiragana("か\u{3099}は\u{309A}"), true);
+        assert_eq!(is_hiragana("か゛は゜"), true);
+        assert_eq!(is_hiragana("\u{3099}か"), false);
+        assert_eq!(is_hiragana("カ\u{3099}"), false);
+    }
 }
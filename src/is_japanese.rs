@@ -1,16 +1,32 @@
 #[cfg(feature = "enable_regex")]
 use regex::Regex;
 
+#[cfg(feature = "enable_regex")]
 use crate::utils::is_char_japanese::*;
+use crate::utils::utf8_ranges::{are_all_3_byte_chars_in, is_code_in_ranges};
 
 /// Test if `input` only includes [Kanji](https://en.wikipedia.org/wiki/Kanji), [Kana](https://en.wikipedia.org/wiki/Kana), zenkaku punctuation, japanese symbols and japanese numbers.
 pub fn is_japanese(input: &str) -> bool {
     if input.is_empty() {
         return false;
     }
-    input.chars().all(is_char_japanese)
+    // Every japanese range is encoded with 3 bytes in UTF-8, so any other char fails the check
+    are_all_3_byte_chars_in(input, |code| {
+        is_code_in_ranges(code, &JAPANESE_RANGES_MERGED)
+    })
 }
 
+/// JAPANESE_RANGES with adjacent and overlapping ranges merged, so fewer ranges are checked
+const JAPANESE_RANGES_MERGED: [[u32; 2]; 7] = [
+    [0x3000, 0x30FF], // CJK punctuation, hiragana and katakana
+    [0x3400, 0x4DBF], // RARE_CJK
+    [0x4E00, 0x9FFF], // COMMON_CJK
+    [0xFF01, 0xFF1F], // zenkaku punctuation and numbers
+    [0xFF21, 0xFF3F], // zenkaku uppercase and punctuation
+    [0xFF41, 0xFF9F], // zenkaku lowercase, punctuation and hankaku katakana
+    [0xFFE0, 0xFFEE], // ZENKAKU_SYMBOLS_CURRENCY
+];
+
 #[cfg(feature = "enable_regex")]
 /// Checks if all chars are in the japanese unicode ranges or match the provided regex
 pub fn is_japanese_with_whitelist(input: &str, allowed: Option<&Regex>) -> bool {
@@ -27,3 +43,25 @@ pub fn is_japanese_with_whitelist(input: &str, allowed: Option<&Regex>) -> bool
         is_jap
     })
 }
+
+#[test]
+fn merged_ranges_match_is_char_japanese() {
+    for char in (0..0x10000).filter_map(core::char::from_u32) {
+        let code = char as u32;
+        assert_eq!(
+            is_code_in_ranges(code, &JAPANESE_RANGES_MERGED),
+            crate::utils::is_char_japanese(char),
+            "{:x}",
+            code
+        );
+    }
+}
+
+#[test]
+fn check_is_japanese() {
+    assert_eq!(is_japanese("泣き虫。！〜＄"), true);
+    assert_eq!(is_japanese("ｶﾀｶﾅ　ＡＢＣ１２３"), true);
+    assert_eq!(is_japanese("泣き虫.!~$"), false);
+    assert_eq!(is_japanese("A泣き虫"), false);
+    assert_eq!(is_japanese("泣き虫ō"), false);
+}
@@ -1,11 +1,12 @@
 use crate::utils::is_char_kana::*;
+use crate::utils::voiced_sound_marks::all_kana_with_marks;
 
 /// Test if all chars of `input` are [Kana](https://en.wikipedia.org/wiki/Kana) ([Katakana](https://en.wikipedia.org/wiki/Katakana) and/or [Hiragana](https://en.wikipedia.org/wiki/Hiragana))
 pub fn is_kana(input: &str) -> bool {
     if input.is_empty() {
         return false;
     }
-    input.chars().all(is_char_kana)
+    all_kana_with_marks(input, is_char_kana)
 }
 
 #[cfg(test)]
@@ -40,4 +41,9 @@ mod tests {
     fn ignores_long_dash_in_mixed_kana() {
         assert_eq!(is_kana("アーあ"), true);
     }
+    #[test]
+    fn accepts_voiced_sound_marks_after_kana() {
+        assert_eq!(is_kana("か\u{3099}ハ\u{309A}"), true);
+        assert_eq!(is_kana("漢\u{3099}"), false);
+    }
 }
@@ -1,11 +1,12 @@
 use crate::utils::is_char_katakana::*;
+use crate::utils::voiced_sound_marks::all_kana_with_marks;
 
 /// Test if all chars of `input` are [Katakana](https://en.wikipedia.org/wiki/Katakana)
 pub fn is_katakana(input: &str) -> bool {
     if input.is_empty() {
         return false;
     }
-    input.chars().all(is_char_katakana)
+    all_kana_with_marks(input, is_char_katakana)
 }
 
 #[cfg(test)]
@@ -40,4 +41,11 @@ mod tests {
     fn ignores_long_dash_in_katakana() {
         assert_eq!(is_katakana("ゲーム"), true);
     }
+    #[test]
+    fn accepts_voiced_sound_marks_after_katakana() {
+        assert_eq!(is_katakana("カ\u{3099}ハ\u{309A}"), true);
+        assert_eq!(is_katakana("カ゛"), true);
+        assert_eq!(is_katakana("\u{3099}"), false);
+        assert_eq!(is_katakana("か\u{3099}"), false);
+    }
 }
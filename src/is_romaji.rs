@@ -1,6 +1,8 @@
 #[cfg(feature = "enable_regex")]
 use regex::Regex;
 
+use crate::options::Options;
+use crate::to_halfwidth::halfwidth_romaji_char;
 use crate::utils::is_char_romaji::*;
 
 /// Test if every char in `input` is [Romaji](https://en.wikipedia.org/wiki/Romaji) (allowing [Hepburn romanisation](https://en.wikipedia.org/wiki/Hepburn_romanization))
@@ -8,7 +10,20 @@ pub fn is_romaji(input: &str) -> bool {
     if input.is_empty() {
         return false;
     }
-    input.chars().all(is_char_romaji)
+    // All of ASCII is romaji, and checking bytes is faster than decoding chars
+    input.is_ascii() || input.chars().all(is_char_romaji)
+}
+
+/// Test if every char in `input` is [Romaji](https://en.wikipedia.org/wiki/Romaji), reading
+/// full-width letters and punctuation as romaji with the `fullwidth_romaji` option
+pub fn is_romaji_with_opt(input: &str, options: Options) -> bool {
+    if !options.fullwidth_romaji {
+        return is_romaji(input);
+    }
+    !input.is_empty()
+        && input
+            .chars()
+            .all(|char| is_char_romaji(halfwidth_romaji_char(char)))
 }
 
 #[cfg(feature = "enable_regex")]
@@ -90,6 +105,25 @@ mod tests {
         assert_eq!(is_romaji("ｈｅｌｌｏ"), false);
     }
 
+    #[test]
+    fn passes_zenkaku_latin_with_fullwidth_romaji() {
+        let options = Options {
+            fullwidth_romaji: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            is_romaji_with_opt("ｈｅｌｌｏ　ＷＯＲＬＤ！", options.clone()),
+            true
+        );
+        assert_eq!(
+            is_romaji_with_opt("ｋａｎａ and kana", options.clone()),
+            true
+        );
+        assert_eq!(is_romaji_with_opt("ｈｅｌｌｏ", Options::default()), false);
+        assert_eq!(is_romaji_with_opt("ｋａｎａかな", options.clone()), false);
+        assert_eq!(is_romaji_with_opt("", options), false);
+    }
+
     #[cfg(feature = "enable_regex")]
     #[test]
     fn accepts_optional_allowed_chars() {
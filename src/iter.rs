@@ -0,0 +1,479 @@
+//! Lazy conversions of char iterators.
+//!
+//! The adapters of [`ConvertJapaneseIter`] convert chars as they are pulled from the input. They
+//! only look ahead as far as the longest syllable needs, so they can be chained into other
+//! iterator pipelines without collecting the input into a string.
+//!
+//! # Examples
+//! ```
+//! use wana_kana::iter::ConvertJapaneseIter;
+//! let kana: String = "wanakana".chars().kana().collect();
+//! assert_eq!(kana, "わなかな");
+//! let romaji: String = "ワニカニ　ガ　スゴイ".chars().romaji().collect();
+//! assert_eq!(romaji, "wanikani ga sugoi");
+//! let first: String = "ka".chars().cycle().kana().take(3).collect();
+//! assert_eq!(first, "かかか");
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::iter::Fuse;
+
+use crate::options::{CustomMapping, Options, Romanization};
+use crate::to_kana::{kana_tree, push_next_kana};
+use crate::to_kana_node_tree::Node as KanaNode;
+use crate::to_romaji::{push_next_romaji, romaji_tree};
+use crate::to_romaji_node_tree::Node as RomajiNode;
+use crate::utils::custom_mapping::to_hiragana_keys;
+use crate::utils::hankaku_katakana_to_zenkaku::HankakuKatakanaToZenkaku;
+use crate::utils::is_char_long_dash::is_char_long_dash;
+use crate::utils::katakana_to_hiragana::{shift_katakana, KatakanaToHiragana};
+
+/// Adds lazy conversions to every iterator of chars.
+pub trait ConvertJapaneseIter: Iterator<Item = char> + Sized {
+    /// Converts the chars like [`to_kana`](crate::ConvertJapanese::to_kana)
+    fn kana(self) -> Kana<Self> {
+        self.kana_with_opt(Options::default())
+    }
+
+    /// Converts the chars like [`to_kana_with_opt`](crate::ConvertJapanese::to_kana_with_opt)
+    fn kana_with_opt(self, options: Options) -> Kana<Self>;
+
+    /// Converts the chars like [`to_romaji`](crate::ConvertJapanese::to_romaji)
+    fn romaji(self) -> Romaji<Self> {
+        self.romaji_with_opt(Options::default())
+    }
+
+    /// Converts the chars like [`to_romaji_with_opt`](crate::ConvertJapanese::to_romaji_with_opt)
+    fn romaji_with_opt(self, options: Options) -> Romaji<Self>;
+}
+
+impl<I: Iterator<Item = char>> ConvertJapaneseIter for I {
+    fn kana_with_opt(self, options: Options) -> Kana<Self> {
+        Kana::new(self, options)
+    }
+
+    fn romaji_with_opt(self, options: Options) -> Romaji<Self> {
+        Romaji::new(self, options)
+    }
+}
+
+/// Iterator converting romaji to kana, created by
+/// [`ConvertJapaneseIter::kana`](ConvertJapaneseIter::kana).
+#[derive(Debug, Clone)]
+pub struct Kana<I> {
+    input: Fuse<I>,
+    converter: KanaConverter,
+    ouput: String,
+    /// Byte position of the next char to return from `ouput`
+    ouput_pos: usize,
+}
+
+impl<I: Iterator<Item = char>> Kana<I> {
+    fn new(input: I, options: Options) -> Self {
+        Kana {
+            input: input.fuse(),
+            converter: KanaConverter::new(options),
+            ouput: String::new(),
+            ouput_pos: 0,
+        }
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for Kana<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        next_char(
+            &mut self.input,
+            &mut self.converter,
+            &mut self.ouput,
+            &mut self.ouput_pos,
+        )
+    }
+}
+
+/// Iterator converting kana to romaji, created by
+/// [`ConvertJapaneseIter::romaji`](ConvertJapaneseIter::romaji).
+///
+/// A run of 'ー' lengthens one vowel however long it is, so the lookahead grows with it.
+#[derive(Debug, Clone)]
+pub struct Romaji<I> {
+    input: Fuse<I>,
+    converter: RomajiConverter,
+    ouput: String,
+    /// Byte position of the next char to return from `ouput`
+    ouput_pos: usize,
+}
+
+impl<I: Iterator<Item = char>> Romaji<I> {
+    fn new(input: I, options: Options) -> Self {
+        Romaji {
+            input: input.fuse(),
+            converter: RomajiConverter::new(options),
+            ouput: String::new(),
+            ouput_pos: 0,
+        }
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for Romaji<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        next_char(
+            &mut self.input,
+            &mut self.converter,
+            &mut self.ouput,
+            &mut self.ouput_pos,
+        )
+    }
+}
+
+/// Returns the next char of `ouput`, converting more of `input` when all of it was returned
+fn next_char(
+    input: &mut impl Iterator<Item = char>,
+    converter: &mut impl Converter,
+    ouput: &mut String,
+    ouput_pos: &mut usize,
+) -> Option<char> {
+    loop {
+        if let Some(char) = ouput[*ouput_pos..].chars().next() {
+            *ouput_pos += char.len_utf8();
+            return Some(char);
+        }
+        ouput.clear();
+        *ouput_pos = 0;
+
+        while converter.needs_input() {
+            match input.next() {
+                Some(char) => converter.push(char),
+                None => break,
+            }
+        }
+        if !converter.convert_next(ouput) {
+            return None;
+        }
+    }
+}
+
+/// Converts chars which are pushed one by one, holding back as many as are needed to find the
+/// longest match.
+pub(crate) trait Converter {
+    /// Returns true if more chars are needed before the next syllable can be converted, unless the
+    /// input ends
+    fn needs_input(&self) -> bool;
+
+    /// Adds the next char of the input
+    fn push(&mut self, char: char);
+
+    /// Converts the next syllable of the pushed chars and appends it to `ouput`. Returns false if
+    /// there was nothing to convert. Unless the input ended, it is only called when no more input
+    /// is needed.
+    fn convert_next(&mut self, ouput: &mut String) -> bool;
+}
+
+/// Converts romaji to kana like to_kana()
+#[derive(Debug, Clone)]
+pub(crate) struct KanaConverter {
+    options: Options,
+    tree: KanaNode,
+    /// Number of chars needed to find the longest match
+    lookahead: usize,
+    window: Vec<char>,
+    /// The last char of the output
+    previous: Option<char>,
+}
+
+impl KanaConverter {
+    pub(crate) fn new(options: Options) -> Self {
+        let tree = kana_tree(&options);
+        let lookahead = tree
+            .depth()
+            .max(longest_key(&options.custom_kana_mapping))
+            .max(1);
+        KanaConverter {
+            options,
+            tree,
+            lookahead,
+            window: Vec::with_capacity(lookahead),
+            previous: None,
+        }
+    }
+}
+
+impl Converter for KanaConverter {
+    fn needs_input(&self) -> bool {
+        self.window.len() < self.lookahead
+    }
+
+    fn push(&mut self, char: char) {
+        self.window.push(char);
+    }
+
+    fn convert_next(&mut self, ouput: &mut String) -> bool {
+        if self.window.is_empty() {
+            return false;
+        }
+        let output_start = ouput.len();
+        let len = push_next_kana(
+            &self.tree,
+            &self.options,
+            &self.window,
+            self.previous,
+            ouput,
+        );
+        self.window.drain(..len);
+        self.previous = ouput[output_start..].chars().last().or(self.previous);
+        true
+    }
+}
+
+/// Converts kana to romaji like to_romaji()
+#[derive(Debug, Clone)]
+pub(crate) struct RomajiConverter {
+    options: Options,
+    tree: &'static RomajiNode,
+    custom_mapping: CustomMapping,
+    /// Converts half-width katakana before they are added to the window
+    zenkaku: HankakuKatakanaToZenkaku,
+    /// Number of chars, not counting 'ー', needed to convert the next syllable
+    lookahead: usize,
+    /// `None` for strict romanization, which keeps 'ー'
+    katakana: Option<KatakanaToHiragana>,
+    orig_window: Vec<char>,
+    /// The hiragana of `orig_window`, char for char
+    window: Vec<char>,
+    /// Number of chars in `orig_window` which are not 'ー'
+    window_len: usize,
+}
+
+impl RomajiConverter {
+    pub(crate) fn new(options: Options) -> Self {
+        let tree = romaji_tree(options.romanization);
+        let custom_mapping = to_hiragana_keys(&options.custom_romaji_mapping);
+        // A syllable, the kana lengthening it and the syllable after it
+        let lookahead = tree.depth().max(longest_key(&custom_mapping)) * 2 + 1;
+        let katakana = if options.romanization == Romanization::Strict {
+            None
+        } else {
+            Some(KatakanaToHiragana::new(true))
+        };
+        RomajiConverter {
+            options,
+            tree,
+            custom_mapping,
+            zenkaku: HankakuKatakanaToZenkaku::default(),
+            lookahead,
+            katakana,
+            orig_window: Vec::with_capacity(lookahead),
+            window: Vec::with_capacity(lookahead),
+            window_len: 0,
+        }
+    }
+}
+
+impl Converter for RomajiConverter {
+    fn needs_input(&self) -> bool {
+        self.window_len < self.lookahead
+    }
+
+    fn push(&mut self, char: char) {
+        for char in self.zenkaku.push(char) {
+            self.push_zenkaku(char);
+        }
+    }
+
+    fn convert_next(&mut self, ouput: &mut String) -> bool {
+        // Called with more input needed only at the end, which completes a held back kana
+        if self.needs_input() {
+            if let Some(kana) = self.zenkaku.finish() {
+                self.push_zenkaku(kana);
+            }
+        }
+        if self.window.is_empty() {
+            return false;
+        }
+        let len = push_next_romaji(
+            self.tree,
+            &self.custom_mapping,
+            &self.options,
+            &self.window,
+            &self.orig_window,
+            ouput,
+        );
+        self.window.drain(..len);
+        for char in self.orig_window.drain(..len) {
+            if !is_char_long_dash(char) {
+                self.window_len -= 1;
+            }
+        }
+        true
+    }
+}
+
+impl RomajiConverter {
+    /// Adds a char of the input after half-width katakana were converted
+    fn push_zenkaku(&mut self, char: char) {
+        let hira_char = match &mut self.katakana {
+            Some(katakana) => katakana.convert(char),
+            None => shift_katakana(char),
+        };
+        self.orig_window.push(char);
+        self.window.push(hira_char);
+        if !is_char_long_dash(char) {
+            self.window_len += 1;
+        }
+    }
+}
+
+/// Returns the number of chars of the longest key of `mapping`
+fn longest_key(mapping: &CustomMapping) -> usize {
+    mapping
+        .keys()
+        .map(|key| key.chars().count())
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::options::{ImeMode, LongVowelMark, LongVowels};
+    use crate::to_kana::to_kana_with_opt;
+    use crate::to_romaji::to_romaji_with_opt;
+    use crate::utils::custom_mapping::mapping;
+
+    const ROMAJI: &[&str] = &[
+        "",
+        "wanakana",
+        "onaji BUTTSUUJI",
+        "kyouky",
+        "shinbun n",
+        "kitte tte",
+        "KŌHĪ tōkyō",
+        "a-ka-",
+        "座禅‘zazen’スタイル",
+        "wi we wo",
+        "chotto matte kudasai!",
+        "ｗａｎａｋａｎａ　ＫＡＮＡ－",
+    ];
+
+    const KANA: &[&str] = &[
+        "",
+        "ワニカニ　ガ　スゴイ　ダ",
+        "わにかに　が　すごい　だ",
+        "とうきょう おおさか",
+        "スーーーーパー",
+        "ーあ",
+        "きんえん しんよう てんいん",
+        "しんぶん さんま さんぽ",
+        "ヷヸヹヺー",
+        "ンー ッー",
+        "ゃっ きゃっきゃ",
+        "漢字とカナ",
+        "オーサカ",
+        "ｶﾞｯｺｳ ｽｰﾊﾟｰ ｲｶ",
+    ];
+
+    fn kana_options() -> Vec<Options> {
+        vec![
+            Options::default(),
+            Options {
+                use_obsolete_kana: true,
+                ..Default::default()
+            },
+            Options {
+                imemode: ImeMode::On,
+                ..Default::default()
+            },
+            Options {
+                imemode: ImeMode::ToKatakana,
+                long_vowel_mark: LongVowelMark::Vowel,
+                ..Default::default()
+            },
+            Options {
+                custom_kana_mapping: mapping(&[("na", "に"), ("ka", "ば"), ("tsuu", "")]),
+                ..Default::default()
+            },
+            Options {
+                fullwidth_romaji: true,
+                ..Default::default()
+            },
+        ]
+    }
+
+    fn romaji_options() -> Vec<Options> {
+        let mut options = vec![
+            Options {
+                upcase_katakana: true,
+                ..Default::default()
+            },
+            Options {
+                long_vowels: LongVowels::Macron,
+                ..Default::default()
+            },
+            Options {
+                custom_romaji_mapping: mapping(&[("じ", "zi"), ("きょう", "kyo")]),
+                ..Default::default()
+            },
+        ];
+        for romanization in [
+            Romanization::Hepburn,
+            Romanization::Kunrei,
+            Romanization::Nihon,
+            Romanization::Passport,
+            Romanization::Strict,
+        ] {
+            options.push(Options {
+                romanization,
+                ..Default::default()
+            });
+        }
+        options
+    }
+
+    #[test]
+    fn kana_matches_to_kana() {
+        for options in kana_options() {
+            for input in ROMAJI {
+                let lazy: String = input.chars().kana_with_opt(options.clone()).collect();
+                assert_eq!(lazy, to_kana_with_opt(input, options.clone()), "{}", input);
+            }
+        }
+    }
+
+    #[test]
+    fn romaji_matches_to_romaji() {
+        for options in romaji_options() {
+            for input in KANA {
+                let lazy: String = input.chars().romaji_with_opt(options.clone()).collect();
+                assert_eq!(
+                    lazy,
+                    to_romaji_with_opt(input, options.clone()),
+                    "{}",
+                    input
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn converts_endless_input() {
+        let kana: String = "kya".chars().cycle().kana().take(4).collect();
+        assert_eq!(kana, "きゃきゃ");
+        let romaji: String = "シャ".chars().cycle().romaji().take(6).collect();
+        assert_eq!(romaji, "shasha");
+    }
+
+    #[test]
+    fn chains_with_other_adapters() {
+        let romaji: String = "wanakana"
+            .chars()
+            .kana()
+            .filter(|c| *c != 'な')
+            .romaji()
+            .collect();
+        assert_eq!(romaji, "waka");
+    }
+}
@@ -0,0 +1,324 @@
+//! Incremental romaji to kana conversion for text input, one keystroke at a time.
+//!
+//! The [`KanaComposer`] converts the romaji typed so far as soon as the kana is known, and keeps
+//! romaji which may still change in a pending buffer ("ky" waits for a vowel, "n" for the next
+//! key).
+//!
+//! # Examples
+//! ```
+//! use wana_kana::kana_composer::KanaComposer;
+//! let mut composer = KanaComposer::new();
+//! composer.push_str("kyouky");
+//! assert_eq!(composer.committed(), "きょう");
+//! assert_eq!(composer.pending(), "ky");
+//! assert_eq!(composer.text(), "きょうky");
+//! assert_eq!(composer.pending_range(), 9..11);
+//! composer.push('o');
+//! assert_eq!(composer.text(), "きょうきょ");
+//! assert_eq!(composer.backspace(), Some('ょ'));
+//! assert_eq!(composer.take(), "きょうき");
+//! ```
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::options::Options;
+use crate::to_kana::{next_match, push_next_kana};
+use crate::to_kana_node_tree::{
+    Node, TO_KANA_NODE_TREE, TO_KANA_NODE_TREE_IMEMODE, TO_KANA_NODE_TREE_OBSOLETE,
+};
+use crate::utils::custom_mapping::is_custom_mapping_prefix;
+
+/// Converts keystrokes to kana, keeping romaji which may still change in a pending buffer.
+///
+/// Lowercase input results in hiragana and uppercase input in katakana, as with to_kana(). Like
+/// an IME, "nn" is ん and a single "n" waits for the next key.
+#[derive(Debug, Default, Clone)]
+pub struct KanaComposer {
+    options: Options,
+    committed: String,
+    pending: String,
+}
+
+impl KanaComposer {
+    /// Creates an empty composer with the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty composer which converts with `options`. With `imemode` set to
+    /// `ToHiragana` or `ToKatakana` the kana are hiragana or katakana whatever the case.
+    pub fn with_opt(options: Options) -> Self {
+        KanaComposer {
+            options,
+            ..Default::default()
+        }
+    }
+
+    /// Adds a typed char and converts everything which can't change anymore
+    pub fn push(&mut self, key: char) {
+        self.pending.push(key);
+        self.resolve();
+    }
+
+    /// Adds typed chars, as if they were pushed one by one
+    pub fn push_str(&mut self, keys: &str) {
+        for key in keys.chars() {
+            self.push(key);
+        }
+    }
+
+    /// Removes the last char, a romaji char of the pending buffer if there is one, otherwise the
+    /// last committed kana. Returns the removed char.
+    pub fn backspace(&mut self) -> Option<char> {
+        self.pending.pop().or_else(|| self.committed.pop())
+    }
+
+    /// Converts the pending buffer as it is, like a trailing "n" to ん
+    pub fn flush(&mut self) {
+        let chars = self.pending.chars().collect::<Vec<_>>();
+        let mut pos = 0;
+        while pos != chars.len() {
+            pos += self.commit_next(&chars[pos..]);
+        }
+        self.pending.clear();
+    }
+
+    /// Flushes the pending buffer and returns the whole text, leaving the composer empty
+    pub fn take(&mut self) -> String {
+        self.flush();
+        core::mem::take(&mut self.committed)
+    }
+
+    /// Removes everything which has been typed
+    pub fn clear(&mut self) {
+        self.committed.clear();
+        self.pending.clear();
+    }
+
+    /// Returns true if nothing has been typed
+    pub fn is_empty(&self) -> bool {
+        self.committed.is_empty() && self.pending.is_empty()
+    }
+
+    /// Returns the converted kana
+    pub fn committed(&self) -> &str {
+        &self.committed
+    }
+
+    /// Returns the romaji which is waiting for more input
+    pub fn pending(&self) -> &str {
+        &self.pending
+    }
+
+    /// Returns the committed kana followed by the pending romaji, as it should be displayed
+    pub fn text(&self) -> String {
+        format!("{}{}", self.committed, self.pending)
+    }
+
+    /// Returns the byte range of the pending romaji in [`text`](KanaComposer::text), which is
+    /// usually underlined
+    pub fn pending_range(&self) -> Range<usize> {
+        self.committed.len()..self.cursor()
+    }
+
+    /// Returns the byte position of the cursor in [`text`](KanaComposer::text), which is always
+    /// at the end
+    pub fn cursor(&self) -> usize {
+        self.committed.len() + self.pending.len()
+    }
+
+    /// Moves the beginning of the pending buffer to the committed kana, up to the romaji which
+    /// could still become another kana with more input.
+    fn resolve(&mut self) {
+        let chars = self.pending.chars().collect::<Vec<_>>();
+        let trees = self.trees();
+        let mut stable_len = 0;
+        while stable_len != chars.len() {
+            let rest = &chars[stable_len..];
+            let is_incomplete = trees.iter().any(|tree| {
+                let (node, walked) = tree.walk(rest);
+                walked == rest.len() && node.has_transitions()
+            });
+            if is_incomplete || is_custom_mapping_prefix(&self.options.custom_kana_mapping, rest) {
+                break;
+            }
+            stable_len += self.commit_next(rest);
+        }
+        if stable_len != 0 {
+            self.pending = chars[stable_len..].iter().collect();
+        }
+    }
+
+    /// Converts the romaji at the start of `chars` and appends the kana to the committed kana.
+    /// Returns the number of chars converted.
+    fn commit_next(&mut self, chars: &[char]) -> usize {
+        let [tree, ime_tree] = self.trees();
+        // "nn" is one ん with the IME tree, everything else is converted like with to_kana()
+        let tree = if next_match(&ime_tree, &self.options, chars).1
+            > next_match(&tree, &self.options, chars).1
+        {
+            ime_tree
+        } else {
+            tree
+        };
+        let previous = self.committed.chars().last();
+        push_next_kana(&tree, &self.options, chars, previous, &mut self.committed)
+    }
+
+    /// Returns the tree of the options and the tree of the IME mode, which reads "nn" as ん
+    fn trees(&self) -> [Node; 2] {
+        let tree = if self.options.use_obsolete_kana {
+            TO_KANA_NODE_TREE_OBSOLETE
+        } else {
+            TO_KANA_NODE_TREE
+        };
+        [tree, TO_KANA_NODE_TREE_IMEMODE]
+            .map(|tree| tree.with_fullwidth_romaji(self.options.fullwidth_romaji))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::options::ImeMode;
+    use crate::utils::custom_mapping::mapping;
+
+    fn typed(keys: &str) -> KanaComposer {
+        let mut composer = KanaComposer::new();
+        composer.push_str(keys);
+        composer
+    }
+
+    #[test]
+    fn sane_defaults() {
+        let composer = KanaComposer::new();
+        assert!(composer.is_empty());
+        assert_eq!(composer.text(), "");
+        assert_eq!(composer.cursor(), 0);
+    }
+
+    #[test]
+    fn commits_complete_syllables() {
+        let composer = typed("wanakana");
+        assert_eq!(composer.committed(), "わなかな");
+        assert_eq!(composer.pending(), "");
+    }
+
+    #[test]
+    fn keeps_incomplete_syllables_pending() {
+        assert_eq!(typed("k").pending(), "k");
+        assert_eq!(typed("kiky").committed(), "き");
+        assert_eq!(typed("kiky").pending(), "ky");
+        assert_eq!(typed("ts").pending(), "ts");
+    }
+
+    #[test]
+    fn n_waits_for_the_next_key() {
+        assert_eq!(typed("n").pending(), "n");
+        assert_eq!(typed("na").text(), "な");
+        assert_eq!(typed("nk").text(), "んk");
+        assert_eq!(typed("n'").text(), "ん");
+        assert_eq!(typed("shinbun").text(), "しんぶn");
+        assert_eq!(typed("shinbun").take(), "しんぶん");
+    }
+
+    #[test]
+    fn nn_is_one_n() {
+        assert_eq!(typed("nn").text(), "ん");
+        assert_eq!(typed("nn").pending(), "");
+        assert_eq!(typed("kannji").text(), "かんじ");
+        assert_eq!(typed("konnnichiha").text(), "こんにちは");
+        assert_eq!(typed("KANNJI").text(), "カンジ");
+    }
+
+    #[test]
+    fn trailing_n_is_flushed_as_n() {
+        let mut composer = typed("kan");
+        assert_eq!(composer.text(), "かn");
+        assert_eq!(composer.pending(), "n");
+        assert_eq!(composer.take(), "かん");
+    }
+
+    #[test]
+    fn double_consonants() {
+        assert_eq!(typed("kk").text(), "kk");
+        assert_eq!(typed("kka").text(), "っか");
+        assert_eq!(typed("kitte").text(), "きって");
+    }
+
+    #[test]
+    fn uppercase_is_katakana() {
+        assert_eq!(typed("WANI").text(), "ワニ");
+        assert_eq!(typed("KYa").text(), "きゃ");
+    }
+
+    #[test]
+    fn passes_through_non_romaji() {
+        assert_eq!(typed("ka1, ").text(), "か1、 ");
+    }
+
+    #[test]
+    fn backspace_removes_pending_romaji_first() {
+        let mut composer = typed("kaky");
+        assert_eq!(composer.backspace(), Some('y'));
+        assert_eq!(composer.text(), "かk");
+        assert_eq!(composer.backspace(), Some('k'));
+        assert_eq!(composer.backspace(), Some('か'));
+        assert_eq!(composer.backspace(), None);
+        composer.push_str("shi");
+        assert_eq!(composer.text(), "し");
+    }
+
+    #[test]
+    fn reports_pending_range_and_cursor() {
+        let composer = typed("kaky");
+        assert_eq!(composer.pending_range(), 3..5);
+        assert_eq!(composer.cursor(), 5);
+        assert_eq!(&composer.text()[composer.pending_range()], "ky");
+    }
+
+    #[test]
+    fn take_flushes_and_empties() {
+        let mut composer = typed("kan");
+        assert_eq!(composer.take(), "かん");
+        assert!(composer.is_empty());
+        composer.push_str("ky");
+        composer.clear();
+        assert!(composer.is_empty());
+    }
+
+    #[test]
+    fn uses_options() {
+        let mut composer = KanaComposer::with_opt(Options {
+            use_obsolete_kana: true,
+            custom_kana_mapping: mapping(&[("tsi", "つぃ")]),
+            ..Default::default()
+        });
+        composer.push_str("wits");
+        assert_eq!(composer.text(), "ゐts");
+        composer.push('i');
+        assert_eq!(composer.text(), "ゐつぃ");
+    }
+
+    #[test]
+    fn uses_the_script_of_the_ime_mode() {
+        let mut composer = KanaComposer::with_opt(Options {
+            imemode: ImeMode::ToKatakana,
+            ..Default::default()
+        });
+        composer.push_str("kannji");
+        assert_eq!(composer.text(), "カンジ");
+        composer.push('n');
+        assert_eq!(composer.take(), "カンジン");
+
+        let mut composer = KanaComposer::with_opt(Options {
+            imemode: ImeMode::ToHiragana,
+            ..Default::default()
+        });
+        composer.push_str("KANA");
+        assert_eq!(composer.text(), "かな");
+    }
+}
@@ -40,16 +40,41 @@
 //! assert_eq!("勢い".contains_kanji(), true);
 //! ```
 //!
+//! # Iterators
+//!
+//! [`iter::ConvertJapaneseIter`] converts iterators of chars lazily, like
+//! `input.chars().kana()`.
+//!
+//! # Streams
+//!
+//! [`stream`] has readers and writers which convert UTF-8 streams chunk by chunk, without
+//! reading the whole input into memory.
+//!
+//! # IME
+//!
+//! [`kana_composer::KanaComposer`] converts romaji to kana while it is being typed, keeping
+//! incomplete syllables in a pending buffer.
+//!
+//! # Typing games
+//!
+//! [`typing_matcher::TypingMatcher`] checks keystrokes against a kana target, accepting every
+//! romaji spelling of it.
+//!
 //! # Tokenize
 //!
 //! [`tokenize`] Splits input into array of strings separated by opinionated TokenType.
+//!
+//! # `no_std`
+//!
+//! Without the default `std` feature the crate is `#![no_std]` and only needs `alloc`. The
+//! conversions and checks stay available, [`stream`] and the binaries need `std`.
 
 #![doc(test(attr(allow(unused_variables), deny(warnings))))]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![warn(missing_docs)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
-#[macro_use]
-extern crate lazy_static;
+extern crate alloc;
 
 #[cfg(feature = "enable_regex")]
 extern crate regex;
@@ -58,6 +83,14 @@ extern crate regex;
 #[allow(missing_docs)]
 pub mod utils;
 
+pub mod iter;
+pub mod kana_composer;
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+pub mod stream;
+pub mod typing_matcher;
+
+pub(crate) mod conversion_error;
 pub(crate) mod is_hiragana;
 pub(crate) mod is_japanese;
 pub(crate) mod is_kana;
@@ -65,7 +98,13 @@ pub(crate) mod is_kanji;
 pub(crate) mod is_katakana;
 pub(crate) mod is_mixed;
 pub(crate) mod is_romaji;
+pub(crate) mod normalize;
+pub(crate) mod round_trips;
+pub(crate) mod tables;
+pub(crate) mod trie;
 
+pub(crate) mod to_fullwidth;
+pub(crate) mod to_halfwidth;
 pub(crate) mod to_hiragana;
 pub(crate) mod to_kana;
 pub(crate) mod to_kana_node_tree;
@@ -86,10 +125,14 @@ pub mod trim_okurigana;
 pub mod constants;
 mod options;
 
-pub use crate::options::Options;
+pub use crate::conversion_error::{ConversionError, ErrorKind, InvalidSpan};
+pub use crate::options::{
+    ImeMode, LongOKana, LongVowelMark, LongVowels, NormalizeOptions, Options, Romanization,
+    WidthOptions,
+};
 
 pub mod traits;
-pub use traits::{ConvertJapanese, IsJapaneseChar, IsJapaneseStr};
+pub use traits::{ConvertJapanese, ConvertJapaneseCow, IsJapaneseChar, IsJapaneseStr, Span};
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,187 @@
+use alloc::string::String;
+
+use crate::constants::{DASH_VARIANTS, PROLONGED_SOUND_MARK, WAVE_DASH, ZENKAKU_TILDE};
+use crate::options::NormalizeOptions;
+use crate::to_halfwidth::ascii_char;
+use crate::utils::hankaku_katakana_to_zenkaku::{
+    composed_char_indices, hankaku_to_zenkaku, zenkaku_char_indices,
+};
+use crate::utils::is_char_kana::is_char_kana;
+use crate::utils::is_char_long_dash::is_char_long_dash;
+use crate::utils::katakana_to_hiragana::{is_kana_as_symbol, shift_katakana};
+
+/// Normalize Japanese text for searching, so that spellings which only differ in script, width
+/// or dashes become the same
+pub fn normalize(input: &str) -> String {
+    normalize_with_opt(input, NormalizeOptions::default())
+}
+
+/// Normalize Japanese text with the steps set in `options`
+pub fn normalize_with_opt(input: &str, options: NormalizeOptions) -> String {
+    let mut ouput = String::with_capacity(input.len());
+    let mut previous = None;
+    let mut push = |char: char| {
+        let char = normalize_char(char, previous, options);
+        previous = Some(char);
+        if !(options.remove_long_vowel_mark && is_char_long_dash(char)) {
+            ouput.push(char);
+        }
+    };
+    // Kana and their voiced sound marks are always composed
+    if options.fold_width {
+        zenkaku_char_indices(input).for_each(|(_, char)| push(char));
+    } else {
+        composed_char_indices(input).for_each(|(_, char)| push(char));
+    }
+    ouput
+}
+
+/// Applies the steps of `options` to `char`, `previous` is the char normalized before it
+fn normalize_char(char: char, previous: Option<char>, options: NormalizeOptions) -> char {
+    let code = char as u32;
+    let char = if options.unify_dashes && DASH_VARIANTS.contains(&code) {
+        // Half-width kana are only left without fold_width
+        let is_after_kana = previous.is_some_and(|previous| {
+            is_char_kana(previous) || hankaku_to_zenkaku(previous).is_some()
+        });
+        if is_after_kana {
+            core::char::from_u32(PROLONGED_SOUND_MARK).unwrap()
+        } else {
+            '-'
+        }
+    } else if options.unify_wave_dashes && code == ZENKAKU_TILDE {
+        core::char::from_u32(WAVE_DASH).unwrap()
+    } else if options.fold_width {
+        ascii_char(char).unwrap_or(char)
+    } else {
+        char
+    };
+    let char = if options.expand_small_ka && is_kana_as_symbol(char) {
+        'カ'
+    } else {
+        char
+    };
+    if options.fold_kana {
+        shift_katakana(char)
+    } else {
+        char
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn only(step: impl Fn(&mut NormalizeOptions)) -> NormalizeOptions {
+        let mut options = NormalizeOptions {
+            fold_kana: false,
+            fold_width: false,
+            unify_dashes: false,
+            unify_wave_dashes: false,
+            remove_long_vowel_mark: false,
+            expand_small_ka: false,
+        };
+        step(&mut options);
+        options
+    }
+
+    #[test]
+    fn sane_defaults() {
+        assert_eq!(normalize(""), "");
+        assert_eq!(normalize("漢字 and ascii"), "漢字 and ascii");
+    }
+
+    #[test]
+    fn applies_every_step_by_default() {
+        assert_eq!(
+            normalize("スーパー ｽｰﾊﾟｰ すーぱー"),
+            "すーぱー すーぱー すーぱー"
+        );
+        assert_eq!(normalize("ラ−メン　ＡＢＣ－１２３"), "らーめん ABC-123");
+        assert_eq!(normalize("東京～大阪 一ヶ月"), "東京〜大阪 一か月");
+    }
+
+    #[test]
+    fn index_and_query_spellings_match() {
+        let spellings = ["コーヒー", "ｺｰﾋｰ", "こーひー", "コ―ヒ―", "コ－ヒ－", "ｺ-ﾋ-"];
+        for spelling in spellings {
+            assert_eq!(normalize(spelling), "こーひー", "{}", spelling);
+        }
+    }
+
+    #[test]
+    fn composes_voiced_sound_marks() {
+        assert_eq!(normalize("ハ\u{309A}ン カ゛"), "ぱん が");
+        let options = only(|_| {});
+        assert_eq!(normalize_with_opt("か\u{3099}ｶﾞ", options), "がｶﾞ");
+    }
+
+    #[test]
+    fn fold_kana() {
+        let options = only(|o| o.fold_kana = true);
+        assert_eq!(
+            normalize_with_opt("カタカナ ヴ ー", options),
+            "かたかな ゔ ー"
+        );
+        assert_eq!(normalize_with_opt("ｶﾀｶﾅ ヶ", options), "ｶﾀｶﾅ ヶ");
+    }
+
+    #[test]
+    fn fold_width() {
+        let options = only(|o| o.fold_width = true);
+        assert_eq!(
+            normalize_with_opt("ｶﾞｯｺｳ　ＡＢＣ！～ かな", options),
+            "ガッコウ ABC!~ かな"
+        );
+    }
+
+    #[test]
+    fn unify_dashes() {
+        let options = only(|o| o.unify_dashes = true);
+        for dash in [
+            '‐', '‑', '‒', '–', '—', '―', '−', '─', '━', '﹣', '－', 'ｰ', '-',
+        ] {
+            let text = format!("ラ{}メン 03{}12", dash, dash);
+            assert_eq!(normalize_with_opt(&text, options), "ラーメン 03-12");
+        }
+        assert_eq!(normalize_with_opt("ｽ-ﾊﾟ-", options), "ｽーﾊﾟー");
+        assert_eq!(normalize_with_opt("ー-", options), "ーー");
+    }
+
+    #[test]
+    fn unify_wave_dashes() {
+        let options = only(|o| o.unify_wave_dashes = true);
+        assert_eq!(normalize_with_opt("1～2〜3~", options), "1〜2〜3~");
+        let with_width = NormalizeOptions {
+            unify_wave_dashes: false,
+            ..Default::default()
+        };
+        assert_eq!(normalize_with_opt("1～2〜3", with_width), "1~2〜3");
+    }
+
+    #[test]
+    fn remove_long_vowel_mark() {
+        let options = only(|o| o.remove_long_vowel_mark = true);
+        assert_eq!(
+            normalize_with_opt("スーパー ラ−メン", options),
+            "スパ ラ−メン"
+        );
+        let with_dashes = NormalizeOptions {
+            remove_long_vowel_mark: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            normalize_with_opt("ｽｰﾊﾟｰ－ﾏｰｹｯﾄ　ＡＢＣ〜", with_dashes),
+            "すぱまけっと ABC〜"
+        );
+    }
+
+    #[test]
+    fn expand_small_ka() {
+        let options = only(|o| o.expand_small_ka = true);
+        assert_eq!(
+            normalize_with_opt("一ヶ月 霞ヵ関", options),
+            "一カ月 霞カ関"
+        );
+    }
+}
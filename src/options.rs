@@ -1,3 +1,9 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+
+/// The map of the custom mappings. A `BTreeMap`, so its type is the same with and without `std`
+pub(crate) type CustomMapping = BTreeMap<String, String>;
+
 #[derive(Debug, Default, Clone)]
 /// Options to set.
 pub struct Options {
@@ -13,6 +19,240 @@ pub struct Options {
     /// to_romaji('ひらがな カタカナ', { upcaseKatakana: true })
     pub upcase_katakana: bool,
 
-    /// Set to true to handle conversion while it is being typed
-    pub imemode: bool,
+    /// Handle conversion while it is being typed, and optionally force the script of to_kana()
+    /// to_kana('shin', { imemode: ImeMode::On })
+    /// => "しn"
+    /// to_kana('KYOU', { imemode: ImeMode::ToHiragana })
+    /// => "きょう"
+    pub imemode: ImeMode,
+
+    /// The romanization system used by to_romaji()
+    /// to_romaji('しんぶん', { romanization: Romanization::Kunrei })
+    /// => "sinbun"
+    pub romanization: Romanization,
+
+    /// How to_romaji() spells long vowels, such as おう, うう or 'ー'
+    /// to_romaji('とうきょう', { long_vowels: LongVowels::Macron })
+    /// => "tōkyō"
+    pub long_vowels: LongVowels,
+
+    /// Kana written by to_kana() for ō and ô, when they are converted to hiragana
+    /// to_kana('tōkyō', { long_o_kana: LongOKana::Oo })
+    /// => "とおきょお"
+    pub long_o_kana: LongOKana,
+
+    /// What to_kana() writes for "-" after hiragana (convertLongVowelMark upstream)
+    /// to_kana('batsuge-mu', { long_vowel_mark: LongVowelMark::Vowel })
+    /// => "ばつげえむ"
+    pub long_vowel_mark: LongVowelMark,
+
+    /// Custom romaji to kana rules used by to_kana(), to_hiragana() and to_katakana(). They are
+    /// merged into the default rules, the longest match wins and custom rules win on ties.
+    /// to_kana('wanakana', { custom_kana_mapping: { na: 'に', ka: 'bana' } })
+    /// => "わにbanaに"
+    pub custom_kana_mapping: CustomMapping,
+
+    /// Custom kana to romaji rules used by to_romaji() and to_ukrainian(). Keys may span several
+    /// kana, the longest match wins and custom rules win on ties with the built-in ones.
+    /// to_romaji('つじぎり', { custom_romaji_mapping: { じ: 'zi', つ: 'tu', り: 'li' } })
+    /// => "tuzigili"
+    pub custom_romaji_mapping: CustomMapping,
+
+    /// Set to true to write half-width katakana with to_katakana(), voiced kana are followed
+    /// by ﾞ or ﾟ. Katakana without half-width form, such as ヶ, stay full-width.
+    /// to_katakana('ぱん、こーひー', { halfwidth_katakana: true })
+    /// => "ﾊﾟﾝ､ｺｰﾋｰ"
+    pub halfwidth_katakana: bool,
+
+    /// Set to true to read full-width letters and punctuation as romaji with to_kana() and
+    /// is_romaji_with_opt(), as typed with the IME in full-width alphanumeric mode. Uppercase
+    /// letters still become katakana, the chars which are not romaji are passed through as they
+    /// are.
+    /// to_kana('ｋａｎａ　ＫＡＮＡ', { fullwidth_romaji: true })
+    /// => "かな　カナ"
+    pub fullwidth_romaji: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+/// Conversion of romaji while it is being typed, as in an IME.
+///
+/// Every mode but `Off` keeps a single "n" as it is, as it may be the start of a syllable like な.
+/// "nn", "n'" and "n " are converted to ん.
+pub enum ImeMode {
+    /// Convert the whole input at once
+    #[default]
+    Off,
+    /// Lowercase romaji becomes hiragana, uppercase romaji katakana
+    On,
+    /// All romaji becomes hiragana, whatever the case
+    ToHiragana,
+    /// All romaji becomes katakana, whatever the case
+    ToKatakana,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+/// Romanization system used when converting kana to romaji.
+pub enum Romanization {
+    /// [Hepburn](https://en.wikipedia.org/wiki/Hepburn_romanization) (shi, tsu, fu, ja)
+    #[default]
+    Hepburn,
+    /// [Kunrei-shiki](https://en.wikipedia.org/wiki/Kunrei-shiki_romanization) (si, tu, hu, zya)
+    Kunrei,
+    /// [Nihon-shiki](https://en.wikipedia.org/wiki/Nihon-shiki_romanization) (si, tu, di, du, wo, kwa)
+    Nihon,
+    /// Passport Hepburn, as used for names in Japanese passports: long vowels are dropped, except
+    /// for "oh" at the end of a name, ん is written "m" before b, m and p, and without apostrophe
+    /// (さとう => satoh, おおの => ono, なんば => namba, じゅんいち => junichi)
+    Passport,
+    /// Reversible romanization based on ISO 3602 strict (Nihon-shiki). Every kana is kept
+    /// apart, so the output converts back to the same kana with to_kana() (ぢ => di, づ => du,
+    /// を => wo, ぁ => xa, ー => -). ゐ and ゑ (wi, we) need `use_obsolete_kana` on the way back.
+    /// ヷ, ヸ, ヹ and ヺ are kept as they are, since va, vi, ve and vo are read as ヴァ, ヴィ, ヴェ
+    /// and ヴォ.
+    Strict,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+/// Spelling of long vowels when converting kana to romaji.
+///
+/// ああ, うう, ええ, おう, おお and a vowel followed by 'ー' are considered long vowels.
+/// いい is only lengthened when written with 'ー', as in modified Hepburn.
+pub enum LongVowels {
+    /// Spell the kana as they are typed on a keyboard (とうきょう => toukyou, スーパー => suupaa)
+    #[default]
+    Wapuro,
+    /// Use macrons (とうきょう => tōkyō, スーパー => sūpā)
+    Macron,
+    /// Use circumflexes (とうきょう => tôkyô, スーパー => sûpâ)
+    Circumflex,
+    /// Double the vowel (とうきょう => tookyoo, スーパー => suupaa)
+    Doubled,
+}
+
+impl LongVowels {
+    /// Returns the spelling of `vowel` when it is lengthened.
+    pub(crate) fn lengthen(self, vowel: char) -> Option<&'static str> {
+        let long = match (self, vowel) {
+            (LongVowels::Wapuro, _) => return None,
+            (LongVowels::Macron, 'a') => "ā",
+            (LongVowels::Macron, 'i') => "ī",
+            (LongVowels::Macron, 'u') => "ū",
+            (LongVowels::Macron, 'e') => "ē",
+            (LongVowels::Macron, 'o') => "ō",
+            (LongVowels::Circumflex, 'a') => "â",
+            (LongVowels::Circumflex, 'i') => "î",
+            (LongVowels::Circumflex, 'u') => "û",
+            (LongVowels::Circumflex, 'e') => "ê",
+            (LongVowels::Circumflex, 'o') => "ô",
+            (LongVowels::Doubled, 'a') => "aa",
+            (LongVowels::Doubled, 'i') => "ii",
+            (LongVowels::Doubled, 'u') => "uu",
+            (LongVowels::Doubled, 'e') => "ee",
+            (LongVowels::Doubled, 'o') => "oo",
+            _ => return None,
+        };
+        Some(long)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+/// Conversion of "-" following hiragana when converting romaji to kana. "-" following katakana
+/// always becomes 'ー'.
+pub enum LongVowelMark {
+    /// 'ー' (batsuge-mu => ばつげーむ)
+    #[default]
+    LongDash,
+    /// The kana of the lengthened vowel, like ō and ô with `long_o_kana`
+    /// (batsuge-mu => ばつげえむ, ko-hi- => こうひい). "-" after kana without vowel stays "-".
+    Vowel,
+    /// Keep the hyphen as punctuation (batsuge-mu => ばつげ-む)
+    Hyphen,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+/// Hiragana which lengthen ō and ô when converting romaji to kana.
+///
+/// The other vowels are lengthened by repeating them (ā => ああ). Katakana always use 'ー'.
+pub enum LongOKana {
+    /// ō => おう, as in とうきょう
+    #[default]
+    Ou,
+    /// ō => おお, as in おおさか
+    Oo,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The classes of chars converted by to_fullwidth() and to_halfwidth(). All are converted by
+/// default.
+///
+/// to_fullwidth('ｶﾅ and ABC 123', { letters: false, digits: false, ..Default::default() })
+/// => "カナ　and　ABC　123"
+pub struct WidthOptions {
+    /// Latin letters (A => Ａ)
+    pub letters: bool,
+    /// Latin digits (1 => １)
+    pub digits: bool,
+    /// ASCII punctuation and the space (! => ！, ' ' => '　')
+    pub punctuation: bool,
+    /// Katakana, with the long vowel mark and the kana punctuation (ｶﾞ => ガ, ｰ => ー, ｡ => 。)
+    pub katakana: bool,
+}
+
+impl Default for WidthOptions {
+    fn default() -> Self {
+        WidthOptions {
+            letters: true,
+            digits: true,
+            punctuation: true,
+            katakana: true,
+        }
+    }
+}
+
+impl WidthOptions {
+    /// Returns true if the ASCII char `ascii`, or the space, is in a class to convert
+    pub(crate) fn converts_ascii(self, ascii: char) -> bool {
+        if ascii.is_ascii_alphabetic() {
+            self.letters
+        } else if ascii.is_ascii_digit() {
+            self.digits
+        } else {
+            self.punctuation && (ascii == ' ' || ascii.is_ascii_punctuation())
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The steps applied by normalize(). All are applied by default, except removing 'ー'.
+///
+/// normalize('ｽｰﾊﾟｰ－ﾏｰｹｯﾄ　ＡＢＣ〜', { remove_long_vowel_mark: true, ..Default::default() })
+/// => "すぱまけっと ABC〜"
+pub struct NormalizeOptions {
+    /// Convert katakana to hiragana, keeping 'ー' (スーパー => すーぱー)
+    pub fold_kana: bool,
+    /// Convert full-width ASCII to ASCII and half-width katakana to full-width katakana
+    /// (ＡＢＣ１ => ABC1, ｶﾞｯｺｳ => ガッコウ)
+    pub fold_width: bool,
+    /// Convert the dash variants (‐ ‑ ‒ – — ― − ─ ━ ﹣ － ｰ -) to 'ー' after kana, and to '-'
+    /// elsewhere (ラ−メン => ラーメン, ０３―１２ => 03-12)
+    pub unify_dashes: bool,
+    /// Convert the full-width tilde to the wave dash (～ => 〜)
+    pub unify_wave_dashes: bool,
+    /// Remove 'ー', after the dashes were unified (スーパー => スパ)
+    pub remove_long_vowel_mark: bool,
+    /// Convert ヶ and ヵ, which stand for 箇 and are read ka, to カ (一ヶ月 => 一カ月)
+    pub expand_small_ka: bool,
+}
+
+impl Default for NormalizeOptions {
+    fn default() -> Self {
+        NormalizeOptions {
+            fold_kana: true,
+            fold_width: true,
+            unify_dashes: true,
+            unify_wave_dashes: true,
+            remove_long_vowel_mark: false,
+            expand_small_ka: true,
+        }
+    }
 }
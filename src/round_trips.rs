@@ -0,0 +1,66 @@
+use crate::is_romaji::is_romaji;
+use crate::options::{Options, Romanization};
+use crate::to_kana::to_kana_with_opt;
+use crate::to_romaji::to_romaji_with_opt;
+
+fn strict_romaji() -> Options {
+    Options {
+        romanization: Romanization::Strict,
+        upcase_katakana: true,
+        ..Default::default()
+    }
+}
+
+fn strict_kana() -> Options {
+    Options {
+        use_obsolete_kana: true,
+        ..Default::default()
+    }
+}
+
+/// Test if `input` is unchanged after converting kana to strict romaji and back, or romaji to
+/// kana and back to strict romaji.
+pub fn round_trips(input: &str) -> bool {
+    if is_romaji(input) {
+        let kana = to_kana_with_opt(input, strict_kana());
+        to_romaji_with_opt(&kana, strict_romaji()) == input
+    } else {
+        let romaji = to_romaji_with_opt(input, strict_romaji());
+        to_kana_with_opt(&romaji, strict_kana()) == input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sane_defaults() {
+        assert_eq!(round_trips(""), true);
+    }
+    #[test]
+    fn kana_round_trips() {
+        assert_eq!(round_trips("ちぢむ　つづく"), true);
+        assert_eq!(round_trips("ゐゑを"), true);
+        assert_eq!(round_trips("スーパーマン"), true);
+        assert_eq!(round_trips("ふぁっしょん"), true);
+        assert_eq!(round_trips("シンヨう"), true);
+        assert_eq!(round_trips("ヷヸヹヺ"), true);
+        assert_eq!(round_trips("ッヷー"), true);
+        assert_eq!(round_trips("かゝがゞカヽガヾ"), true);
+    }
+    #[test]
+    fn strict_romaji_round_trips() {
+        assert_eq!(round_trips("tidimu tuduku"), true);
+        assert_eq!(round_trips("SUPA-MAN"), true);
+    }
+    #[test]
+    fn other_romaji_does_not_round_trip() {
+        assert_eq!(round_trips("chijimu tsuzuku"), false);
+        assert_eq!(round_trips("SHINBUN"), false);
+    }
+    #[test]
+    fn romaji_in_kana_does_not_round_trip() {
+        assert_eq!(round_trips("かなkana"), false);
+    }
+}
@@ -0,0 +1,553 @@
+//! Streaming conversions of UTF-8 readers and writers.
+//!
+//! [`KanaReader`] and [`RomajiReader`] convert what they read from another reader,
+//! [`KanaWriter`] and [`RomajiWriter`] convert what is written to them before passing it on.
+//! The input is converted chunk by chunk, chars which may still be part of a longer match (like
+//! "ky" or "っ") are held back until the next chunk arrives, so the result is the same as
+//! converting the whole input at once.
+//!
+//! # Examples
+//! ```
+//! use std::io::{Read, Write};
+//! use wana_kana::stream::{KanaWriter, RomajiReader};
+//!
+//! let mut writer = KanaWriter::new(Vec::new());
+//! writer.write_all(b"toky").unwrap();
+//! writer.write_all(b"o").unwrap();
+//! let kana = writer.finish().unwrap();
+//! assert_eq!(String::from_utf8(kana).unwrap(), "ときょ");
+//!
+//! let mut romaji = String::new();
+//! RomajiReader::new("きゃっきゃ".as_bytes())
+//!     .read_to_string(&mut romaji)
+//!     .unwrap();
+//! assert_eq!(romaji, "kyakkya");
+//! ```
+
+use std::io::{self, Read, Write};
+
+use crate::iter::{Converter, KanaConverter, RomajiConverter};
+use crate::options::Options;
+
+/// Size of the chunks read from the inner reader
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// Reader converting the romaji read from another reader to kana, like to_kana()
+#[derive(Debug)]
+pub struct KanaReader<R> {
+    reader: ConvertReader<R, KanaConverter>,
+}
+
+impl<R: Read> KanaReader<R> {
+    /// Creates a reader which converts with the default options
+    pub fn new(inner: R) -> Self {
+        Self::with_opt(inner, Options::default())
+    }
+
+    /// Creates a reader which converts with `options`
+    pub fn with_opt(inner: R, options: Options) -> Self {
+        KanaReader {
+            reader: ConvertReader::new(inner, KanaConverter::new(options)),
+        }
+    }
+
+    /// Returns the inner reader
+    pub fn get_ref(&self) -> &R {
+        &self.reader.inner
+    }
+
+    /// Returns the inner reader, the input which was read but not converted yet is lost
+    pub fn into_inner(self) -> R {
+        self.reader.inner
+    }
+}
+
+impl<R: Read> Read for KanaReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.reader.read(buf)
+    }
+}
+
+/// Reader converting the kana read from another reader to romaji, like to_romaji()
+#[derive(Debug)]
+pub struct RomajiReader<R> {
+    reader: ConvertReader<R, RomajiConverter>,
+}
+
+impl<R: Read> RomajiReader<R> {
+    /// Creates a reader which converts with the default options
+    pub fn new(inner: R) -> Self {
+        Self::with_opt(inner, Options::default())
+    }
+
+    /// Creates a reader which converts with `options`
+    pub fn with_opt(inner: R, options: Options) -> Self {
+        RomajiReader {
+            reader: ConvertReader::new(inner, RomajiConverter::new(options)),
+        }
+    }
+
+    /// Returns the inner reader
+    pub fn get_ref(&self) -> &R {
+        &self.reader.inner
+    }
+
+    /// Returns the inner reader, the input which was read but not converted yet is lost
+    pub fn into_inner(self) -> R {
+        self.reader.inner
+    }
+}
+
+impl<R: Read> Read for RomajiReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.reader.read(buf)
+    }
+}
+
+/// Writer converting the romaji written to it to kana, like to_kana(), and writing the kana to
+/// another writer.
+///
+/// The held back input is converted by [`finish`](KanaWriter::finish), or when the writer is
+/// dropped, ignoring errors.
+#[derive(Debug)]
+pub struct KanaWriter<W: Write> {
+    writer: ConvertWriter<W, KanaConverter>,
+}
+
+impl<W: Write> KanaWriter<W> {
+    /// Creates a writer which converts with the default options
+    pub fn new(inner: W) -> Self {
+        Self::with_opt(inner, Options::default())
+    }
+
+    /// Creates a writer which converts with `options`
+    pub fn with_opt(inner: W, options: Options) -> Self {
+        KanaWriter {
+            writer: ConvertWriter::new(inner, KanaConverter::new(options)),
+        }
+    }
+
+    /// Returns the inner writer
+    pub fn get_ref(&self) -> &W {
+        self.writer.get_ref()
+    }
+
+    /// Converts and writes the held back input, then returns the inner writer
+    pub fn finish(self) -> io::Result<W> {
+        self.writer.finish()
+    }
+}
+
+impl<W: Write> Write for KanaWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.writer.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Writer converting the kana written to it to romaji, like to_romaji(), and writing the romaji
+/// to another writer.
+///
+/// The held back input is converted by [`finish`](RomajiWriter::finish), or when the writer is
+/// dropped, ignoring errors.
+#[derive(Debug)]
+pub struct RomajiWriter<W: Write> {
+    writer: ConvertWriter<W, RomajiConverter>,
+}
+
+impl<W: Write> RomajiWriter<W> {
+    /// Creates a writer which converts with the default options
+    pub fn new(inner: W) -> Self {
+        Self::with_opt(inner, Options::default())
+    }
+
+    /// Creates a writer which converts with `options`
+    pub fn with_opt(inner: W, options: Options) -> Self {
+        RomajiWriter {
+            writer: ConvertWriter::new(inner, RomajiConverter::new(options)),
+        }
+    }
+
+    /// Returns the inner writer
+    pub fn get_ref(&self) -> &W {
+        self.writer.get_ref()
+    }
+
+    /// Converts and writes the held back input, then returns the inner writer
+    pub fn finish(self) -> io::Result<W> {
+        self.writer.finish()
+    }
+}
+
+impl<W: Write> Write for RomajiWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.writer.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+#[derive(Debug)]
+struct ConvertReader<R, C> {
+    inner: R,
+    converter: C,
+    decoder: Utf8Decoder,
+    chunk: Vec<u8>,
+    ouput: String,
+    /// Byte position of the next byte to return from `ouput`
+    ouput_pos: usize,
+    is_done: bool,
+}
+
+impl<R: Read, C: Converter> ConvertReader<R, C> {
+    fn new(inner: R, converter: C) -> Self {
+        ConvertReader {
+            inner,
+            converter,
+            decoder: Utf8Decoder::default(),
+            chunk: vec![0; CHUNK_SIZE],
+            ouput: String::new(),
+            ouput_pos: 0,
+            is_done: false,
+        }
+    }
+
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        loop {
+            let rest = &self.ouput.as_bytes()[self.ouput_pos..];
+            if !rest.is_empty() || buf.is_empty() {
+                let len = rest.len().min(buf.len());
+                buf[..len].copy_from_slice(&rest[..len]);
+                self.ouput_pos += len;
+                return Ok(len);
+            }
+            if self.is_done {
+                return Ok(0);
+            }
+            self.ouput.clear();
+            self.ouput_pos = 0;
+
+            let len = self.inner.read(&mut self.chunk)?;
+            if len == 0 {
+                self.is_done = true;
+                finish(&mut self.converter, &self.decoder, &mut self.ouput)?;
+            } else {
+                convert(
+                    &mut self.converter,
+                    &mut self.decoder,
+                    &self.chunk[..len],
+                    &mut self.ouput,
+                )?;
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ConvertWriter<W: Write, C: Converter> {
+    /// `None` after finishing
+    inner: Option<W>,
+    converter: C,
+    decoder: Utf8Decoder,
+    /// Converted text which was not written yet
+    ouput: String,
+}
+
+impl<W: Write, C: Converter> ConvertWriter<W, C> {
+    fn new(inner: W, converter: C) -> Self {
+        ConvertWriter {
+            inner: Some(inner),
+            converter,
+            decoder: Utf8Decoder::default(),
+            ouput: String::new(),
+        }
+    }
+
+    fn get_ref(&self) -> &W {
+        self.inner.as_ref().unwrap()
+    }
+
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        convert(&mut self.converter, &mut self.decoder, buf, &mut self.ouput)?;
+        self.write_ouput()?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.write_ouput()?;
+        self.inner.as_mut().unwrap().flush()
+    }
+
+    fn finish(mut self) -> io::Result<W> {
+        self.finish_ouput()?;
+        Ok(self.inner.take().unwrap())
+    }
+
+    fn finish_ouput(&mut self) -> io::Result<()> {
+        finish(&mut self.converter, &self.decoder, &mut self.ouput)?;
+        self.flush()
+    }
+
+    fn write_ouput(&mut self) -> io::Result<()> {
+        self.inner
+            .as_mut()
+            .unwrap()
+            .write_all(self.ouput.as_bytes())?;
+        self.ouput.clear();
+        Ok(())
+    }
+}
+
+impl<W: Write, C: Converter> Drop for ConvertWriter<W, C> {
+    fn drop(&mut self) {
+        if self.inner.is_some() {
+            let _ = self.finish_ouput();
+        }
+    }
+}
+
+/// Decodes UTF-8 which arrives in chunks, keeping the bytes of a char which is split between two
+/// chunks.
+#[derive(Debug, Default)]
+struct Utf8Decoder {
+    partial: Vec<u8>,
+}
+
+impl Utf8Decoder {
+    /// Calls `push` with every complete char of `bytes`
+    fn decode(&mut self, mut bytes: &[u8], mut push: impl FnMut(char)) -> io::Result<()> {
+        if let Some(&first) = self.partial.first() {
+            let char_len = match first {
+                0xC0..=0xDF => 2,
+                0xE0..=0xEF => 3,
+                _ => 4,
+            };
+            let missing = (char_len - self.partial.len()).min(bytes.len());
+            self.partial.extend_from_slice(&bytes[..missing]);
+            bytes = &bytes[missing..];
+            if self.partial.len() < char_len {
+                return Ok(());
+            }
+            std::str::from_utf8(&self.partial)
+                .map_err(invalid_data)?
+                .chars()
+                .for_each(&mut push);
+            self.partial.clear();
+        }
+        let valid = match std::str::from_utf8(bytes) {
+            Ok(valid) => valid,
+            Err(error) if error.error_len().is_none() => {
+                self.partial
+                    .extend_from_slice(&bytes[error.valid_up_to()..]);
+                std::str::from_utf8(&bytes[..error.valid_up_to()]).unwrap()
+            }
+            Err(error) => return Err(invalid_data(error)),
+        };
+        valid.chars().for_each(push);
+        Ok(())
+    }
+
+    /// Fails if the input ended in the middle of a char
+    fn finish(&self) -> io::Result<()> {
+        if self.partial.is_empty() {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "stream did not end with a complete UTF-8 char",
+            ))
+        }
+    }
+}
+
+fn invalid_data(error: std::str::Utf8Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+/// Decodes `bytes` and converts every syllable which can't change anymore
+fn convert(
+    converter: &mut impl Converter,
+    decoder: &mut Utf8Decoder,
+    bytes: &[u8],
+    ouput: &mut String,
+) -> io::Result<()> {
+    decoder.decode(bytes, |char| {
+        converter.push(char);
+        while !converter.needs_input() {
+            converter.convert_next(ouput);
+        }
+    })
+}
+
+/// Converts the held back input at the end of the stream
+fn finish(
+    converter: &mut impl Converter,
+    decoder: &Utf8Decoder,
+    ouput: &mut String,
+) -> io::Result<()> {
+    decoder.finish()?;
+    while converter.convert_next(ouput) {}
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::options::LongVowels;
+    use crate::to_kana::to_kana_with_opt;
+    use crate::to_romaji::to_romaji_with_opt;
+
+    /// Reader returning one byte per read
+    struct ByteReader<'a>(&'a [u8]);
+
+    impl Read for ByteReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match (self.0.split_first(), buf.first_mut()) {
+                (Some((byte, rest)), Some(first)) => {
+                    *first = *byte;
+                    self.0 = rest;
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
+
+    const ROMAJI: &str = "kyouky tte shinbun n KŌHĪ 座禅‘zazen’";
+    const KANA: &str = "きゃっきゃ スーーーパー とうきょう おおさか しんよう ヷー ｶﾞｯｺｳ ﾊﾟﾝ 漢字";
+
+    fn options() -> Options {
+        Options {
+            long_vowels: LongVowels::Macron,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn reader_holds_back_partial_matches() {
+        let mut kana = String::new();
+        KanaReader::new(ByteReader(ROMAJI.as_bytes()))
+            .read_to_string(&mut kana)
+            .unwrap();
+        assert_eq!(kana, to_kana_with_opt(ROMAJI, Options::default()));
+
+        let mut romaji = String::new();
+        RomajiReader::with_opt(ByteReader(KANA.as_bytes()), options())
+            .read_to_string(&mut romaji)
+            .unwrap();
+        assert_eq!(romaji, to_romaji_with_opt(KANA, options()));
+    }
+
+    #[test]
+    fn reader_reads_into_small_buffers() {
+        let mut reader = KanaReader::new("kyo".as_bytes());
+        let mut buf = [0; 2];
+        assert_eq!(reader.read(&mut buf).unwrap(), 2);
+        assert_eq!(reader.read(&mut buf[..1]).unwrap(), 1);
+        assert_eq!(reader.read(&mut buf).unwrap(), 2);
+        assert_eq!(reader.read(&mut buf).unwrap(), 1);
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+    }
+
+    #[test]
+    fn writer_holds_back_partial_matches() {
+        let mut writer = KanaWriter::new(Vec::new());
+        for byte in ROMAJI.as_bytes() {
+            writer.write_all(&[*byte]).unwrap();
+        }
+        let kana = writer.finish().unwrap();
+        assert_eq!(
+            String::from_utf8(kana).unwrap(),
+            to_kana_with_opt(ROMAJI, Options::default())
+        );
+
+        let mut writer = RomajiWriter::with_opt(Vec::new(), options());
+        for chunk in KANA.as_bytes().chunks(5) {
+            writer.write_all(chunk).unwrap();
+        }
+        let romaji = writer.finish().unwrap();
+        assert_eq!(
+            String::from_utf8(romaji).unwrap(),
+            to_romaji_with_opt(KANA, options())
+        );
+    }
+
+    #[test]
+    fn writer_flush_keeps_partial_matches() {
+        let mut writer = KanaWriter::new(Vec::new());
+        writer.write_all(b"wanakanaky").unwrap();
+        writer.flush().unwrap();
+        assert!(writer.get_ref().starts_with("わなか".as_bytes()));
+        writer.write_all(b"o").unwrap();
+        assert_eq!(writer.finish().unwrap(), "わなかなきょ".as_bytes());
+    }
+
+    #[test]
+    fn writer_finishes_on_drop() {
+        let mut kana = Vec::new();
+        KanaWriter::new(&mut kana).write_all(b"kan").unwrap();
+        assert_eq!(kana, "かん".as_bytes());
+    }
+
+    #[test]
+    fn every_conversion_composes_voiced_sound_marks() {
+        use crate::iter::ConvertJapaneseIter;
+        use crate::to_romaji::{to_romaji, to_romaji_with_spans};
+
+        for (kana, romaji) in [
+            ("ﾞ", "゛"),
+            ("ﾟ", "゜"),
+            ("ｶﾞ", "ga"),
+            ("ﾊﾟ", "pa"),
+            ("カ\u{3099}", "ga"),
+            ("ハ\u{309A}", "pa"),
+            ("ゝ\u{3099}", "ゞ"),
+            ("ヽ\u{3099}", "ヾ"),
+        ] {
+            assert_eq!(to_romaji(kana), romaji, "{}", kana);
+            assert_eq!(
+                to_romaji_with_spans(kana, Options::default()).0,
+                romaji,
+                "{}",
+                kana
+            );
+            assert_eq!(
+                kana.chars().romaji().collect::<String>(),
+                romaji,
+                "{}",
+                kana
+            );
+
+            let mut read = String::new();
+            RomajiReader::new(ByteReader(kana.as_bytes()))
+                .read_to_string(&mut read)
+                .unwrap();
+            assert_eq!(read, romaji, "{}", kana);
+
+            let mut writer = RomajiWriter::new(Vec::new());
+            for byte in kana.as_bytes() {
+                writer.write_all(&[*byte]).unwrap();
+            }
+            assert_eq!(writer.finish().unwrap(), romaji.as_bytes(), "{}", kana);
+        }
+    }
+
+    #[test]
+    fn invalid_utf8_is_an_error() {
+        let mut kana = String::new();
+        let error = KanaReader::new(&b"ka\xFFka"[..])
+            .read_to_string(&mut kana)
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        let mut writer = RomajiWriter::new(Vec::new());
+        writer.write_all(&"か".as_bytes()[..2]).unwrap();
+        let error = writer.finish().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+}
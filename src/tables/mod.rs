@@ -0,0 +1,10 @@
+//! Transition tables generated by `upstream_conversions`
+
+#[rustfmt::skip]
+pub(crate) mod to_kana;
+#[rustfmt::skip]
+pub(crate) mod spellings;
+#[rustfmt::skip]
+pub(crate) mod to_romaji;
+#[rustfmt::skip]
+pub(crate) mod to_ukrainian;
@@ -0,0 +1,412 @@
+//! Generated by upstream_conversions from data/to_kana.json, do not edit.
+
+/// Number of chars of the longest kana in SPELLINGS
+pub(crate) const MAX_KANA_LEN: usize = 3;
+
+/// Every romaji spelling of the kana of TO_KANA_TRIE, by kana, with "nn" of the IME mode for ん
+pub(crate) static SPELLINGS: &[(&str, &[&str])] = &[
+    ("、", &[","]),
+    ("。", &["."]),
+    ("「", &["‘"]),
+    ("」", &["’"]),
+    ("『", &["“"]),
+    ("』", &["”"]),
+    ("〜", &["~"]),
+    ("ぁ", &["la", "xa"]),
+    ("あ", &["a"]),
+    ("ぃ", &["li", "lyi", "xi", "xyi"]),
+    ("い", &["i", "yi"]),
+    ("いぇ", &["ye"]),
+    ("ぅ", &["lu", "xu"]),
+    ("う", &["u", "whu", "wu"]),
+    ("うぁ", &["wha"]),
+    ("うぃ", &["whi", "wi"]),
+    ("うぇ", &["we", "whe"]),
+    ("うぉ", &["who"]),
+    ("ぇ", &["le", "lye", "xe", "xye"]),
+    ("え", &["e"]),
+    ("ぉ", &["lo", "xo"]),
+    ("お", &["o"]),
+    ("か", &["ca", "ka"]),
+    ("が", &["ga"]),
+    ("き", &["ci", "ki"]),
+    ("きぃ", &["kyi"]),
+    ("きぇ", &["kye"]),
+    ("きゃ", &["kya"]),
+    ("きゅ", &["kyu"]),
+    ("きょ", &["kyo"]),
+    ("ぎ", &["gi"]),
+    ("ぎぃ", &["gyi"]),
+    ("ぎぇ", &["gye"]),
+    ("ぎゃ", &["gya"]),
+    ("ぎゅ", &["gyu"]),
+    ("ぎょ", &["gyo"]),
+    ("く", &["cu", "ku"]),
+    ("くぁ", &["kwa", "qa", "qwa"]),
+    ("くぃ", &["qi", "qwi", "qyi"]),
+    ("くぅ", &["qu", "qwu"]),
+    ("くぇ", &["qe", "qwe", "qye"]),
+    ("くぉ", &["qo", "qwo"]),
+    ("くゃ", &["qya"]),
+    ("くゅ", &["qyu"]),
+    ("くょ", &["qyo"]),
+    ("ぐ", &["gu"]),
+    ("ぐぁ", &["gwa"]),
+    ("ぐぃ", &["gwi"]),
+    ("ぐぅ", &["gwu"]),
+    ("ぐぇ", &["gwe"]),
+    ("ぐぉ", &["gwo"]),
+    ("け", &["ce", "ke"]),
+    ("げ", &["ge"]),
+    ("こ", &["co", "ko"]),
+    ("ご", &["go"]),
+    ("さ", &["sa"]),
+    ("ざ", &["za"]),
+    ("し", &["shi", "si"]),
+    ("しぃ", &["shyi", "syi"]),
+    ("しぇ", &["she", "shye", "sye"]),
+    ("しゃ", &["sha", "shya", "sya"]),
+    ("しゅ", &["shu", "shyu", "syu"]),
+    ("しょ", &["sho", "shyo", "syo"]),
+    ("じ", &["ji", "zi"]),
+    ("じぃ", &["jyi", "zyi"]),
+    ("じぇ", &["je", "jye", "zye"]),
+    ("じゃ", &["ja", "jya", "zya"]),
+    ("じゅ", &["ju", "jyu", "zyu"]),
+    ("じょ", &["jo", "jyo", "zyo"]),
+    ("す", &["su"]),
+    ("すぁ", &["swa"]),
+    ("すぃ", &["swi"]),
+    ("すぅ", &["swu"]),
+    ("すぇ", &["swe"]),
+    ("すぉ", &["swo"]),
+    ("ず", &["zu"]),
+    ("せ", &["se"]),
+    ("ぜ", &["ze"]),
+    ("そ", &["so"]),
+    ("ぞ", &["zo"]),
+    ("た", &["ta"]),
+    ("だ", &["da"]),
+    ("ち", &["chi", "ti"]),
+    ("ちぃ", &["chyi", "cyi", "tyi"]),
+    ("ちぇ", &["che", "chye", "cye", "tye"]),
+    ("ちゃ", &["cha", "chya", "cya", "tya"]),
+    ("ちゅ", &["chu", "chyu", "cyu", "tyu"]),
+    ("ちょ", &["cho", "chyo", "cyo", "tyo"]),
+    ("ぢ", &["di"]),
+    ("ぢぃ", &["dyi"]),
+    ("ぢぇ", &["dye"]),
+    ("ぢゃ", &["dya"]),
+    ("ぢゅ", &["dyu"]),
+    ("ぢょ", &["dyo"]),
+    ("っ", &["ltsu", "ltu", "xtsu", "xtu"]),
+    ("っい", &["yyi"]),
+    ("っいぇ", &["yye"]),
+    ("っう", &["wwhu", "wwu"]),
+    ("っうぁ", &["wwha"]),
+    ("っうぃ", &["wwhi", "wwi"]),
+    ("っうぇ", &["wwe", "wwhe"]),
+    ("っうぉ", &["wwho"]),
+    ("っか", &["cca", "kka"]),
+    ("っが", &["gga"]),
+    ("っき", &["cci", "kki"]),
+    ("っきぃ", &["kkyi"]),
+    ("っきぇ", &["kkye"]),
+    ("っきゃ", &["kkya"]),
+    ("っきゅ", &["kkyu"]),
+    ("っきょ", &["kkyo"]),
+    ("っぎ", &["ggi"]),
+    ("っぎぃ", &["ggyi"]),
+    ("っぎぇ", &["ggye"]),
+    ("っぎゃ", &["ggya"]),
+    ("っぎゅ", &["ggyu"]),
+    ("っぎょ", &["ggyo"]),
+    ("っく", &["ccu", "kku"]),
+    ("っくぁ", &["kkwa", "qqa", "qqwa"]),
+    ("っくぃ", &["qqi", "qqwi", "qqyi"]),
+    ("っくぅ", &["qqu", "qqwu"]),
+    ("っくぇ", &["qqe", "qqwe", "qqye"]),
+    ("っくぉ", &["qqo", "qqwo"]),
+    ("っくゃ", &["qqya"]),
+    ("っくゅ", &["qqyu"]),
+    ("っくょ", &["qqyo"]),
+    ("っぐ", &["ggu"]),
+    ("っぐぁ", &["ggwa"]),
+    ("っぐぃ", &["ggwi"]),
+    ("っぐぅ", &["ggwu"]),
+    ("っぐぇ", &["ggwe"]),
+    ("っぐぉ", &["ggwo"]),
+    ("っけ", &["cce", "kke"]),
+    ("っげ", &["gge"]),
+    ("っこ", &["cco", "kko"]),
+    ("っご", &["ggo"]),
+    ("っさ", &["ssa"]),
+    ("っざ", &["zza"]),
+    ("っし", &["sshi", "ssi"]),
+    ("っしぃ", &["sshyi", "ssyi"]),
+    ("っしぇ", &["sshe", "sshye", "ssye"]),
+    ("っしゃ", &["ssha", "sshya", "ssya"]),
+    ("っしゅ", &["sshu", "sshyu", "ssyu"]),
+    ("っしょ", &["ssho", "sshyo", "ssyo"]),
+    ("っじ", &["jji", "zzi"]),
+    ("っじぃ", &["jjyi", "zzyi"]),
+    ("っじぇ", &["jje", "jjye", "zzye"]),
+    ("っじゃ", &["jja", "jjya", "zzya"]),
+    ("っじゅ", &["jju", "jjyu", "zzyu"]),
+    ("っじょ", &["jjo", "jjyo", "zzyo"]),
+    ("っす", &["ssu"]),
+    ("っすぁ", &["sswa"]),
+    ("っすぃ", &["sswi"]),
+    ("っすぅ", &["sswu"]),
+    ("っすぇ", &["sswe"]),
+    ("っすぉ", &["sswo"]),
+    ("っず", &["zzu"]),
+    ("っせ", &["sse"]),
+    ("っぜ", &["zze"]),
+    ("っそ", &["sso"]),
+    ("っぞ", &["zzo"]),
+    ("った", &["tta"]),
+    ("っだ", &["dda"]),
+    ("っち", &["cchi", "tti"]),
+    ("っちぃ", &["cchyi", "ccyi", "ttyi"]),
+    ("っちぇ", &["cche", "cchye", "ccye", "ttye"]),
+    ("っちゃ", &["ccha", "cchya", "ccya", "ttya"]),
+    ("っちゅ", &["cchu", "cchyu", "ccyu", "ttyu"]),
+    ("っちょ", &["ccho", "cchyo", "ccyo", "ttyo"]),
+    ("っぢ", &["ddi"]),
+    ("っぢぃ", &["ddyi"]),
+    ("っぢぇ", &["ddye"]),
+    ("っぢゃ", &["ddya"]),
+    ("っぢゅ", &["ddyu"]),
+    ("っぢょ", &["ddyo"]),
+    ("っつ", &["ttsu", "ttu"]),
+    ("っつぁ", &["ttsa"]),
+    ("っつぃ", &["ttsi"]),
+    ("っつぇ", &["ttse"]),
+    ("っつぉ", &["ttso"]),
+    ("っづ", &["ddu"]),
+    ("って", &["tte"]),
+    ("ってぃ", &["tthi"]),
+    ("ってぇ", &["tthe"]),
+    ("ってゃ", &["ttha"]),
+    ("ってゅ", &["tthu"]),
+    ("ってょ", &["ttho"]),
+    ("っで", &["dde"]),
+    ("っでぃ", &["ddhi"]),
+    ("っでぇ", &["ddhe"]),
+    ("っでゃ", &["ddha"]),
+    ("っでゅ", &["ddhu"]),
+    ("っでょ", &["ddho"]),
+    ("っと", &["tto"]),
+    ("っとぁ", &["ttwa"]),
+    ("っとぃ", &["ttwi"]),
+    ("っとぅ", &["ttwu"]),
+    ("っとぇ", &["ttwe"]),
+    ("っとぉ", &["ttwo"]),
+    ("っど", &["ddo"]),
+    ("っどぁ", &["ddwa"]),
+    ("っどぃ", &["ddwi"]),
+    ("っどぅ", &["ddwu"]),
+    ("っどぇ", &["ddwe"]),
+    ("っどぉ", &["ddwo"]),
+    ("っは", &["hha"]),
+    ("っば", &["bba"]),
+    ("っぱ", &["ppa"]),
+    ("っひ", &["hhi"]),
+    ("っひぃ", &["hhyi"]),
+    ("っひぇ", &["hhye"]),
+    ("っひゃ", &["hhya"]),
+    ("っひゅ", &["hhyu"]),
+    ("っひょ", &["hhyo"]),
+    ("っび", &["bbi"]),
+    ("っびぃ", &["bbyi"]),
+    ("っびぇ", &["bbye"]),
+    ("っびゃ", &["bbya"]),
+    ("っびゅ", &["bbyu"]),
+    ("っびょ", &["bbyo"]),
+    ("っぴ", &["ppi"]),
+    ("っぴぃ", &["ppyi"]),
+    ("っぴぇ", &["ppye"]),
+    ("っぴゃ", &["ppya"]),
+    ("っぴゅ", &["ppyu"]),
+    ("っぴょ", &["ppyo"]),
+    ("っふ", &["ffu", "hhu"]),
+    ("っふぁ", &["ffa", "ffwa"]),
+    ("っふぃ", &["ffi", "ffwi", "ffyi"]),
+    ("っふぅ", &["ffwu"]),
+    ("っふぇ", &["ffe", "ffwe", "ffye"]),
+    ("っふぉ", &["ffo", "ffwo"]),
+    ("っふゃ", &["ffya"]),
+    ("っふゅ", &["ffyu"]),
+    ("っふょ", &["ffyo"]),
+    ("っぶ", &["bbu"]),
+    ("っぷ", &["ppu"]),
+    ("っへ", &["hhe"]),
+    ("っべ", &["bbe"]),
+    ("っぺ", &["ppe"]),
+    ("っほ", &["hho"]),
+    ("っぼ", &["bbo"]),
+    ("っぽ", &["ppo"]),
+    ("っま", &["mma"]),
+    ("っみ", &["mmi"]),
+    ("っみぃ", &["mmyi"]),
+    ("っみぇ", &["mmye"]),
+    ("っみゃ", &["mmya"]),
+    ("っみゅ", &["mmyu"]),
+    ("っみょ", &["mmyo"]),
+    ("っむ", &["mmu"]),
+    ("っめ", &["mme"]),
+    ("っも", &["mmo"]),
+    ("っや", &["yya"]),
+    ("っゆ", &["yyu"]),
+    ("っよ", &["yyo"]),
+    ("っら", &["rra"]),
+    ("っり", &["rri"]),
+    ("っりぃ", &["rryi"]),
+    ("っりぇ", &["rrye"]),
+    ("っりゃ", &["rrya"]),
+    ("っりゅ", &["rryu"]),
+    ("っりょ", &["rryo"]),
+    ("っる", &["rru"]),
+    ("っれ", &["rre"]),
+    ("っろ", &["rro"]),
+    ("っわ", &["wwa"]),
+    ("っを", &["wwo"]),
+    ("っゔ", &["vvu"]),
+    ("っゔぁ", &["vva"]),
+    ("っゔぃ", &["vvi", "vvyi"]),
+    ("っゔぇ", &["vve", "vvye"]),
+    ("っゔぉ", &["vvo"]),
+    ("っゔゃ", &["vvya"]),
+    ("っゔゅ", &["vvyu"]),
+    ("っゔょ", &["vvyo"]),
+    ("つ", &["tsu", "tu"]),
+    ("つぁ", &["tsa"]),
+    ("つぃ", &["tsi"]),
+    ("つぇ", &["tse"]),
+    ("つぉ", &["tso"]),
+    ("づ", &["du"]),
+    ("て", &["te"]),
+    ("てぃ", &["thi"]),
+    ("てぇ", &["the"]),
+    ("てゃ", &["tha"]),
+    ("てゅ", &["thu"]),
+    ("てょ", &["tho"]),
+    ("で", &["de"]),
+    ("でぃ", &["dhi"]),
+    ("でぇ", &["dhe"]),
+    ("でゃ", &["dha"]),
+    ("でゅ", &["dhu"]),
+    ("でょ", &["dho"]),
+    ("と", &["to"]),
+    ("とぁ", &["twa"]),
+    ("とぃ", &["twi"]),
+    ("とぅ", &["twu"]),
+    ("とぇ", &["twe"]),
+    ("とぉ", &["two"]),
+    ("ど", &["do"]),
+    ("どぁ", &["dwa"]),
+    ("どぃ", &["dwi"]),
+    ("どぅ", &["dwu"]),
+    ("どぇ", &["dwe"]),
+    ("どぉ", &["dwo"]),
+    ("な", &["na"]),
+    ("に", &["ni"]),
+    ("にぃ", &["nyi"]),
+    ("にぇ", &["nye"]),
+    ("にゃ", &["nya"]),
+    ("にゅ", &["nyu"]),
+    ("にょ", &["nyo"]),
+    ("ぬ", &["nu"]),
+    ("ね", &["ne"]),
+    ("の", &["no"]),
+    ("は", &["ha"]),
+    ("ば", &["ba"]),
+    ("ぱ", &["pa"]),
+    ("ひ", &["hi"]),
+    ("ひぃ", &["hyi"]),
+    ("ひぇ", &["hye"]),
+    ("ひゃ", &["hya"]),
+    ("ひゅ", &["hyu"]),
+    ("ひょ", &["hyo"]),
+    ("び", &["bi"]),
+    ("びぃ", &["byi"]),
+    ("びぇ", &["bye"]),
+    ("びゃ", &["bya"]),
+    ("びゅ", &["byu"]),
+    ("びょ", &["byo"]),
+    ("ぴ", &["pi"]),
+    ("ぴぃ", &["pyi"]),
+    ("ぴぇ", &["pye"]),
+    ("ぴゃ", &["pya"]),
+    ("ぴゅ", &["pyu"]),
+    ("ぴょ", &["pyo"]),
+    ("ふ", &["fu", "hu"]),
+    ("ふぁ", &["fa", "fwa"]),
+    ("ふぃ", &["fi", "fwi", "fyi"]),
+    ("ふぅ", &["fwu"]),
+    ("ふぇ", &["fe", "fwe", "fye"]),
+    ("ふぉ", &["fo", "fwo"]),
+    ("ふゃ", &["fya"]),
+    ("ふゅ", &["fyu"]),
+    ("ふょ", &["fyo"]),
+    ("ぶ", &["bu"]),
+    ("ぷ", &["pu"]),
+    ("へ", &["he"]),
+    ("べ", &["be"]),
+    ("ぺ", &["pe"]),
+    ("ほ", &["ho"]),
+    ("ぼ", &["bo"]),
+    ("ぽ", &["po"]),
+    ("ま", &["ma"]),
+    ("み", &["mi"]),
+    ("みぃ", &["myi"]),
+    ("みぇ", &["mye"]),
+    ("みゃ", &["mya"]),
+    ("みゅ", &["myu"]),
+    ("みょ", &["myo"]),
+    ("む", &["mu"]),
+    ("め", &["me"]),
+    ("も", &["mo"]),
+    ("ゃ", &["lya", "xya"]),
+    ("や", &["ya"]),
+    ("ゅ", &["lyu", "xyu"]),
+    ("ゆ", &["yu"]),
+    ("ょ", &["lyo", "xyo"]),
+    ("よ", &["yo"]),
+    ("ら", &["ra"]),
+    ("り", &["ri"]),
+    ("りぃ", &["ryi"]),
+    ("りぇ", &["rye"]),
+    ("りゃ", &["rya"]),
+    ("りゅ", &["ryu"]),
+    ("りょ", &["ryo"]),
+    ("る", &["ru"]),
+    ("れ", &["re"]),
+    ("ろ", &["ro"]),
+    ("ゎ", &["lwa", "xwa"]),
+    ("わ", &["wa"]),
+    ("を", &["wo"]),
+    ("ん", &["n", "n'", "xn", "nn"]),
+    ("ゔ", &["vu"]),
+    ("ゔぁ", &["va"]),
+    ("ゔぃ", &["vi", "vyi"]),
+    ("ゔぇ", &["ve", "vye"]),
+    ("ゔぉ", &["vo"]),
+    ("ゔゃ", &["vya"]),
+    ("ゔゅ", &["vyu"]),
+    ("ゔょ", &["vyo"]),
+    ("ヵ", &["lca", "lka", "xca", "xka"]),
+    ("ヶ", &["lce", "lke", "xce", "xke"]),
+    ("・", &["/"]),
+    ("ー", &["-"]),
+    ("！", &["!"]),
+    ("（", &["("]),
+    ("）", &[")"]),
+    ("：", &[":"]),
+    ("？", &["?"]),
+    ("［", &["["]),
+    ("］", &["]"]),
+    ("｛", &["{"]),
+    ("｝", &["}"]),
+];
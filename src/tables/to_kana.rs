@@ -0,0 +1,4021 @@
+//! Generated by upstream_conversions from data/to_kana.json, do not edit.
+
+use crate::trie::Trie;
+
+pub(crate) static TO_KANA_TRIE: Trie = Trie {
+    root: (0, 44),
+    transitions: &[
+        ('!', 44, 44),
+        ('(', 44, 44),
+        (')', 44, 44),
+        (',', 44, 44),
+        ('-', 44, 44),
+        ('.', 44, 44),
+        ('/', 44, 44),
+        (':', 44, 44),
+        ('?', 44, 44),
+        ('[', 44, 44),
+        (']', 44, 44),
+        ('a', 44, 44),
+        ('b', 44, 51),
+        ('c', 51, 59),
+        ('d', 59, 68),
+        ('e', 68, 68),
+        ('f', 68, 76),
+        ('g', 76, 84),
+        ('h', 84, 91),
+        ('i', 91, 91),
+        ('j', 91, 98),
+        ('k', 98, 106),
+        ('l', 106, 116),
+        ('m', 116, 123),
+        ('n', 123, 130),
+        ('o', 130, 130),
+        ('p', 130, 137),
+        ('q', 137, 145),
+        ('r', 145, 152),
+        ('s', 152, 161),
+        ('t', 161, 171),
+        ('u', 171, 171),
+        ('v', 171, 178),
+        ('w', 178, 185),
+        ('x', 185, 196),
+        ('y', 196, 202),
+        ('z', 202, 209),
+        ('{', 209, 209),
+        ('}', 209, 209),
+        ('~', 209, 209),
+        ('‘', 209, 209),
+        ('’', 209, 209),
+        ('“', 209, 209),
+        ('”', 209, 209),
+        ('a', 209, 209),
+        ('b', 209, 215),
+        ('e', 215, 215),
+        ('i', 215, 215),
+        ('o', 215, 215),
+        ('u', 215, 215),
+        ('y', 215, 220),
+        ('a', 220, 220),
+        ('c', 220, 227),
+        ('e', 227, 227),
+        ('h', 227, 233),
+        ('i', 233, 233),
+        ('o', 233, 233),
+        ('u', 233, 233),
+        ('y', 233, 238),
+        ('a', 238, 238),
+        ('d', 238, 246),
+        ('e', 246, 246),
+        ('h', 246, 251),
+        ('i', 251, 251),
+        ('o', 251, 251),
+        ('u', 251, 251),
+        ('w', 251, 256),
+        ('y', 256, 261),
+        ('a', 261, 261),
+        ('e', 261, 261),
+        ('f', 261, 268),
+        ('i', 268, 268),
+        ('o', 268, 268),
+        ('u', 268, 268),
+        ('w', 268, 273),
+        ('y', 273, 278),
+        ('a', 278, 278),
+        ('e', 278, 278),
+        ('g', 278, 285),
+        ('i', 285, 285),
+        ('o', 285, 285),
+        ('u', 285, 285),
+        ('w', 285, 290),
+        ('y', 290, 295),
+        ('a', 295, 295),
+        ('e', 295, 295),
+        ('h', 295, 301),
+        ('i', 301, 301),
+        ('o', 301, 301),
+        ('u', 301, 301),
+        ('y', 301, 306),
+        ('a', 306, 306),
+        ('e', 306, 306),
+        ('i', 306, 306),
+        ('j', 306, 312),
+        ('o', 312, 312),
+        ('u', 312, 312),
+        ('y', 312, 317),
+        ('a', 317, 317),
+        ('e', 317, 317),
+        ('i', 317, 317),
+        ('k', 317, 324),
+        ('o', 324, 324),
+        ('u', 324, 324),
+        ('w', 324, 325),
+        ('y', 325, 330),
+        ('a', 330, 330),
+        ('c', 330, 332),
+        ('e', 332, 332),
+        ('i', 332, 332),
+        ('k', 332, 334),
+        ('o', 334, 334),
+        ('t', 334, 336),
+        ('u', 336, 336),
+        ('w', 336, 337),
+        ('y', 337, 342),
+        ('a', 342, 342),
+        ('e', 342, 342),
+        ('i', 342, 342),
+        ('m', 342, 348),
+        ('o', 348, 348),
+        ('u', 348, 348),
+        ('y', 348, 353),
+        ('\'', 353, 353),
+        ('a', 353, 353),
+        ('e', 353, 353),
+        ('i', 353, 353),
+        ('o', 353, 353),
+        ('u', 353, 353),
+        ('y', 353, 358),
+        ('a', 358, 358),
+        ('e', 358, 358),
+        ('i', 358, 358),
+        ('o', 358, 358),
+        ('p', 358, 364),
+        ('u', 364, 364),
+        ('y', 364, 369),
+        ('a', 369, 369),
+        ('e', 369, 369),
+        ('i', 369, 369),
+        ('o', 369, 369),
+        ('q', 369, 376),
+        ('u', 376, 376),
+        ('w', 376, 381),
+        ('y', 381, 386),
+        ('a', 386, 386),
+        ('e', 386, 386),
+        ('i', 386, 386),
+        ('o', 386, 386),
+        ('r', 386, 392),
+        ('u', 392, 392),
+        ('y', 392, 397),
+        ('a', 397, 397),
+        ('e', 397, 397),
+        ('h', 397, 403),
+        ('i', 403, 403),
+        ('o', 403, 403),
+        ('s', 403, 411),
+        ('u', 411, 411),
+        ('w', 411, 416),
+        ('y', 416, 421),
+        ('a', 421, 421),
+        ('e', 421, 421),
+        ('h', 421, 426),
+        ('i', 426, 426),
+        ('o', 426, 426),
+        ('s', 426, 431),
+        ('t', 431, 440),
+        ('u', 440, 440),
+        ('w', 440, 445),
+        ('y', 445, 450),
+        ('a', 450, 450),
+        ('e', 450, 450),
+        ('i', 450, 450),
+        ('o', 450, 450),
+        ('u', 450, 450),
+        ('v', 450, 456),
+        ('y', 456, 461),
+        ('a', 461, 461),
+        ('e', 461, 461),
+        ('h', 461, 466),
+        ('i', 466, 466),
+        ('o', 466, 466),
+        ('u', 466, 466),
+        ('w', 466, 472),
+        ('a', 472, 472),
+        ('c', 472, 474),
+        ('e', 474, 474),
+        ('i', 474, 474),
+        ('k', 474, 476),
+        ('n', 476, 476),
+        ('o', 476, 476),
+        ('t', 476, 478),
+        ('u', 478, 478),
+        ('w', 478, 479),
+        ('y', 479, 484),
+        ('a', 484, 484),
+        ('e', 484, 484),
+        ('i', 484, 484),
+        ('o', 484, 484),
+        ('u', 484, 484),
+        ('y', 484, 489),
+        ('a', 489, 489),
+        ('e', 489, 489),
+        ('i', 489, 489),
+        ('o', 489, 489),
+        ('u', 489, 489),
+        ('y', 489, 494),
+        ('z', 494, 500),
+        ('a', 500, 500),
+        ('e', 500, 500),
+        ('i', 500, 500),
+        ('o', 500, 500),
+        ('u', 500, 500),
+        ('y', 500, 505),
+        ('a', 505, 505),
+        ('e', 505, 505),
+        ('i', 505, 505),
+        ('o', 505, 505),
+        ('u', 505, 505),
+        ('a', 505, 505),
+        ('e', 505, 505),
+        ('h', 505, 511),
+        ('i', 511, 511),
+        ('o', 511, 511),
+        ('u', 511, 511),
+        ('y', 511, 516),
+        ('a', 516, 516),
+        ('e', 516, 516),
+        ('i', 516, 516),
+        ('o', 516, 516),
+        ('u', 516, 516),
+        ('y', 516, 521),
+        ('a', 521, 521),
+        ('e', 521, 521),
+        ('i', 521, 521),
+        ('o', 521, 521),
+        ('u', 521, 521),
+        ('a', 521, 521),
+        ('e', 521, 521),
+        ('h', 521, 526),
+        ('i', 526, 526),
+        ('o', 526, 526),
+        ('u', 526, 526),
+        ('w', 526, 531),
+        ('y', 531, 536),
+        ('a', 536, 536),
+        ('e', 536, 536),
+        ('i', 536, 536),
+        ('o', 536, 536),
+        ('u', 536, 536),
+        ('a', 536, 536),
+        ('e', 536, 536),
+        ('i', 536, 536),
+        ('o', 536, 536),
+        ('u', 536, 536),
+        ('a', 536, 536),
+        ('e', 536, 536),
+        ('i', 536, 536),
+        ('o', 536, 536),
+        ('u', 536, 536),
+        ('a', 536, 536),
+        ('e', 536, 536),
+        ('i', 536, 536),
+        ('o', 536, 536),
+        ('u', 536, 536),
+        ('w', 536, 541),
+        ('y', 541, 546),
+        ('a', 546, 546),
+        ('e', 546, 546),
+        ('i', 546, 546),
+        ('o', 546, 546),
+        ('u', 546, 546),
+        ('a', 546, 546),
+        ('e', 546, 546),
+        ('i', 546, 546),
+        ('o', 546, 546),
+        ('u', 546, 546),
+        ('a', 546, 546),
+        ('e', 546, 546),
+        ('i', 546, 546),
+        ('o', 546, 546),
+        ('u', 546, 546),
+        ('w', 546, 551),
+        ('y', 551, 556),
+        ('a', 556, 556),
+        ('e', 556, 556),
+        ('i', 556, 556),
+        ('o', 556, 556),
+        ('u', 556, 556),
+        ('a', 556, 556),
+        ('e', 556, 556),
+        ('i', 556, 556),
+        ('o', 556, 556),
+        ('u', 556, 556),
+        ('a', 556, 556),
+        ('e', 556, 556),
+        ('i', 556, 556),
+        ('o', 556, 556),
+        ('u', 556, 556),
+        ('y', 556, 561),
+        ('a', 561, 561),
+        ('e', 561, 561),
+        ('i', 561, 561),
+        ('o', 561, 561),
+        ('u', 561, 561),
+        ('a', 561, 561),
+        ('e', 561, 561),
+        ('i', 561, 561),
+        ('o', 561, 561),
+        ('u', 561, 561),
+        ('y', 561, 566),
+        ('a', 566, 566),
+        ('e', 566, 566),
+        ('i', 566, 566),
+        ('o', 566, 566),
+        ('u', 566, 566),
+        ('a', 566, 566),
+        ('e', 566, 566),
+        ('i', 566, 566),
+        ('o', 566, 566),
+        ('u', 566, 566),
+        ('w', 566, 567),
+        ('y', 567, 572),
+        ('a', 572, 572),
+        ('a', 572, 572),
+        ('e', 572, 572),
+        ('i', 572, 572),
+        ('o', 572, 572),
+        ('u', 572, 572),
+        ('a', 572, 572),
+        ('e', 572, 572),
+        ('a', 572, 572),
+        ('e', 572, 572),
+        ('s', 572, 573),
+        ('u', 573, 573),
+        ('a', 573, 573),
+        ('a', 573, 573),
+        ('e', 573, 573),
+        ('i', 573, 573),
+        ('o', 573, 573),
+        ('u', 573, 573),
+        ('a', 573, 573),
+        ('e', 573, 573),
+        ('i', 573, 573),
+        ('o', 573, 573),
+        ('u', 573, 573),
+        ('y', 573, 578),
+        ('a', 578, 578),
+        ('e', 578, 578),
+        ('i', 578, 578),
+        ('o', 578, 578),
+        ('u', 578, 578),
+        ('a', 578, 578),
+        ('e', 578, 578),
+        ('i', 578, 578),
+        ('o', 578, 578),
+        ('u', 578, 578),
+        ('a', 578, 578),
+        ('e', 578, 578),
+        ('i', 578, 578),
+        ('o', 578, 578),
+        ('u', 578, 578),
+        ('y', 578, 583),
+        ('a', 583, 583),
+        ('e', 583, 583),
+        ('i', 583, 583),
+        ('o', 583, 583),
+        ('u', 583, 583),
+        ('a', 583, 583),
+        ('e', 583, 583),
+        ('i', 583, 583),
+        ('o', 583, 583),
+        ('u', 583, 583),
+        ('w', 583, 588),
+        ('y', 588, 593),
+        ('a', 593, 593),
+        ('e', 593, 593),
+        ('i', 593, 593),
+        ('o', 593, 593),
+        ('u', 593, 593),
+        ('a', 593, 593),
+        ('e', 593, 593),
+        ('i', 593, 593),
+        ('o', 593, 593),
+        ('u', 593, 593),
+        ('a', 593, 593),
+        ('e', 593, 593),
+        ('i', 593, 593),
+        ('o', 593, 593),
+        ('u', 593, 593),
+        ('y', 593, 598),
+        ('a', 598, 598),
+        ('e', 598, 598),
+        ('i', 598, 598),
+        ('o', 598, 598),
+        ('u', 598, 598),
+        ('a', 598, 598),
+        ('e', 598, 598),
+        ('i', 598, 598),
+        ('o', 598, 598),
+        ('u', 598, 598),
+        ('y', 598, 603),
+        ('a', 603, 603),
+        ('e', 603, 603),
+        ('h', 603, 609),
+        ('i', 609, 609),
+        ('o', 609, 609),
+        ('u', 609, 609),
+        ('w', 609, 614),
+        ('y', 614, 619),
+        ('a', 619, 619),
+        ('e', 619, 619),
+        ('i', 619, 619),
+        ('o', 619, 619),
+        ('u', 619, 619),
+        ('a', 619, 619),
+        ('e', 619, 619),
+        ('i', 619, 619),
+        ('o', 619, 619),
+        ('u', 619, 619),
+        ('a', 619, 619),
+        ('e', 619, 619),
+        ('i', 619, 619),
+        ('o', 619, 619),
+        ('u', 619, 619),
+        ('a', 619, 619),
+        ('e', 619, 619),
+        ('i', 619, 619),
+        ('o', 619, 619),
+        ('u', 619, 619),
+        ('a', 619, 619),
+        ('e', 619, 619),
+        ('h', 619, 624),
+        ('i', 624, 624),
+        ('o', 624, 624),
+        ('s', 624, 629),
+        ('u', 629, 629),
+        ('w', 629, 634),
+        ('y', 634, 639),
+        ('a', 639, 639),
+        ('e', 639, 639),
+        ('i', 639, 639),
+        ('o', 639, 639),
+        ('u', 639, 639),
+        ('a', 639, 639),
+        ('e', 639, 639),
+        ('i', 639, 639),
+        ('o', 639, 639),
+        ('u', 639, 639),
+        ('a', 639, 639),
+        ('e', 639, 639),
+        ('i', 639, 639),
+        ('o', 639, 639),
+        ('u', 639, 639),
+        ('y', 639, 644),
+        ('a', 644, 644),
+        ('e', 644, 644),
+        ('i', 644, 644),
+        ('o', 644, 644),
+        ('u', 644, 644),
+        ('a', 644, 644),
+        ('e', 644, 644),
+        ('i', 644, 644),
+        ('o', 644, 644),
+        ('u', 644, 644),
+        ('a', 644, 644),
+        ('e', 644, 644),
+        ('h', 644, 649),
+        ('i', 649, 649),
+        ('o', 649, 649),
+        ('u', 649, 649),
+        ('a', 649, 649),
+        ('e', 649, 649),
+        ('a', 649, 649),
+        ('e', 649, 649),
+        ('s', 649, 650),
+        ('u', 650, 650),
+        ('a', 650, 650),
+        ('a', 650, 650),
+        ('e', 650, 650),
+        ('i', 650, 650),
+        ('o', 650, 650),
+        ('u', 650, 650),
+        ('a', 650, 650),
+        ('e', 650, 650),
+        ('i', 650, 650),
+        ('o', 650, 650),
+        ('u', 650, 650),
+        ('a', 650, 650),
+        ('e', 650, 650),
+        ('i', 650, 650),
+        ('o', 650, 650),
+        ('u', 650, 650),
+        ('a', 650, 650),
+        ('e', 650, 650),
+        ('i', 650, 650),
+        ('o', 650, 650),
+        ('u', 650, 650),
+        ('y', 650, 655),
+        ('a', 655, 655),
+        ('e', 655, 655),
+        ('i', 655, 655),
+        ('o', 655, 655),
+        ('u', 655, 655),
+        ('a', 655, 655),
+        ('e', 655, 655),
+        ('i', 655, 655),
+        ('o', 655, 655),
+        ('u', 655, 655),
+        ('y', 655, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('y', 660, 665),
+        ('a', 665, 665),
+        ('e', 665, 665),
+        ('i', 665, 665),
+        ('o', 665, 665),
+        ('u', 665, 665),
+        ('a', 665, 665),
+        ('e', 665, 665),
+        ('i', 665, 665),
+        ('o', 665, 665),
+        ('u', 665, 665),
+        ('a', 665, 665),
+        ('e', 665, 665),
+        ('i', 665, 665),
+        ('o', 665, 665),
+        ('u', 665, 665),
+        ('a', 665, 665),
+        ('e', 665, 665),
+        ('i', 665, 665),
+        ('o', 665, 665),
+        ('u', 665, 665),
+        ('a', 665, 665),
+        ('e', 665, 665),
+        ('i', 665, 665),
+        ('o', 665, 665),
+        ('u', 665, 665),
+        ('a', 665, 665),
+        ('e', 665, 665),
+        ('i', 665, 665),
+        ('o', 665, 665),
+        ('u', 665, 665),
+        ('a', 665, 665),
+        ('e', 665, 665),
+        ('i', 665, 665),
+        ('o', 665, 665),
+        ('u', 665, 665),
+        ('a', 665, 665),
+        ('e', 665, 665),
+        ('i', 665, 665),
+        ('o', 665, 665),
+        ('u', 665, 665),
+        ('u', 665, 665),
+        ('a', 665, 665),
+        ('e', 665, 665),
+        ('i', 665, 665),
+        ('o', 665, 665),
+        ('u', 665, 665),
+        ('a', 665, 665),
+        ('e', 665, 665),
+        ('i', 665, 665),
+        ('o', 665, 665),
+        ('u', 665, 665),
+        ('a', 665, 665),
+        ('e', 665, 665),
+        ('i', 665, 665),
+        ('o', 665, 665),
+        ('u', 665, 665),
+    ],
+    outputs: &[
+        Some("！"),
+        Some("（"),
+        Some("）"),
+        Some("、"),
+        Some("ー"),
+        Some("。"),
+        Some("・"),
+        Some("："),
+        Some("？"),
+        Some("［"),
+        Some("］"),
+        Some("あ"),
+        None,
+        None,
+        None,
+        Some("え"),
+        None,
+        None,
+        None,
+        Some("い"),
+        None,
+        None,
+        None,
+        None,
+        Some("ん"),
+        Some("お"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("う"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("｛"),
+        Some("｝"),
+        Some("〜"),
+        Some("「"),
+        Some("」"),
+        Some("『"),
+        Some("』"),
+        Some("ば"),
+        None,
+        Some("べ"),
+        Some("び"),
+        Some("ぼ"),
+        Some("ぶ"),
+        None,
+        Some("か"),
+        None,
+        Some("け"),
+        None,
+        Some("き"),
+        Some("こ"),
+        Some("く"),
+        None,
+        Some("だ"),
+        None,
+        Some("で"),
+        None,
+        Some("ぢ"),
+        Some("ど"),
+        Some("づ"),
+        None,
+        None,
+        Some("ふぁ"),
+        Some("ふぇ"),
+        None,
+        Some("ふぃ"),
+        Some("ふぉ"),
+        Some("ふ"),
+        None,
+        None,
+        Some("が"),
+        Some("げ"),
+        None,
+        Some("ぎ"),
+        Some("ご"),
+        Some("ぐ"),
+        None,
+        None,
+        Some("は"),
+        Some("へ"),
+        None,
+        Some("ひ"),
+        Some("ほ"),
+        Some("ふ"),
+        None,
+        Some("じゃ"),
+        Some("じぇ"),
+        Some("じ"),
+        None,
+        Some("じょ"),
+        Some("じゅ"),
+        None,
+        Some("か"),
+        Some("け"),
+        Some("き"),
+        None,
+        Some("こ"),
+        Some("く"),
+        None,
+        None,
+        Some("ぁ"),
+        None,
+        Some("ぇ"),
+        Some("ぃ"),
+        None,
+        Some("ぉ"),
+        None,
+        Some("ぅ"),
+        None,
+        None,
+        Some("ま"),
+        Some("め"),
+        Some("み"),
+        None,
+        Some("も"),
+        Some("む"),
+        None,
+        Some("ん"),
+        Some("な"),
+        Some("ね"),
+        Some("に"),
+        Some("の"),
+        Some("ぬ"),
+        None,
+        Some("ぱ"),
+        Some("ぺ"),
+        Some("ぴ"),
+        Some("ぽ"),
+        None,
+        Some("ぷ"),
+        None,
+        Some("くぁ"),
+        Some("くぇ"),
+        Some("くぃ"),
+        Some("くぉ"),
+        None,
+        Some("くぅ"),
+        None,
+        None,
+        Some("ら"),
+        Some("れ"),
+        Some("り"),
+        Some("ろ"),
+        None,
+        Some("る"),
+        None,
+        Some("さ"),
+        Some("せ"),
+        None,
+        Some("し"),
+        Some("そ"),
+        None,
+        Some("す"),
+        None,
+        None,
+        Some("た"),
+        Some("て"),
+        None,
+        Some("ち"),
+        Some("と"),
+        None,
+        None,
+        Some("つ"),
+        None,
+        None,
+        Some("ゔぁ"),
+        Some("ゔぇ"),
+        Some("ゔぃ"),
+        Some("ゔぉ"),
+        Some("ゔ"),
+        None,
+        None,
+        Some("わ"),
+        Some("うぇ"),
+        None,
+        Some("うぃ"),
+        Some("を"),
+        Some("う"),
+        None,
+        Some("ぁ"),
+        None,
+        Some("ぇ"),
+        Some("ぃ"),
+        None,
+        Some("ん"),
+        Some("ぉ"),
+        None,
+        Some("ぅ"),
+        None,
+        None,
+        Some("や"),
+        Some("いぇ"),
+        Some("い"),
+        Some("よ"),
+        Some("ゆ"),
+        None,
+        Some("ざ"),
+        Some("ぜ"),
+        Some("じ"),
+        Some("ぞ"),
+        Some("ず"),
+        None,
+        None,
+        Some("っば"),
+        Some("っべ"),
+        Some("っび"),
+        Some("っぼ"),
+        Some("っぶ"),
+        None,
+        Some("びゃ"),
+        Some("びぇ"),
+        Some("びぃ"),
+        Some("びょ"),
+        Some("びゅ"),
+        Some("っか"),
+        Some("っけ"),
+        None,
+        Some("っき"),
+        Some("っこ"),
+        Some("っく"),
+        None,
+        Some("ちゃ"),
+        Some("ちぇ"),
+        Some("ち"),
+        Some("ちょ"),
+        Some("ちゅ"),
+        None,
+        Some("ちゃ"),
+        Some("ちぇ"),
+        Some("ちぃ"),
+        Some("ちょ"),
+        Some("ちゅ"),
+        Some("っだ"),
+        Some("っで"),
+        None,
+        Some("っぢ"),
+        Some("っど"),
+        Some("っづ"),
+        None,
+        None,
+        Some("でゃ"),
+        Some("でぇ"),
+        Some("でぃ"),
+        Some("でょ"),
+        Some("でゅ"),
+        Some("どぁ"),
+        Some("どぇ"),
+        Some("どぃ"),
+        Some("どぉ"),
+        Some("どぅ"),
+        Some("ぢゃ"),
+        Some("ぢぇ"),
+        Some("ぢぃ"),
+        Some("ぢょ"),
+        Some("ぢゅ"),
+        Some("っふぁ"),
+        Some("っふぇ"),
+        Some("っふぃ"),
+        Some("っふぉ"),
+        Some("っふ"),
+        None,
+        None,
+        Some("ふぁ"),
+        Some("ふぇ"),
+        Some("ふぃ"),
+        Some("ふぉ"),
+        Some("ふぅ"),
+        Some("ふゃ"),
+        Some("ふぇ"),
+        Some("ふぃ"),
+        Some("ふょ"),
+        Some("ふゅ"),
+        Some("っが"),
+        Some("っげ"),
+        Some("っぎ"),
+        Some("っご"),
+        Some("っぐ"),
+        None,
+        None,
+        Some("ぐぁ"),
+        Some("ぐぇ"),
+        Some("ぐぃ"),
+        Some("ぐぉ"),
+        Some("ぐぅ"),
+        Some("ぎゃ"),
+        Some("ぎぇ"),
+        Some("ぎぃ"),
+        Some("ぎょ"),
+        Some("ぎゅ"),
+        Some("っは"),
+        Some("っへ"),
+        Some("っひ"),
+        Some("っほ"),
+        Some("っふ"),
+        None,
+        Some("ひゃ"),
+        Some("ひぇ"),
+        Some("ひぃ"),
+        Some("ひょ"),
+        Some("ひゅ"),
+        Some("っじゃ"),
+        Some("っじぇ"),
+        Some("っじ"),
+        Some("っじょ"),
+        Some("っじゅ"),
+        None,
+        Some("じゃ"),
+        Some("じぇ"),
+        Some("じぃ"),
+        Some("じょ"),
+        Some("じゅ"),
+        Some("っか"),
+        Some("っけ"),
+        Some("っき"),
+        Some("っこ"),
+        Some("っく"),
+        None,
+        None,
+        Some("くぁ"),
+        Some("きゃ"),
+        Some("きぇ"),
+        Some("きぃ"),
+        Some("きょ"),
+        Some("きゅ"),
+        Some("ヵ"),
+        Some("ヶ"),
+        Some("ヵ"),
+        Some("ヶ"),
+        None,
+        Some("っ"),
+        Some("ゎ"),
+        Some("ゃ"),
+        Some("ぇ"),
+        Some("ぃ"),
+        Some("ょ"),
+        Some("ゅ"),
+        Some("っま"),
+        Some("っめ"),
+        Some("っみ"),
+        Some("っも"),
+        Some("っむ"),
+        None,
+        Some("みゃ"),
+        Some("みぇ"),
+        Some("みぃ"),
+        Some("みょ"),
+        Some("みゅ"),
+        Some("にゃ"),
+        Some("にぇ"),
+        Some("にぃ"),
+        Some("にょ"),
+        Some("にゅ"),
+        Some("っぱ"),
+        Some("っぺ"),
+        Some("っぴ"),
+        Some("っぽ"),
+        Some("っぷ"),
+        None,
+        Some("ぴゃ"),
+        Some("ぴぇ"),
+        Some("ぴぃ"),
+        Some("ぴょ"),
+        Some("ぴゅ"),
+        Some("っくぁ"),
+        Some("っくぇ"),
+        Some("っくぃ"),
+        Some("っくぉ"),
+        Some("っくぅ"),
+        None,
+        None,
+        Some("くぁ"),
+        Some("くぇ"),
+        Some("くぃ"),
+        Some("くぉ"),
+        Some("くぅ"),
+        Some("くゃ"),
+        Some("くぇ"),
+        Some("くぃ"),
+        Some("くょ"),
+        Some("くゅ"),
+        Some("っら"),
+        Some("っれ"),
+        Some("っり"),
+        Some("っろ"),
+        Some("っる"),
+        None,
+        Some("りゃ"),
+        Some("りぇ"),
+        Some("りぃ"),
+        Some("りょ"),
+        Some("りゅ"),
+        Some("しゃ"),
+        Some("しぇ"),
+        Some("し"),
+        Some("しょ"),
+        Some("しゅ"),
+        None,
+        Some("っさ"),
+        Some("っせ"),
+        None,
+        Some("っし"),
+        Some("っそ"),
+        Some("っす"),
+        None,
+        None,
+        Some("すぁ"),
+        Some("すぇ"),
+        Some("すぃ"),
+        Some("すぉ"),
+        Some("すぅ"),
+        Some("しゃ"),
+        Some("しぇ"),
+        Some("しぃ"),
+        Some("しょ"),
+        Some("しゅ"),
+        Some("てゃ"),
+        Some("てぇ"),
+        Some("てぃ"),
+        Some("てょ"),
+        Some("てゅ"),
+        Some("つぁ"),
+        Some("つぇ"),
+        Some("つぃ"),
+        Some("つぉ"),
+        Some("つ"),
+        Some("った"),
+        Some("って"),
+        None,
+        Some("っち"),
+        Some("っと"),
+        None,
+        Some("っつ"),
+        None,
+        None,
+        Some("とぁ"),
+        Some("とぇ"),
+        Some("とぃ"),
+        Some("とぉ"),
+        Some("とぅ"),
+        Some("ちゃ"),
+        Some("ちぇ"),
+        Some("ちぃ"),
+        Some("ちょ"),
+        Some("ちゅ"),
+        Some("っゔぁ"),
+        Some("っゔぇ"),
+        Some("っゔぃ"),
+        Some("っゔぉ"),
+        Some("っゔ"),
+        None,
+        Some("ゔゃ"),
+        Some("ゔぇ"),
+        Some("ゔぃ"),
+        Some("ゔょ"),
+        Some("ゔゅ"),
+        Some("うぁ"),
+        Some("うぇ"),
+        Some("うぃ"),
+        Some("うぉ"),
+        Some("う"),
+        Some("っわ"),
+        Some("っうぇ"),
+        None,
+        Some("っうぃ"),
+        Some("っを"),
+        Some("っう"),
+        Some("ヵ"),
+        Some("ヶ"),
+        Some("ヵ"),
+        Some("ヶ"),
+        None,
+        Some("っ"),
+        Some("ゎ"),
+        Some("ゃ"),
+        Some("ぇ"),
+        Some("ぃ"),
+        Some("ょ"),
+        Some("ゅ"),
+        Some("っや"),
+        Some("っいぇ"),
+        Some("っい"),
+        Some("っよ"),
+        Some("っゆ"),
+        Some("じゃ"),
+        Some("じぇ"),
+        Some("じぃ"),
+        Some("じょ"),
+        Some("じゅ"),
+        Some("っざ"),
+        Some("っぜ"),
+        Some("っじ"),
+        Some("っぞ"),
+        Some("っず"),
+        None,
+        Some("っびゃ"),
+        Some("っびぇ"),
+        Some("っびぃ"),
+        Some("っびょ"),
+        Some("っびゅ"),
+        Some("っちゃ"),
+        Some("っちぇ"),
+        Some("っち"),
+        Some("っちょ"),
+        Some("っちゅ"),
+        None,
+        Some("っちゃ"),
+        Some("っちぇ"),
+        Some("っちぃ"),
+        Some("っちょ"),
+        Some("っちゅ"),
+        Some("ちゃ"),
+        Some("ちぇ"),
+        Some("ちぃ"),
+        Some("ちょ"),
+        Some("ちゅ"),
+        Some("っでゃ"),
+        Some("っでぇ"),
+        Some("っでぃ"),
+        Some("っでょ"),
+        Some("っでゅ"),
+        Some("っどぁ"),
+        Some("っどぇ"),
+        Some("っどぃ"),
+        Some("っどぉ"),
+        Some("っどぅ"),
+        Some("っぢゃ"),
+        Some("っぢぇ"),
+        Some("っぢぃ"),
+        Some("っぢょ"),
+        Some("っぢゅ"),
+        Some("っふぁ"),
+        Some("っふぇ"),
+        Some("っふぃ"),
+        Some("っふぉ"),
+        Some("っふぅ"),
+        Some("っふゃ"),
+        Some("っふぇ"),
+        Some("っふぃ"),
+        Some("っふょ"),
+        Some("っふゅ"),
+        Some("っぐぁ"),
+        Some("っぐぇ"),
+        Some("っぐぃ"),
+        Some("っぐぉ"),
+        Some("っぐぅ"),
+        Some("っぎゃ"),
+        Some("っぎぇ"),
+        Some("っぎぃ"),
+        Some("っぎょ"),
+        Some("っぎゅ"),
+        Some("っひゃ"),
+        Some("っひぇ"),
+        Some("っひぃ"),
+        Some("っひょ"),
+        Some("っひゅ"),
+        Some("っじゃ"),
+        Some("っじぇ"),
+        Some("っじぃ"),
+        Some("っじょ"),
+        Some("っじゅ"),
+        Some("っくぁ"),
+        Some("っきゃ"),
+        Some("っきぇ"),
+        Some("っきぃ"),
+        Some("っきょ"),
+        Some("っきゅ"),
+        Some("っ"),
+        Some("っみゃ"),
+        Some("っみぇ"),
+        Some("っみぃ"),
+        Some("っみょ"),
+        Some("っみゅ"),
+        Some("っぴゃ"),
+        Some("っぴぇ"),
+        Some("っぴぃ"),
+        Some("っぴょ"),
+        Some("っぴゅ"),
+        Some("っくぁ"),
+        Some("っくぇ"),
+        Some("っくぃ"),
+        Some("っくぉ"),
+        Some("っくぅ"),
+        Some("っくゃ"),
+        Some("っくぇ"),
+        Some("っくぃ"),
+        Some("っくょ"),
+        Some("っくゅ"),
+        Some("っりゃ"),
+        Some("っりぇ"),
+        Some("っりぃ"),
+        Some("っりょ"),
+        Some("っりゅ"),
+        Some("しゃ"),
+        Some("しぇ"),
+        Some("しぃ"),
+        Some("しょ"),
+        Some("しゅ"),
+        Some("っしゃ"),
+        Some("っしぇ"),
+        Some("っし"),
+        Some("っしょ"),
+        Some("っしゅ"),
+        None,
+        Some("っすぁ"),
+        Some("っすぇ"),
+        Some("っすぃ"),
+        Some("っすぉ"),
+        Some("っすぅ"),
+        Some("っしゃ"),
+        Some("っしぇ"),
+        Some("っしぃ"),
+        Some("っしょ"),
+        Some("っしゅ"),
+        Some("ってゃ"),
+        Some("ってぇ"),
+        Some("ってぃ"),
+        Some("ってょ"),
+        Some("ってゅ"),
+        Some("っつぁ"),
+        Some("っつぇ"),
+        Some("っつぃ"),
+        Some("っつぉ"),
+        Some("っつ"),
+        Some("っとぁ"),
+        Some("っとぇ"),
+        Some("っとぃ"),
+        Some("っとぉ"),
+        Some("っとぅ"),
+        Some("っちゃ"),
+        Some("っちぇ"),
+        Some("っちぃ"),
+        Some("っちょ"),
+        Some("っちゅ"),
+        Some("っゔゃ"),
+        Some("っゔぇ"),
+        Some("っゔぃ"),
+        Some("っゔょ"),
+        Some("っゔゅ"),
+        Some("っうぁ"),
+        Some("っうぇ"),
+        Some("っうぃ"),
+        Some("っうぉ"),
+        Some("っう"),
+        Some("っ"),
+        Some("っじゃ"),
+        Some("っじぇ"),
+        Some("っじぃ"),
+        Some("っじょ"),
+        Some("っじゅ"),
+        Some("っちゃ"),
+        Some("っちぇ"),
+        Some("っちぃ"),
+        Some("っちょ"),
+        Some("っちゅ"),
+        Some("っしゃ"),
+        Some("っしぇ"),
+        Some("っしぃ"),
+        Some("っしょ"),
+        Some("っしゅ"),
+    ],
+};
+
+pub(crate) static TO_KANA_TRIE_OBSOLETE: Trie = Trie {
+    root: (0, 44),
+    transitions: &[
+        ('!', 44, 44),
+        ('(', 44, 44),
+        (')', 44, 44),
+        (',', 44, 44),
+        ('-', 44, 44),
+        ('.', 44, 44),
+        ('/', 44, 44),
+        (':', 44, 44),
+        ('?', 44, 44),
+        ('[', 44, 44),
+        (']', 44, 44),
+        ('a', 44, 44),
+        ('b', 44, 51),
+        ('c', 51, 59),
+        ('d', 59, 68),
+        ('e', 68, 68),
+        ('f', 68, 76),
+        ('g', 76, 84),
+        ('h', 84, 91),
+        ('i', 91, 91),
+        ('j', 91, 98),
+        ('k', 98, 106),
+        ('l', 106, 116),
+        ('m', 116, 123),
+        ('n', 123, 130),
+        ('o', 130, 130),
+        ('p', 130, 137),
+        ('q', 137, 145),
+        ('r', 145, 152),
+        ('s', 152, 161),
+        ('t', 161, 171),
+        ('u', 171, 171),
+        ('v', 171, 178),
+        ('w', 178, 185),
+        ('x', 185, 196),
+        ('y', 196, 202),
+        ('z', 202, 209),
+        ('{', 209, 209),
+        ('}', 209, 209),
+        ('~', 209, 209),
+        ('‘', 209, 209),
+        ('’', 209, 209),
+        ('“', 209, 209),
+        ('”', 209, 209),
+        ('a', 209, 209),
+        ('b', 209, 215),
+        ('e', 215, 215),
+        ('i', 215, 215),
+        ('o', 215, 215),
+        ('u', 215, 215),
+        ('y', 215, 220),
+        ('a', 220, 220),
+        ('c', 220, 227),
+        ('e', 227, 227),
+        ('h', 227, 233),
+        ('i', 233, 233),
+        ('o', 233, 233),
+        ('u', 233, 233),
+        ('y', 233, 238),
+        ('a', 238, 238),
+        ('d', 238, 246),
+        ('e', 246, 246),
+        ('h', 246, 251),
+        ('i', 251, 251),
+        ('o', 251, 251),
+        ('u', 251, 251),
+        ('w', 251, 256),
+        ('y', 256, 261),
+        ('a', 261, 261),
+        ('e', 261, 261),
+        ('f', 261, 268),
+        ('i', 268, 268),
+        ('o', 268, 268),
+        ('u', 268, 268),
+        ('w', 268, 273),
+        ('y', 273, 278),
+        ('a', 278, 278),
+        ('e', 278, 278),
+        ('g', 278, 285),
+        ('i', 285, 285),
+        ('o', 285, 285),
+        ('u', 285, 285),
+        ('w', 285, 290),
+        ('y', 290, 295),
+        ('a', 295, 295),
+        ('e', 295, 295),
+        ('h', 295, 301),
+        ('i', 301, 301),
+        ('o', 301, 301),
+        ('u', 301, 301),
+        ('y', 301, 306),
+        ('a', 306, 306),
+        ('e', 306, 306),
+        ('i', 306, 306),
+        ('j', 306, 312),
+        ('o', 312, 312),
+        ('u', 312, 312),
+        ('y', 312, 317),
+        ('a', 317, 317),
+        ('e', 317, 317),
+        ('i', 317, 317),
+        ('k', 317, 324),
+        ('o', 324, 324),
+        ('u', 324, 324),
+        ('w', 324, 325),
+        ('y', 325, 330),
+        ('a', 330, 330),
+        ('c', 330, 332),
+        ('e', 332, 332),
+        ('i', 332, 332),
+        ('k', 332, 334),
+        ('o', 334, 334),
+        ('t', 334, 336),
+        ('u', 336, 336),
+        ('w', 336, 337),
+        ('y', 337, 342),
+        ('a', 342, 342),
+        ('e', 342, 342),
+        ('i', 342, 342),
+        ('m', 342, 348),
+        ('o', 348, 348),
+        ('u', 348, 348),
+        ('y', 348, 353),
+        ('\'', 353, 353),
+        ('a', 353, 353),
+        ('e', 353, 353),
+        ('i', 353, 353),
+        ('o', 353, 353),
+        ('u', 353, 353),
+        ('y', 353, 358),
+        ('a', 358, 358),
+        ('e', 358, 358),
+        ('i', 358, 358),
+        ('o', 358, 358),
+        ('p', 358, 364),
+        ('u', 364, 364),
+        ('y', 364, 369),
+        ('a', 369, 369),
+        ('e', 369, 369),
+        ('i', 369, 369),
+        ('o', 369, 369),
+        ('q', 369, 376),
+        ('u', 376, 376),
+        ('w', 376, 381),
+        ('y', 381, 386),
+        ('a', 386, 386),
+        ('e', 386, 386),
+        ('i', 386, 386),
+        ('o', 386, 386),
+        ('r', 386, 392),
+        ('u', 392, 392),
+        ('y', 392, 397),
+        ('a', 397, 397),
+        ('e', 397, 397),
+        ('h', 397, 403),
+        ('i', 403, 403),
+        ('o', 403, 403),
+        ('s', 403, 411),
+        ('u', 411, 411),
+        ('w', 411, 416),
+        ('y', 416, 421),
+        ('a', 421, 421),
+        ('e', 421, 421),
+        ('h', 421, 426),
+        ('i', 426, 426),
+        ('o', 426, 426),
+        ('s', 426, 431),
+        ('t', 431, 440),
+        ('u', 440, 440),
+        ('w', 440, 445),
+        ('y', 445, 450),
+        ('a', 450, 450),
+        ('e', 450, 450),
+        ('i', 450, 450),
+        ('o', 450, 450),
+        ('u', 450, 450),
+        ('v', 450, 456),
+        ('y', 456, 461),
+        ('a', 461, 461),
+        ('e', 461, 461),
+        ('h', 461, 466),
+        ('i', 466, 466),
+        ('o', 466, 466),
+        ('u', 466, 466),
+        ('w', 466, 472),
+        ('a', 472, 472),
+        ('c', 472, 474),
+        ('e', 474, 474),
+        ('i', 474, 474),
+        ('k', 474, 476),
+        ('n', 476, 476),
+        ('o', 476, 476),
+        ('t', 476, 478),
+        ('u', 478, 478),
+        ('w', 478, 479),
+        ('y', 479, 484),
+        ('a', 484, 484),
+        ('e', 484, 484),
+        ('i', 484, 484),
+        ('o', 484, 484),
+        ('u', 484, 484),
+        ('y', 484, 489),
+        ('a', 489, 489),
+        ('e', 489, 489),
+        ('i', 489, 489),
+        ('o', 489, 489),
+        ('u', 489, 489),
+        ('y', 489, 494),
+        ('z', 494, 500),
+        ('a', 500, 500),
+        ('e', 500, 500),
+        ('i', 500, 500),
+        ('o', 500, 500),
+        ('u', 500, 500),
+        ('y', 500, 505),
+        ('a', 505, 505),
+        ('e', 505, 505),
+        ('i', 505, 505),
+        ('o', 505, 505),
+        ('u', 505, 505),
+        ('a', 505, 505),
+        ('e', 505, 505),
+        ('h', 505, 511),
+        ('i', 511, 511),
+        ('o', 511, 511),
+        ('u', 511, 511),
+        ('y', 511, 516),
+        ('a', 516, 516),
+        ('e', 516, 516),
+        ('i', 516, 516),
+        ('o', 516, 516),
+        ('u', 516, 516),
+        ('y', 516, 521),
+        ('a', 521, 521),
+        ('e', 521, 521),
+        ('i', 521, 521),
+        ('o', 521, 521),
+        ('u', 521, 521),
+        ('a', 521, 521),
+        ('e', 521, 521),
+        ('h', 521, 526),
+        ('i', 526, 526),
+        ('o', 526, 526),
+        ('u', 526, 526),
+        ('w', 526, 531),
+        ('y', 531, 536),
+        ('a', 536, 536),
+        ('e', 536, 536),
+        ('i', 536, 536),
+        ('o', 536, 536),
+        ('u', 536, 536),
+        ('a', 536, 536),
+        ('e', 536, 536),
+        ('i', 536, 536),
+        ('o', 536, 536),
+        ('u', 536, 536),
+        ('a', 536, 536),
+        ('e', 536, 536),
+        ('i', 536, 536),
+        ('o', 536, 536),
+        ('u', 536, 536),
+        ('a', 536, 536),
+        ('e', 536, 536),
+        ('i', 536, 536),
+        ('o', 536, 536),
+        ('u', 536, 536),
+        ('w', 536, 541),
+        ('y', 541, 546),
+        ('a', 546, 546),
+        ('e', 546, 546),
+        ('i', 546, 546),
+        ('o', 546, 546),
+        ('u', 546, 546),
+        ('a', 546, 546),
+        ('e', 546, 546),
+        ('i', 546, 546),
+        ('o', 546, 546),
+        ('u', 546, 546),
+        ('a', 546, 546),
+        ('e', 546, 546),
+        ('i', 546, 546),
+        ('o', 546, 546),
+        ('u', 546, 546),
+        ('w', 546, 551),
+        ('y', 551, 556),
+        ('a', 556, 556),
+        ('e', 556, 556),
+        ('i', 556, 556),
+        ('o', 556, 556),
+        ('u', 556, 556),
+        ('a', 556, 556),
+        ('e', 556, 556),
+        ('i', 556, 556),
+        ('o', 556, 556),
+        ('u', 556, 556),
+        ('a', 556, 556),
+        ('e', 556, 556),
+        ('i', 556, 556),
+        ('o', 556, 556),
+        ('u', 556, 556),
+        ('y', 556, 561),
+        ('a', 561, 561),
+        ('e', 561, 561),
+        ('i', 561, 561),
+        ('o', 561, 561),
+        ('u', 561, 561),
+        ('a', 561, 561),
+        ('e', 561, 561),
+        ('i', 561, 561),
+        ('o', 561, 561),
+        ('u', 561, 561),
+        ('y', 561, 566),
+        ('a', 566, 566),
+        ('e', 566, 566),
+        ('i', 566, 566),
+        ('o', 566, 566),
+        ('u', 566, 566),
+        ('a', 566, 566),
+        ('e', 566, 566),
+        ('i', 566, 566),
+        ('o', 566, 566),
+        ('u', 566, 566),
+        ('w', 566, 567),
+        ('y', 567, 572),
+        ('a', 572, 572),
+        ('a', 572, 572),
+        ('e', 572, 572),
+        ('i', 572, 572),
+        ('o', 572, 572),
+        ('u', 572, 572),
+        ('a', 572, 572),
+        ('e', 572, 572),
+        ('a', 572, 572),
+        ('e', 572, 572),
+        ('s', 572, 573),
+        ('u', 573, 573),
+        ('a', 573, 573),
+        ('a', 573, 573),
+        ('e', 573, 573),
+        ('i', 573, 573),
+        ('o', 573, 573),
+        ('u', 573, 573),
+        ('a', 573, 573),
+        ('e', 573, 573),
+        ('i', 573, 573),
+        ('o', 573, 573),
+        ('u', 573, 573),
+        ('y', 573, 578),
+        ('a', 578, 578),
+        ('e', 578, 578),
+        ('i', 578, 578),
+        ('o', 578, 578),
+        ('u', 578, 578),
+        ('a', 578, 578),
+        ('e', 578, 578),
+        ('i', 578, 578),
+        ('o', 578, 578),
+        ('u', 578, 578),
+        ('a', 578, 578),
+        ('e', 578, 578),
+        ('i', 578, 578),
+        ('o', 578, 578),
+        ('u', 578, 578),
+        ('y', 578, 583),
+        ('a', 583, 583),
+        ('e', 583, 583),
+        ('i', 583, 583),
+        ('o', 583, 583),
+        ('u', 583, 583),
+        ('a', 583, 583),
+        ('e', 583, 583),
+        ('i', 583, 583),
+        ('o', 583, 583),
+        ('u', 583, 583),
+        ('w', 583, 588),
+        ('y', 588, 593),
+        ('a', 593, 593),
+        ('e', 593, 593),
+        ('i', 593, 593),
+        ('o', 593, 593),
+        ('u', 593, 593),
+        ('a', 593, 593),
+        ('e', 593, 593),
+        ('i', 593, 593),
+        ('o', 593, 593),
+        ('u', 593, 593),
+        ('a', 593, 593),
+        ('e', 593, 593),
+        ('i', 593, 593),
+        ('o', 593, 593),
+        ('u', 593, 593),
+        ('y', 593, 598),
+        ('a', 598, 598),
+        ('e', 598, 598),
+        ('i', 598, 598),
+        ('o', 598, 598),
+        ('u', 598, 598),
+        ('a', 598, 598),
+        ('e', 598, 598),
+        ('i', 598, 598),
+        ('o', 598, 598),
+        ('u', 598, 598),
+        ('y', 598, 603),
+        ('a', 603, 603),
+        ('e', 603, 603),
+        ('h', 603, 609),
+        ('i', 609, 609),
+        ('o', 609, 609),
+        ('u', 609, 609),
+        ('w', 609, 614),
+        ('y', 614, 619),
+        ('a', 619, 619),
+        ('e', 619, 619),
+        ('i', 619, 619),
+        ('o', 619, 619),
+        ('u', 619, 619),
+        ('a', 619, 619),
+        ('e', 619, 619),
+        ('i', 619, 619),
+        ('o', 619, 619),
+        ('u', 619, 619),
+        ('a', 619, 619),
+        ('e', 619, 619),
+        ('i', 619, 619),
+        ('o', 619, 619),
+        ('u', 619, 619),
+        ('a', 619, 619),
+        ('e', 619, 619),
+        ('i', 619, 619),
+        ('o', 619, 619),
+        ('u', 619, 619),
+        ('a', 619, 619),
+        ('e', 619, 619),
+        ('h', 619, 624),
+        ('i', 624, 624),
+        ('o', 624, 624),
+        ('s', 624, 629),
+        ('u', 629, 629),
+        ('w', 629, 634),
+        ('y', 634, 639),
+        ('a', 639, 639),
+        ('e', 639, 639),
+        ('i', 639, 639),
+        ('o', 639, 639),
+        ('u', 639, 639),
+        ('a', 639, 639),
+        ('e', 639, 639),
+        ('i', 639, 639),
+        ('o', 639, 639),
+        ('u', 639, 639),
+        ('a', 639, 639),
+        ('e', 639, 639),
+        ('i', 639, 639),
+        ('o', 639, 639),
+        ('u', 639, 639),
+        ('y', 639, 644),
+        ('a', 644, 644),
+        ('e', 644, 644),
+        ('i', 644, 644),
+        ('o', 644, 644),
+        ('u', 644, 644),
+        ('a', 644, 644),
+        ('e', 644, 644),
+        ('i', 644, 644),
+        ('o', 644, 644),
+        ('u', 644, 644),
+        ('a', 644, 644),
+        ('e', 644, 644),
+        ('h', 644, 649),
+        ('i', 649, 649),
+        ('o', 649, 649),
+        ('u', 649, 649),
+        ('a', 649, 649),
+        ('e', 649, 649),
+        ('a', 649, 649),
+        ('e', 649, 649),
+        ('s', 649, 650),
+        ('u', 650, 650),
+        ('a', 650, 650),
+        ('a', 650, 650),
+        ('e', 650, 650),
+        ('i', 650, 650),
+        ('o', 650, 650),
+        ('u', 650, 650),
+        ('a', 650, 650),
+        ('e', 650, 650),
+        ('i', 650, 650),
+        ('o', 650, 650),
+        ('u', 650, 650),
+        ('a', 650, 650),
+        ('e', 650, 650),
+        ('i', 650, 650),
+        ('o', 650, 650),
+        ('u', 650, 650),
+        ('a', 650, 650),
+        ('e', 650, 650),
+        ('i', 650, 650),
+        ('o', 650, 650),
+        ('u', 650, 650),
+        ('y', 650, 655),
+        ('a', 655, 655),
+        ('e', 655, 655),
+        ('i', 655, 655),
+        ('o', 655, 655),
+        ('u', 655, 655),
+        ('a', 655, 655),
+        ('e', 655, 655),
+        ('i', 655, 655),
+        ('o', 655, 655),
+        ('u', 655, 655),
+        ('y', 655, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('a', 660, 660),
+        ('e', 660, 660),
+        ('i', 660, 660),
+        ('o', 660, 660),
+        ('u', 660, 660),
+        ('y', 660, 665),
+        ('a', 665, 665),
+        ('e', 665, 665),
+        ('i', 665, 665),
+        ('o', 665, 665),
+        ('u', 665, 665),
+        ('a', 665, 665),
+        ('e', 665, 665),
+        ('i', 665, 665),
+        ('o', 665, 665),
+        ('u', 665, 665),
+        ('a', 665, 665),
+        ('e', 665, 665),
+        ('i', 665, 665),
+        ('o', 665, 665),
+        ('u', 665, 665),
+        ('a', 665, 665),
+        ('e', 665, 665),
+        ('i', 665, 665),
+        ('o', 665, 665),
+        ('u', 665, 665),
+        ('a', 665, 665),
+        ('e', 665, 665),
+        ('i', 665, 665),
+        ('o', 665, 665),
+        ('u', 665, 665),
+        ('a', 665, 665),
+        ('e', 665, 665),
+        ('i', 665, 665),
+        ('o', 665, 665),
+        ('u', 665, 665),
+        ('a', 665, 665),
+        ('e', 665, 665),
+        ('i', 665, 665),
+        ('o', 665, 665),
+        ('u', 665, 665),
+        ('a', 665, 665),
+        ('e', 665, 665),
+        ('i', 665, 665),
+        ('o', 665, 665),
+        ('u', 665, 665),
+        ('u', 665, 665),
+        ('a', 665, 665),
+        ('e', 665, 665),
+        ('i', 665, 665),
+        ('o', 665, 665),
+        ('u', 665, 665),
+        ('a', 665, 665),
+        ('e', 665, 665),
+        ('i', 665, 665),
+        ('o', 665, 665),
+        ('u', 665, 665),
+        ('a', 665, 665),
+        ('e', 665, 665),
+        ('i', 665, 665),
+        ('o', 665, 665),
+        ('u', 665, 665),
+    ],
+    outputs: &[
+        Some("！"),
+        Some("（"),
+        Some("）"),
+        Some("、"),
+        Some("ー"),
+        Some("。"),
+        Some("・"),
+        Some("："),
+        Some("？"),
+        Some("［"),
+        Some("］"),
+        Some("あ"),
+        None,
+        None,
+        None,
+        Some("え"),
+        None,
+        None,
+        None,
+        Some("い"),
+        None,
+        None,
+        None,
+        None,
+        Some("ん"),
+        Some("お"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("う"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("｛"),
+        Some("｝"),
+        Some("〜"),
+        Some("「"),
+        Some("」"),
+        Some("『"),
+        Some("』"),
+        Some("ば"),
+        None,
+        Some("べ"),
+        Some("び"),
+        Some("ぼ"),
+        Some("ぶ"),
+        None,
+        Some("か"),
+        None,
+        Some("け"),
+        None,
+        Some("き"),
+        Some("こ"),
+        Some("く"),
+        None,
+        Some("だ"),
+        None,
+        Some("で"),
+        None,
+        Some("ぢ"),
+        Some("ど"),
+        Some("づ"),
+        None,
+        None,
+        Some("ふぁ"),
+        Some("ふぇ"),
+        None,
+        Some("ふぃ"),
+        Some("ふぉ"),
+        Some("ふ"),
+        None,
+        None,
+        Some("が"),
+        Some("げ"),
+        None,
+        Some("ぎ"),
+        Some("ご"),
+        Some("ぐ"),
+        None,
+        None,
+        Some("は"),
+        Some("へ"),
+        None,
+        Some("ひ"),
+        Some("ほ"),
+        Some("ふ"),
+        None,
+        Some("じゃ"),
+        Some("じぇ"),
+        Some("じ"),
+        None,
+        Some("じょ"),
+        Some("じゅ"),
+        None,
+        Some("か"),
+        Some("け"),
+        Some("き"),
+        None,
+        Some("こ"),
+        Some("く"),
+        None,
+        None,
+        Some("ぁ"),
+        None,
+        Some("ぇ"),
+        Some("ぃ"),
+        None,
+        Some("ぉ"),
+        None,
+        Some("ぅ"),
+        None,
+        None,
+        Some("ま"),
+        Some("め"),
+        Some("み"),
+        None,
+        Some("も"),
+        Some("む"),
+        None,
+        Some("ん"),
+        Some("な"),
+        Some("ね"),
+        Some("に"),
+        Some("の"),
+        Some("ぬ"),
+        None,
+        Some("ぱ"),
+        Some("ぺ"),
+        Some("ぴ"),
+        Some("ぽ"),
+        None,
+        Some("ぷ"),
+        None,
+        Some("くぁ"),
+        Some("くぇ"),
+        Some("くぃ"),
+        Some("くぉ"),
+        None,
+        Some("くぅ"),
+        None,
+        None,
+        Some("ら"),
+        Some("れ"),
+        Some("り"),
+        Some("ろ"),
+        None,
+        Some("る"),
+        None,
+        Some("さ"),
+        Some("せ"),
+        None,
+        Some("し"),
+        Some("そ"),
+        None,
+        Some("す"),
+        None,
+        None,
+        Some("た"),
+        Some("て"),
+        None,
+        Some("ち"),
+        Some("と"),
+        None,
+        None,
+        Some("つ"),
+        None,
+        None,
+        Some("ゔぁ"),
+        Some("ゔぇ"),
+        Some("ゔぃ"),
+        Some("ゔぉ"),
+        Some("ゔ"),
+        None,
+        None,
+        Some("わ"),
+        Some("ゑ"),
+        None,
+        Some("ゐ"),
+        Some("を"),
+        Some("う"),
+        None,
+        Some("ぁ"),
+        None,
+        Some("ぇ"),
+        Some("ぃ"),
+        None,
+        Some("ん"),
+        Some("ぉ"),
+        None,
+        Some("ぅ"),
+        None,
+        None,
+        Some("や"),
+        Some("いぇ"),
+        Some("い"),
+        Some("よ"),
+        Some("ゆ"),
+        None,
+        Some("ざ"),
+        Some("ぜ"),
+        Some("じ"),
+        Some("ぞ"),
+        Some("ず"),
+        None,
+        None,
+        Some("っば"),
+        Some("っべ"),
+        Some("っび"),
+        Some("っぼ"),
+        Some("っぶ"),
+        None,
+        Some("びゃ"),
+        Some("びぇ"),
+        Some("びぃ"),
+        Some("びょ"),
+        Some("びゅ"),
+        Some("っか"),
+        Some("っけ"),
+        None,
+        Some("っき"),
+        Some("っこ"),
+        Some("っく"),
+        None,
+        Some("ちゃ"),
+        Some("ちぇ"),
+        Some("ち"),
+        Some("ちょ"),
+        Some("ちゅ"),
+        None,
+        Some("ちゃ"),
+        Some("ちぇ"),
+        Some("ちぃ"),
+        Some("ちょ"),
+        Some("ちゅ"),
+        Some("っだ"),
+        Some("っで"),
+        None,
+        Some("っぢ"),
+        Some("っど"),
+        Some("っづ"),
+        None,
+        None,
+        Some("でゃ"),
+        Some("でぇ"),
+        Some("でぃ"),
+        Some("でょ"),
+        Some("でゅ"),
+        Some("どぁ"),
+        Some("どぇ"),
+        Some("どぃ"),
+        Some("どぉ"),
+        Some("どぅ"),
+        Some("ぢゃ"),
+        Some("ぢぇ"),
+        Some("ぢぃ"),
+        Some("ぢょ"),
+        Some("ぢゅ"),
+        Some("っふぁ"),
+        Some("っふぇ"),
+        Some("っふぃ"),
+        Some("っふぉ"),
+        Some("っふ"),
+        None,
+        None,
+        Some("ふぁ"),
+        Some("ふぇ"),
+        Some("ふぃ"),
+        Some("ふぉ"),
+        Some("ふぅ"),
+        Some("ふゃ"),
+        Some("ふぇ"),
+        Some("ふぃ"),
+        Some("ふょ"),
+        Some("ふゅ"),
+        Some("っが"),
+        Some("っげ"),
+        Some("っぎ"),
+        Some("っご"),
+        Some("っぐ"),
+        None,
+        None,
+        Some("ぐぁ"),
+        Some("ぐぇ"),
+        Some("ぐぃ"),
+        Some("ぐぉ"),
+        Some("ぐぅ"),
+        Some("ぎゃ"),
+        Some("ぎぇ"),
+        Some("ぎぃ"),
+        Some("ぎょ"),
+        Some("ぎゅ"),
+        Some("っは"),
+        Some("っへ"),
+        Some("っひ"),
+        Some("っほ"),
+        Some("っふ"),
+        None,
+        Some("ひゃ"),
+        Some("ひぇ"),
+        Some("ひぃ"),
+        Some("ひょ"),
+        Some("ひゅ"),
+        Some("っじゃ"),
+        Some("っじぇ"),
+        Some("っじ"),
+        Some("っじょ"),
+        Some("っじゅ"),
+        None,
+        Some("じゃ"),
+        Some("じぇ"),
+        Some("じぃ"),
+        Some("じょ"),
+        Some("じゅ"),
+        Some("っか"),
+        Some("っけ"),
+        Some("っき"),
+        Some("っこ"),
+        Some("っく"),
+        None,
+        None,
+        Some("くぁ"),
+        Some("きゃ"),
+        Some("きぇ"),
+        Some("きぃ"),
+        Some("きょ"),
+        Some("きゅ"),
+        Some("ヵ"),
+        Some("ヶ"),
+        Some("ヵ"),
+        Some("ヶ"),
+        None,
+        Some("っ"),
+        Some("ゎ"),
+        Some("ゃ"),
+        Some("ぇ"),
+        Some("ぃ"),
+        Some("ょ"),
+        Some("ゅ"),
+        Some("っま"),
+        Some("っめ"),
+        Some("っみ"),
+        Some("っも"),
+        Some("っむ"),
+        None,
+        Some("みゃ"),
+        Some("みぇ"),
+        Some("みぃ"),
+        Some("みょ"),
+        Some("みゅ"),
+        Some("にゃ"),
+        Some("にぇ"),
+        Some("にぃ"),
+        Some("にょ"),
+        Some("にゅ"),
+        Some("っぱ"),
+        Some("っぺ"),
+        Some("っぴ"),
+        Some("っぽ"),
+        Some("っぷ"),
+        None,
+        Some("ぴゃ"),
+        Some("ぴぇ"),
+        Some("ぴぃ"),
+        Some("ぴょ"),
+        Some("ぴゅ"),
+        Some("っくぁ"),
+        Some("っくぇ"),
+        Some("っくぃ"),
+        Some("っくぉ"),
+        Some("っくぅ"),
+        None,
+        None,
+        Some("くぁ"),
+        Some("くぇ"),
+        Some("くぃ"),
+        Some("くぉ"),
+        Some("くぅ"),
+        Some("くゃ"),
+        Some("くぇ"),
+        Some("くぃ"),
+        Some("くょ"),
+        Some("くゅ"),
+        Some("っら"),
+        Some("っれ"),
+        Some("っり"),
+        Some("っろ"),
+        Some("っる"),
+        None,
+        Some("りゃ"),
+        Some("りぇ"),
+        Some("りぃ"),
+        Some("りょ"),
+        Some("りゅ"),
+        Some("しゃ"),
+        Some("しぇ"),
+        Some("し"),
+        Some("しょ"),
+        Some("しゅ"),
+        None,
+        Some("っさ"),
+        Some("っせ"),
+        None,
+        Some("っし"),
+        Some("っそ"),
+        Some("っす"),
+        None,
+        None,
+        Some("すぁ"),
+        Some("すぇ"),
+        Some("すぃ"),
+        Some("すぉ"),
+        Some("すぅ"),
+        Some("しゃ"),
+        Some("しぇ"),
+        Some("しぃ"),
+        Some("しょ"),
+        Some("しゅ"),
+        Some("てゃ"),
+        Some("てぇ"),
+        Some("てぃ"),
+        Some("てょ"),
+        Some("てゅ"),
+        Some("つぁ"),
+        Some("つぇ"),
+        Some("つぃ"),
+        Some("つぉ"),
+        Some("つ"),
+        Some("った"),
+        Some("って"),
+        None,
+        Some("っち"),
+        Some("っと"),
+        None,
+        Some("っつ"),
+        None,
+        None,
+        Some("とぁ"),
+        Some("とぇ"),
+        Some("とぃ"),
+        Some("とぉ"),
+        Some("とぅ"),
+        Some("ちゃ"),
+        Some("ちぇ"),
+        Some("ちぃ"),
+        Some("ちょ"),
+        Some("ちゅ"),
+        Some("っゔぁ"),
+        Some("っゔぇ"),
+        Some("っゔぃ"),
+        Some("っゔぉ"),
+        Some("っゔ"),
+        None,
+        Some("ゔゃ"),
+        Some("ゔぇ"),
+        Some("ゔぃ"),
+        Some("ゔょ"),
+        Some("ゔゅ"),
+        Some("うぁ"),
+        Some("うぇ"),
+        Some("うぃ"),
+        Some("うぉ"),
+        Some("う"),
+        Some("っわ"),
+        Some("っうぇ"),
+        None,
+        Some("っうぃ"),
+        Some("っを"),
+        Some("っう"),
+        Some("ヵ"),
+        Some("ヶ"),
+        Some("ヵ"),
+        Some("ヶ"),
+        None,
+        Some("っ"),
+        Some("ゎ"),
+        Some("ゃ"),
+        Some("ぇ"),
+        Some("ぃ"),
+        Some("ょ"),
+        Some("ゅ"),
+        Some("っや"),
+        Some("っいぇ"),
+        Some("っい"),
+        Some("っよ"),
+        Some("っゆ"),
+        Some("じゃ"),
+        Some("じぇ"),
+        Some("じぃ"),
+        Some("じょ"),
+        Some("じゅ"),
+        Some("っざ"),
+        Some("っぜ"),
+        Some("っじ"),
+        Some("っぞ"),
+        Some("っず"),
+        None,
+        Some("っびゃ"),
+        Some("っびぇ"),
+        Some("っびぃ"),
+        Some("っびょ"),
+        Some("っびゅ"),
+        Some("っちゃ"),
+        Some("っちぇ"),
+        Some("っち"),
+        Some("っちょ"),
+        Some("っちゅ"),
+        None,
+        Some("っちゃ"),
+        Some("っちぇ"),
+        Some("っちぃ"),
+        Some("っちょ"),
+        Some("っちゅ"),
+        Some("ちゃ"),
+        Some("ちぇ"),
+        Some("ちぃ"),
+        Some("ちょ"),
+        Some("ちゅ"),
+        Some("っでゃ"),
+        Some("っでぇ"),
+        Some("っでぃ"),
+        Some("っでょ"),
+        Some("っでゅ"),
+        Some("っどぁ"),
+        Some("っどぇ"),
+        Some("っどぃ"),
+        Some("っどぉ"),
+        Some("っどぅ"),
+        Some("っぢゃ"),
+        Some("っぢぇ"),
+        Some("っぢぃ"),
+        Some("っぢょ"),
+        Some("っぢゅ"),
+        Some("っふぁ"),
+        Some("っふぇ"),
+        Some("っふぃ"),
+        Some("っふぉ"),
+        Some("っふぅ"),
+        Some("っふゃ"),
+        Some("っふぇ"),
+        Some("っふぃ"),
+        Some("っふょ"),
+        Some("っふゅ"),
+        Some("っぐぁ"),
+        Some("っぐぇ"),
+        Some("っぐぃ"),
+        Some("っぐぉ"),
+        Some("っぐぅ"),
+        Some("っぎゃ"),
+        Some("っぎぇ"),
+        Some("っぎぃ"),
+        Some("っぎょ"),
+        Some("っぎゅ"),
+        Some("っひゃ"),
+        Some("っひぇ"),
+        Some("っひぃ"),
+        Some("っひょ"),
+        Some("っひゅ"),
+        Some("っじゃ"),
+        Some("っじぇ"),
+        Some("っじぃ"),
+        Some("っじょ"),
+        Some("っじゅ"),
+        Some("っくぁ"),
+        Some("っきゃ"),
+        Some("っきぇ"),
+        Some("っきぃ"),
+        Some("っきょ"),
+        Some("っきゅ"),
+        Some("っ"),
+        Some("っみゃ"),
+        Some("っみぇ"),
+        Some("っみぃ"),
+        Some("っみょ"),
+        Some("っみゅ"),
+        Some("っぴゃ"),
+        Some("っぴぇ"),
+        Some("っぴぃ"),
+        Some("っぴょ"),
+        Some("っぴゅ"),
+        Some("っくぁ"),
+        Some("っくぇ"),
+        Some("っくぃ"),
+        Some("っくぉ"),
+        Some("っくぅ"),
+        Some("っくゃ"),
+        Some("っくぇ"),
+        Some("っくぃ"),
+        Some("っくょ"),
+        Some("っくゅ"),
+        Some("っりゃ"),
+        Some("っりぇ"),
+        Some("っりぃ"),
+        Some("っりょ"),
+        Some("っりゅ"),
+        Some("しゃ"),
+        Some("しぇ"),
+        Some("しぃ"),
+        Some("しょ"),
+        Some("しゅ"),
+        Some("っしゃ"),
+        Some("っしぇ"),
+        Some("っし"),
+        Some("っしょ"),
+        Some("っしゅ"),
+        None,
+        Some("っすぁ"),
+        Some("っすぇ"),
+        Some("っすぃ"),
+        Some("っすぉ"),
+        Some("っすぅ"),
+        Some("っしゃ"),
+        Some("っしぇ"),
+        Some("っしぃ"),
+        Some("っしょ"),
+        Some("っしゅ"),
+        Some("ってゃ"),
+        Some("ってぇ"),
+        Some("ってぃ"),
+        Some("ってょ"),
+        Some("ってゅ"),
+        Some("っつぁ"),
+        Some("っつぇ"),
+        Some("っつぃ"),
+        Some("っつぉ"),
+        Some("っつ"),
+        Some("っとぁ"),
+        Some("っとぇ"),
+        Some("っとぃ"),
+        Some("っとぉ"),
+        Some("っとぅ"),
+        Some("っちゃ"),
+        Some("っちぇ"),
+        Some("っちぃ"),
+        Some("っちょ"),
+        Some("っちゅ"),
+        Some("っゔゃ"),
+        Some("っゔぇ"),
+        Some("っゔぃ"),
+        Some("っゔょ"),
+        Some("っゔゅ"),
+        Some("っうぁ"),
+        Some("っうぇ"),
+        Some("っうぃ"),
+        Some("っうぉ"),
+        Some("っう"),
+        Some("っ"),
+        Some("っじゃ"),
+        Some("っじぇ"),
+        Some("っじぃ"),
+        Some("っじょ"),
+        Some("っじゅ"),
+        Some("っちゃ"),
+        Some("っちぇ"),
+        Some("っちぃ"),
+        Some("っちょ"),
+        Some("っちゅ"),
+        Some("っしゃ"),
+        Some("っしぇ"),
+        Some("っしぃ"),
+        Some("っしょ"),
+        Some("っしゅ"),
+    ],
+};
+
+pub(crate) static TO_KANA_TRIE_IMEMODE: Trie = Trie {
+    root: (0, 44),
+    transitions: &[
+        ('!', 44, 44),
+        ('(', 44, 44),
+        (')', 44, 44),
+        (',', 44, 44),
+        ('-', 44, 44),
+        ('.', 44, 44),
+        ('/', 44, 44),
+        (':', 44, 44),
+        ('?', 44, 44),
+        ('[', 44, 44),
+        (']', 44, 44),
+        ('a', 44, 44),
+        ('b', 44, 51),
+        ('c', 51, 59),
+        ('d', 59, 68),
+        ('e', 68, 68),
+        ('f', 68, 76),
+        ('g', 76, 84),
+        ('h', 84, 91),
+        ('i', 91, 91),
+        ('j', 91, 98),
+        ('k', 98, 106),
+        ('l', 106, 116),
+        ('m', 116, 123),
+        ('n', 123, 132),
+        ('o', 132, 132),
+        ('p', 132, 139),
+        ('q', 139, 147),
+        ('r', 147, 154),
+        ('s', 154, 163),
+        ('t', 163, 173),
+        ('u', 173, 173),
+        ('v', 173, 180),
+        ('w', 180, 187),
+        ('x', 187, 198),
+        ('y', 198, 204),
+        ('z', 204, 211),
+        ('{', 211, 211),
+        ('}', 211, 211),
+        ('~', 211, 211),
+        ('‘', 211, 211),
+        ('’', 211, 211),
+        ('“', 211, 211),
+        ('”', 211, 211),
+        ('a', 211, 211),
+        ('b', 211, 217),
+        ('e', 217, 217),
+        ('i', 217, 217),
+        ('o', 217, 217),
+        ('u', 217, 217),
+        ('y', 217, 222),
+        ('a', 222, 222),
+        ('c', 222, 229),
+        ('e', 229, 229),
+        ('h', 229, 235),
+        ('i', 235, 235),
+        ('o', 235, 235),
+        ('u', 235, 235),
+        ('y', 235, 240),
+        ('a', 240, 240),
+        ('d', 240, 248),
+        ('e', 248, 248),
+        ('h', 248, 253),
+        ('i', 253, 253),
+        ('o', 253, 253),
+        ('u', 253, 253),
+        ('w', 253, 258),
+        ('y', 258, 263),
+        ('a', 263, 263),
+        ('e', 263, 263),
+        ('f', 263, 270),
+        ('i', 270, 270),
+        ('o', 270, 270),
+        ('u', 270, 270),
+        ('w', 270, 275),
+        ('y', 275, 280),
+        ('a', 280, 280),
+        ('e', 280, 280),
+        ('g', 280, 287),
+        ('i', 287, 287),
+        ('o', 287, 287),
+        ('u', 287, 287),
+        ('w', 287, 292),
+        ('y', 292, 297),
+        ('a', 297, 297),
+        ('e', 297, 297),
+        ('h', 297, 303),
+        ('i', 303, 303),
+        ('o', 303, 303),
+        ('u', 303, 303),
+        ('y', 303, 308),
+        ('a', 308, 308),
+        ('e', 308, 308),
+        ('i', 308, 308),
+        ('j', 308, 314),
+        ('o', 314, 314),
+        ('u', 314, 314),
+        ('y', 314, 319),
+        ('a', 319, 319),
+        ('e', 319, 319),
+        ('i', 319, 319),
+        ('k', 319, 326),
+        ('o', 326, 326),
+        ('u', 326, 326),
+        ('w', 326, 327),
+        ('y', 327, 332),
+        ('a', 332, 332),
+        ('c', 332, 334),
+        ('e', 334, 334),
+        ('i', 334, 334),
+        ('k', 334, 336),
+        ('o', 336, 336),
+        ('t', 336, 338),
+        ('u', 338, 338),
+        ('w', 338, 339),
+        ('y', 339, 344),
+        ('a', 344, 344),
+        ('e', 344, 344),
+        ('i', 344, 344),
+        ('m', 344, 350),
+        ('o', 350, 350),
+        ('u', 350, 350),
+        ('y', 350, 355),
+        (' ', 355, 355),
+        ('\'', 355, 355),
+        ('a', 355, 355),
+        ('e', 355, 355),
+        ('i', 355, 355),
+        ('n', 355, 355),
+        ('o', 355, 355),
+        ('u', 355, 355),
+        ('y', 355, 360),
+        ('a', 360, 360),
+        ('e', 360, 360),
+        ('i', 360, 360),
+        ('o', 360, 360),
+        ('p', 360, 366),
+        ('u', 366, 366),
+        ('y', 366, 371),
+        ('a', 371, 371),
+        ('e', 371, 371),
+        ('i', 371, 371),
+        ('o', 371, 371),
+        ('q', 371, 378),
+        ('u', 378, 378),
+        ('w', 378, 383),
+        ('y', 383, 388),
+        ('a', 388, 388),
+        ('e', 388, 388),
+        ('i', 388, 388),
+        ('o', 388, 388),
+        ('r', 388, 394),
+        ('u', 394, 394),
+        ('y', 394, 399),
+        ('a', 399, 399),
+        ('e', 399, 399),
+        ('h', 399, 405),
+        ('i', 405, 405),
+        ('o', 405, 405),
+        ('s', 405, 413),
+        ('u', 413, 413),
+        ('w', 413, 418),
+        ('y', 418, 423),
+        ('a', 423, 423),
+        ('e', 423, 423),
+        ('h', 423, 428),
+        ('i', 428, 428),
+        ('o', 428, 428),
+        ('s', 428, 433),
+        ('t', 433, 442),
+        ('u', 442, 442),
+        ('w', 442, 447),
+        ('y', 447, 452),
+        ('a', 452, 452),
+        ('e', 452, 452),
+        ('i', 452, 452),
+        ('o', 452, 452),
+        ('u', 452, 452),
+        ('v', 452, 458),
+        ('y', 458, 463),
+        ('a', 463, 463),
+        ('e', 463, 463),
+        ('h', 463, 468),
+        ('i', 468, 468),
+        ('o', 468, 468),
+        ('u', 468, 468),
+        ('w', 468, 474),
+        ('a', 474, 474),
+        ('c', 474, 476),
+        ('e', 476, 476),
+        ('i', 476, 476),
+        ('k', 476, 478),
+        ('n', 478, 478),
+        ('o', 478, 478),
+        ('t', 478, 480),
+        ('u', 480, 480),
+        ('w', 480, 481),
+        ('y', 481, 486),
+        ('a', 486, 486),
+        ('e', 486, 486),
+        ('i', 486, 486),
+        ('o', 486, 486),
+        ('u', 486, 486),
+        ('y', 486, 491),
+        ('a', 491, 491),
+        ('e', 491, 491),
+        ('i', 491, 491),
+        ('o', 491, 491),
+        ('u', 491, 491),
+        ('y', 491, 496),
+        ('z', 496, 502),
+        ('a', 502, 502),
+        ('e', 502, 502),
+        ('i', 502, 502),
+        ('o', 502, 502),
+        ('u', 502, 502),
+        ('y', 502, 507),
+        ('a', 507, 507),
+        ('e', 507, 507),
+        ('i', 507, 507),
+        ('o', 507, 507),
+        ('u', 507, 507),
+        ('a', 507, 507),
+        ('e', 507, 507),
+        ('h', 507, 513),
+        ('i', 513, 513),
+        ('o', 513, 513),
+        ('u', 513, 513),
+        ('y', 513, 518),
+        ('a', 518, 518),
+        ('e', 518, 518),
+        ('i', 518, 518),
+        ('o', 518, 518),
+        ('u', 518, 518),
+        ('y', 518, 523),
+        ('a', 523, 523),
+        ('e', 523, 523),
+        ('i', 523, 523),
+        ('o', 523, 523),
+        ('u', 523, 523),
+        ('a', 523, 523),
+        ('e', 523, 523),
+        ('h', 523, 528),
+        ('i', 528, 528),
+        ('o', 528, 528),
+        ('u', 528, 528),
+        ('w', 528, 533),
+        ('y', 533, 538),
+        ('a', 538, 538),
+        ('e', 538, 538),
+        ('i', 538, 538),
+        ('o', 538, 538),
+        ('u', 538, 538),
+        ('a', 538, 538),
+        ('e', 538, 538),
+        ('i', 538, 538),
+        ('o', 538, 538),
+        ('u', 538, 538),
+        ('a', 538, 538),
+        ('e', 538, 538),
+        ('i', 538, 538),
+        ('o', 538, 538),
+        ('u', 538, 538),
+        ('a', 538, 538),
+        ('e', 538, 538),
+        ('i', 538, 538),
+        ('o', 538, 538),
+        ('u', 538, 538),
+        ('w', 538, 543),
+        ('y', 543, 548),
+        ('a', 548, 548),
+        ('e', 548, 548),
+        ('i', 548, 548),
+        ('o', 548, 548),
+        ('u', 548, 548),
+        ('a', 548, 548),
+        ('e', 548, 548),
+        ('i', 548, 548),
+        ('o', 548, 548),
+        ('u', 548, 548),
+        ('a', 548, 548),
+        ('e', 548, 548),
+        ('i', 548, 548),
+        ('o', 548, 548),
+        ('u', 548, 548),
+        ('w', 548, 553),
+        ('y', 553, 558),
+        ('a', 558, 558),
+        ('e', 558, 558),
+        ('i', 558, 558),
+        ('o', 558, 558),
+        ('u', 558, 558),
+        ('a', 558, 558),
+        ('e', 558, 558),
+        ('i', 558, 558),
+        ('o', 558, 558),
+        ('u', 558, 558),
+        ('a', 558, 558),
+        ('e', 558, 558),
+        ('i', 558, 558),
+        ('o', 558, 558),
+        ('u', 558, 558),
+        ('y', 558, 563),
+        ('a', 563, 563),
+        ('e', 563, 563),
+        ('i', 563, 563),
+        ('o', 563, 563),
+        ('u', 563, 563),
+        ('a', 563, 563),
+        ('e', 563, 563),
+        ('i', 563, 563),
+        ('o', 563, 563),
+        ('u', 563, 563),
+        ('y', 563, 568),
+        ('a', 568, 568),
+        ('e', 568, 568),
+        ('i', 568, 568),
+        ('o', 568, 568),
+        ('u', 568, 568),
+        ('a', 568, 568),
+        ('e', 568, 568),
+        ('i', 568, 568),
+        ('o', 568, 568),
+        ('u', 568, 568),
+        ('w', 568, 569),
+        ('y', 569, 574),
+        ('a', 574, 574),
+        ('a', 574, 574),
+        ('e', 574, 574),
+        ('i', 574, 574),
+        ('o', 574, 574),
+        ('u', 574, 574),
+        ('a', 574, 574),
+        ('e', 574, 574),
+        ('a', 574, 574),
+        ('e', 574, 574),
+        ('s', 574, 575),
+        ('u', 575, 575),
+        ('a', 575, 575),
+        ('a', 575, 575),
+        ('e', 575, 575),
+        ('i', 575, 575),
+        ('o', 575, 575),
+        ('u', 575, 575),
+        ('a', 575, 575),
+        ('e', 575, 575),
+        ('i', 575, 575),
+        ('o', 575, 575),
+        ('u', 575, 575),
+        ('y', 575, 580),
+        ('a', 580, 580),
+        ('e', 580, 580),
+        ('i', 580, 580),
+        ('o', 580, 580),
+        ('u', 580, 580),
+        ('a', 580, 580),
+        ('e', 580, 580),
+        ('i', 580, 580),
+        ('o', 580, 580),
+        ('u', 580, 580),
+        ('a', 580, 580),
+        ('e', 580, 580),
+        ('i', 580, 580),
+        ('o', 580, 580),
+        ('u', 580, 580),
+        ('y', 580, 585),
+        ('a', 585, 585),
+        ('e', 585, 585),
+        ('i', 585, 585),
+        ('o', 585, 585),
+        ('u', 585, 585),
+        ('a', 585, 585),
+        ('e', 585, 585),
+        ('i', 585, 585),
+        ('o', 585, 585),
+        ('u', 585, 585),
+        ('w', 585, 590),
+        ('y', 590, 595),
+        ('a', 595, 595),
+        ('e', 595, 595),
+        ('i', 595, 595),
+        ('o', 595, 595),
+        ('u', 595, 595),
+        ('a', 595, 595),
+        ('e', 595, 595),
+        ('i', 595, 595),
+        ('o', 595, 595),
+        ('u', 595, 595),
+        ('a', 595, 595),
+        ('e', 595, 595),
+        ('i', 595, 595),
+        ('o', 595, 595),
+        ('u', 595, 595),
+        ('y', 595, 600),
+        ('a', 600, 600),
+        ('e', 600, 600),
+        ('i', 600, 600),
+        ('o', 600, 600),
+        ('u', 600, 600),
+        ('a', 600, 600),
+        ('e', 600, 600),
+        ('i', 600, 600),
+        ('o', 600, 600),
+        ('u', 600, 600),
+        ('y', 600, 605),
+        ('a', 605, 605),
+        ('e', 605, 605),
+        ('h', 605, 611),
+        ('i', 611, 611),
+        ('o', 611, 611),
+        ('u', 611, 611),
+        ('w', 611, 616),
+        ('y', 616, 621),
+        ('a', 621, 621),
+        ('e', 621, 621),
+        ('i', 621, 621),
+        ('o', 621, 621),
+        ('u', 621, 621),
+        ('a', 621, 621),
+        ('e', 621, 621),
+        ('i', 621, 621),
+        ('o', 621, 621),
+        ('u', 621, 621),
+        ('a', 621, 621),
+        ('e', 621, 621),
+        ('i', 621, 621),
+        ('o', 621, 621),
+        ('u', 621, 621),
+        ('a', 621, 621),
+        ('e', 621, 621),
+        ('i', 621, 621),
+        ('o', 621, 621),
+        ('u', 621, 621),
+        ('a', 621, 621),
+        ('e', 621, 621),
+        ('h', 621, 626),
+        ('i', 626, 626),
+        ('o', 626, 626),
+        ('s', 626, 631),
+        ('u', 631, 631),
+        ('w', 631, 636),
+        ('y', 636, 641),
+        ('a', 641, 641),
+        ('e', 641, 641),
+        ('i', 641, 641),
+        ('o', 641, 641),
+        ('u', 641, 641),
+        ('a', 641, 641),
+        ('e', 641, 641),
+        ('i', 641, 641),
+        ('o', 641, 641),
+        ('u', 641, 641),
+        ('a', 641, 641),
+        ('e', 641, 641),
+        ('i', 641, 641),
+        ('o', 641, 641),
+        ('u', 641, 641),
+        ('y', 641, 646),
+        ('a', 646, 646),
+        ('e', 646, 646),
+        ('i', 646, 646),
+        ('o', 646, 646),
+        ('u', 646, 646),
+        ('a', 646, 646),
+        ('e', 646, 646),
+        ('i', 646, 646),
+        ('o', 646, 646),
+        ('u', 646, 646),
+        ('a', 646, 646),
+        ('e', 646, 646),
+        ('h', 646, 651),
+        ('i', 651, 651),
+        ('o', 651, 651),
+        ('u', 651, 651),
+        ('a', 651, 651),
+        ('e', 651, 651),
+        ('a', 651, 651),
+        ('e', 651, 651),
+        ('s', 651, 652),
+        ('u', 652, 652),
+        ('a', 652, 652),
+        ('a', 652, 652),
+        ('e', 652, 652),
+        ('i', 652, 652),
+        ('o', 652, 652),
+        ('u', 652, 652),
+        ('a', 652, 652),
+        ('e', 652, 652),
+        ('i', 652, 652),
+        ('o', 652, 652),
+        ('u', 652, 652),
+        ('a', 652, 652),
+        ('e', 652, 652),
+        ('i', 652, 652),
+        ('o', 652, 652),
+        ('u', 652, 652),
+        ('a', 652, 652),
+        ('e', 652, 652),
+        ('i', 652, 652),
+        ('o', 652, 652),
+        ('u', 652, 652),
+        ('y', 652, 657),
+        ('a', 657, 657),
+        ('e', 657, 657),
+        ('i', 657, 657),
+        ('o', 657, 657),
+        ('u', 657, 657),
+        ('a', 657, 657),
+        ('e', 657, 657),
+        ('i', 657, 657),
+        ('o', 657, 657),
+        ('u', 657, 657),
+        ('y', 657, 662),
+        ('a', 662, 662),
+        ('e', 662, 662),
+        ('i', 662, 662),
+        ('o', 662, 662),
+        ('u', 662, 662),
+        ('a', 662, 662),
+        ('e', 662, 662),
+        ('i', 662, 662),
+        ('o', 662, 662),
+        ('u', 662, 662),
+        ('a', 662, 662),
+        ('e', 662, 662),
+        ('i', 662, 662),
+        ('o', 662, 662),
+        ('u', 662, 662),
+        ('a', 662, 662),
+        ('e', 662, 662),
+        ('i', 662, 662),
+        ('o', 662, 662),
+        ('u', 662, 662),
+        ('a', 662, 662),
+        ('e', 662, 662),
+        ('i', 662, 662),
+        ('o', 662, 662),
+        ('u', 662, 662),
+        ('a', 662, 662),
+        ('e', 662, 662),
+        ('i', 662, 662),
+        ('o', 662, 662),
+        ('u', 662, 662),
+        ('a', 662, 662),
+        ('e', 662, 662),
+        ('i', 662, 662),
+        ('o', 662, 662),
+        ('u', 662, 662),
+        ('a', 662, 662),
+        ('e', 662, 662),
+        ('i', 662, 662),
+        ('o', 662, 662),
+        ('u', 662, 662),
+        ('a', 662, 662),
+        ('e', 662, 662),
+        ('i', 662, 662),
+        ('o', 662, 662),
+        ('u', 662, 662),
+        ('a', 662, 662),
+        ('e', 662, 662),
+        ('i', 662, 662),
+        ('o', 662, 662),
+        ('u', 662, 662),
+        ('a', 662, 662),
+        ('e', 662, 662),
+        ('i', 662, 662),
+        ('o', 662, 662),
+        ('u', 662, 662),
+        ('a', 662, 662),
+        ('a', 662, 662),
+        ('e', 662, 662),
+        ('i', 662, 662),
+        ('o', 662, 662),
+        ('u', 662, 662),
+        ('u', 662, 662),
+        ('a', 662, 662),
+        ('e', 662, 662),
+        ('i', 662, 662),
+        ('o', 662, 662),
+        ('u', 662, 662),
+        ('a', 662, 662),
+        ('e', 662, 662),
+        ('i', 662, 662),
+        ('o', 662, 662),
+        ('u', 662, 662),
+        ('a', 662, 662),
+        ('e', 662, 662),
+        ('i', 662, 662),
+        ('o', 662, 662),
+        ('u', 662, 662),
+        ('a', 662, 662),
+        ('e', 662, 662),
+        ('i', 662, 662),
+        ('o', 662, 662),
+        ('u', 662, 662),
+        ('a', 662, 662),
+        ('e', 662, 662),
+        ('i', 662, 662),
+        ('o', 662, 662),
+        ('u', 662, 662),
+        ('a', 662, 662),
+        ('e', 662, 662),
+        ('i', 662, 662),
+        ('o', 662, 662),
+        ('u', 662, 662),
+        ('a', 662, 662),
+        ('e', 662, 662),
+        ('i', 662, 662),
+        ('o', 662, 662),
+        ('u', 662, 662),
+        ('y', 662, 667),
+        ('a', 667, 667),
+        ('e', 667, 667),
+        ('i', 667, 667),
+        ('o', 667, 667),
+        ('u', 667, 667),
+        ('a', 667, 667),
+        ('e', 667, 667),
+        ('i', 667, 667),
+        ('o', 667, 667),
+        ('u', 667, 667),
+        ('a', 667, 667),
+        ('e', 667, 667),
+        ('i', 667, 667),
+        ('o', 667, 667),
+        ('u', 667, 667),
+        ('a', 667, 667),
+        ('e', 667, 667),
+        ('i', 667, 667),
+        ('o', 667, 667),
+        ('u', 667, 667),
+        ('a', 667, 667),
+        ('e', 667, 667),
+        ('i', 667, 667),
+        ('o', 667, 667),
+        ('u', 667, 667),
+        ('a', 667, 667),
+        ('e', 667, 667),
+        ('i', 667, 667),
+        ('o', 667, 667),
+        ('u', 667, 667),
+        ('a', 667, 667),
+        ('e', 667, 667),
+        ('i', 667, 667),
+        ('o', 667, 667),
+        ('u', 667, 667),
+        ('a', 667, 667),
+        ('e', 667, 667),
+        ('i', 667, 667),
+        ('o', 667, 667),
+        ('u', 667, 667),
+        ('u', 667, 667),
+        ('a', 667, 667),
+        ('e', 667, 667),
+        ('i', 667, 667),
+        ('o', 667, 667),
+        ('u', 667, 667),
+        ('a', 667, 667),
+        ('e', 667, 667),
+        ('i', 667, 667),
+        ('o', 667, 667),
+        ('u', 667, 667),
+        ('a', 667, 667),
+        ('e', 667, 667),
+        ('i', 667, 667),
+        ('o', 667, 667),
+        ('u', 667, 667),
+    ],
+    outputs: &[
+        Some("！"),
+        Some("（"),
+        Some("）"),
+        Some("、"),
+        Some("ー"),
+        Some("。"),
+        Some("・"),
+        Some("："),
+        Some("？"),
+        Some("［"),
+        Some("］"),
+        Some("あ"),
+        None,
+        None,
+        None,
+        Some("え"),
+        None,
+        None,
+        None,
+        Some("い"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("お"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("う"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("｛"),
+        Some("｝"),
+        Some("〜"),
+        Some("「"),
+        Some("」"),
+        Some("『"),
+        Some("』"),
+        Some("ば"),
+        None,
+        Some("べ"),
+        Some("び"),
+        Some("ぼ"),
+        Some("ぶ"),
+        None,
+        Some("か"),
+        None,
+        Some("け"),
+        None,
+        Some("き"),
+        Some("こ"),
+        Some("く"),
+        None,
+        Some("だ"),
+        None,
+        Some("で"),
+        None,
+        Some("ぢ"),
+        Some("ど"),
+        Some("づ"),
+        None,
+        None,
+        Some("ふぁ"),
+        Some("ふぇ"),
+        None,
+        Some("ふぃ"),
+        Some("ふぉ"),
+        Some("ふ"),
+        None,
+        None,
+        Some("が"),
+        Some("げ"),
+        None,
+        Some("ぎ"),
+        Some("ご"),
+        Some("ぐ"),
+        None,
+        None,
+        Some("は"),
+        Some("へ"),
+        None,
+        Some("ひ"),
+        Some("ほ"),
+        Some("ふ"),
+        None,
+        Some("じゃ"),
+        Some("じぇ"),
+        Some("じ"),
+        None,
+        Some("じょ"),
+        Some("じゅ"),
+        None,
+        Some("か"),
+        Some("け"),
+        Some("き"),
+        None,
+        Some("こ"),
+        Some("く"),
+        None,
+        None,
+        Some("ぁ"),
+        None,
+        Some("ぇ"),
+        Some("ぃ"),
+        None,
+        Some("ぉ"),
+        None,
+        Some("ぅ"),
+        None,
+        None,
+        Some("ま"),
+        Some("め"),
+        Some("み"),
+        None,
+        Some("も"),
+        Some("む"),
+        None,
+        Some("ん"),
+        Some("ん"),
+        Some("な"),
+        Some("ね"),
+        Some("に"),
+        Some("ん"),
+        Some("の"),
+        Some("ぬ"),
+        None,
+        Some("ぱ"),
+        Some("ぺ"),
+        Some("ぴ"),
+        Some("ぽ"),
+        None,
+        Some("ぷ"),
+        None,
+        Some("くぁ"),
+        Some("くぇ"),
+        Some("くぃ"),
+        Some("くぉ"),
+        None,
+        Some("くぅ"),
+        None,
+        None,
+        Some("ら"),
+        Some("れ"),
+        Some("り"),
+        Some("ろ"),
+        None,
+        Some("る"),
+        None,
+        Some("さ"),
+        Some("せ"),
+        None,
+        Some("し"),
+        Some("そ"),
+        None,
+        Some("す"),
+        None,
+        None,
+        Some("た"),
+        Some("て"),
+        None,
+        Some("ち"),
+        Some("と"),
+        None,
+        None,
+        Some("つ"),
+        None,
+        None,
+        Some("ゔぁ"),
+        Some("ゔぇ"),
+        Some("ゔぃ"),
+        Some("ゔぉ"),
+        Some("ゔ"),
+        None,
+        None,
+        Some("わ"),
+        Some("うぇ"),
+        None,
+        Some("うぃ"),
+        Some("を"),
+        Some("う"),
+        None,
+        Some("ぁ"),
+        None,
+        Some("ぇ"),
+        Some("ぃ"),
+        None,
+        Some("ん"),
+        Some("ぉ"),
+        None,
+        Some("ぅ"),
+        None,
+        None,
+        Some("や"),
+        Some("いぇ"),
+        Some("い"),
+        Some("よ"),
+        Some("ゆ"),
+        None,
+        Some("ざ"),
+        Some("ぜ"),
+        Some("じ"),
+        Some("ぞ"),
+        Some("ず"),
+        None,
+        None,
+        Some("っば"),
+        Some("っべ"),
+        Some("っび"),
+        Some("っぼ"),
+        Some("っぶ"),
+        None,
+        Some("びゃ"),
+        Some("びぇ"),
+        Some("びぃ"),
+        Some("びょ"),
+        Some("びゅ"),
+        Some("っか"),
+        Some("っけ"),
+        None,
+        Some("っき"),
+        Some("っこ"),
+        Some("っく"),
+        None,
+        Some("ちゃ"),
+        Some("ちぇ"),
+        Some("ち"),
+        Some("ちょ"),
+        Some("ちゅ"),
+        None,
+        Some("ちゃ"),
+        Some("ちぇ"),
+        Some("ちぃ"),
+        Some("ちょ"),
+        Some("ちゅ"),
+        Some("っだ"),
+        Some("っで"),
+        None,
+        Some("っぢ"),
+        Some("っど"),
+        Some("っづ"),
+        None,
+        None,
+        Some("でゃ"),
+        Some("でぇ"),
+        Some("でぃ"),
+        Some("でょ"),
+        Some("でゅ"),
+        Some("どぁ"),
+        Some("どぇ"),
+        Some("どぃ"),
+        Some("どぉ"),
+        Some("どぅ"),
+        Some("ぢゃ"),
+        Some("ぢぇ"),
+        Some("ぢぃ"),
+        Some("ぢょ"),
+        Some("ぢゅ"),
+        Some("っふぁ"),
+        Some("っふぇ"),
+        Some("っふぃ"),
+        Some("っふぉ"),
+        Some("っふ"),
+        None,
+        None,
+        Some("ふぁ"),
+        Some("ふぇ"),
+        Some("ふぃ"),
+        Some("ふぉ"),
+        Some("ふぅ"),
+        Some("ふゃ"),
+        Some("ふぇ"),
+        Some("ふぃ"),
+        Some("ふょ"),
+        Some("ふゅ"),
+        Some("っが"),
+        Some("っげ"),
+        Some("っぎ"),
+        Some("っご"),
+        Some("っぐ"),
+        None,
+        None,
+        Some("ぐぁ"),
+        Some("ぐぇ"),
+        Some("ぐぃ"),
+        Some("ぐぉ"),
+        Some("ぐぅ"),
+        Some("ぎゃ"),
+        Some("ぎぇ"),
+        Some("ぎぃ"),
+        Some("ぎょ"),
+        Some("ぎゅ"),
+        Some("っは"),
+        Some("っへ"),
+        Some("っひ"),
+        Some("っほ"),
+        Some("っふ"),
+        None,
+        Some("ひゃ"),
+        Some("ひぇ"),
+        Some("ひぃ"),
+        Some("ひょ"),
+        Some("ひゅ"),
+        Some("っじゃ"),
+        Some("っじぇ"),
+        Some("っじ"),
+        Some("っじょ"),
+        Some("っじゅ"),
+        None,
+        Some("じゃ"),
+        Some("じぇ"),
+        Some("じぃ"),
+        Some("じょ"),
+        Some("じゅ"),
+        Some("っか"),
+        Some("っけ"),
+        Some("っき"),
+        Some("っこ"),
+        Some("っく"),
+        None,
+        None,
+        Some("くぁ"),
+        Some("きゃ"),
+        Some("きぇ"),
+        Some("きぃ"),
+        Some("きょ"),
+        Some("きゅ"),
+        Some("ヵ"),
+        Some("ヶ"),
+        Some("ヵ"),
+        Some("ヶ"),
+        None,
+        Some("っ"),
+        Some("ゎ"),
+        Some("ゃ"),
+        Some("ぇ"),
+        Some("ぃ"),
+        Some("ょ"),
+        Some("ゅ"),
+        Some("っま"),
+        Some("っめ"),
+        Some("っみ"),
+        Some("っも"),
+        Some("っむ"),
+        None,
+        Some("みゃ"),
+        Some("みぇ"),
+        Some("みぃ"),
+        Some("みょ"),
+        Some("みゅ"),
+        Some("にゃ"),
+        Some("にぇ"),
+        Some("にぃ"),
+        Some("にょ"),
+        Some("にゅ"),
+        Some("っぱ"),
+        Some("っぺ"),
+        Some("っぴ"),
+        Some("っぽ"),
+        Some("っぷ"),
+        None,
+        Some("ぴゃ"),
+        Some("ぴぇ"),
+        Some("ぴぃ"),
+        Some("ぴょ"),
+        Some("ぴゅ"),
+        Some("っくぁ"),
+        Some("っくぇ"),
+        Some("っくぃ"),
+        Some("っくぉ"),
+        Some("っくぅ"),
+        None,
+        None,
+        Some("くぁ"),
+        Some("くぇ"),
+        Some("くぃ"),
+        Some("くぉ"),
+        Some("くぅ"),
+        Some("くゃ"),
+        Some("くぇ"),
+        Some("くぃ"),
+        Some("くょ"),
+        Some("くゅ"),
+        Some("っら"),
+        Some("っれ"),
+        Some("っり"),
+        Some("っろ"),
+        Some("っる"),
+        None,
+        Some("りゃ"),
+        Some("りぇ"),
+        Some("りぃ"),
+        Some("りょ"),
+        Some("りゅ"),
+        Some("しゃ"),
+        Some("しぇ"),
+        Some("し"),
+        Some("しょ"),
+        Some("しゅ"),
+        None,
+        Some("っさ"),
+        Some("っせ"),
+        None,
+        Some("っし"),
+        Some("っそ"),
+        Some("っす"),
+        None,
+        None,
+        Some("すぁ"),
+        Some("すぇ"),
+        Some("すぃ"),
+        Some("すぉ"),
+        Some("すぅ"),
+        Some("しゃ"),
+        Some("しぇ"),
+        Some("しぃ"),
+        Some("しょ"),
+        Some("しゅ"),
+        Some("てゃ"),
+        Some("てぇ"),
+        Some("てぃ"),
+        Some("てょ"),
+        Some("てゅ"),
+        Some("つぁ"),
+        Some("つぇ"),
+        Some("つぃ"),
+        Some("つぉ"),
+        Some("つ"),
+        Some("った"),
+        Some("って"),
+        None,
+        Some("っち"),
+        Some("っと"),
+        None,
+        Some("っつ"),
+        None,
+        None,
+        Some("とぁ"),
+        Some("とぇ"),
+        Some("とぃ"),
+        Some("とぉ"),
+        Some("とぅ"),
+        Some("ちゃ"),
+        Some("ちぇ"),
+        Some("ちぃ"),
+        Some("ちょ"),
+        Some("ちゅ"),
+        Some("っゔぁ"),
+        Some("っゔぇ"),
+        Some("っゔぃ"),
+        Some("っゔぉ"),
+        Some("っゔ"),
+        None,
+        Some("ゔゃ"),
+        Some("ゔぇ"),
+        Some("ゔぃ"),
+        Some("ゔょ"),
+        Some("ゔゅ"),
+        Some("うぁ"),
+        Some("うぇ"),
+        Some("うぃ"),
+        Some("うぉ"),
+        Some("う"),
+        Some("っわ"),
+        Some("っうぇ"),
+        None,
+        Some("っうぃ"),
+        Some("っを"),
+        Some("っう"),
+        Some("ヵ"),
+        Some("ヶ"),
+        Some("ヵ"),
+        Some("ヶ"),
+        None,
+        Some("っ"),
+        Some("ゎ"),
+        Some("ゃ"),
+        Some("ぇ"),
+        Some("ぃ"),
+        Some("ょ"),
+        Some("ゅ"),
+        Some("っや"),
+        Some("っいぇ"),
+        Some("っい"),
+        Some("っよ"),
+        Some("っゆ"),
+        Some("じゃ"),
+        Some("じぇ"),
+        Some("じぃ"),
+        Some("じょ"),
+        Some("じゅ"),
+        Some("っざ"),
+        Some("っぜ"),
+        Some("っじ"),
+        Some("っぞ"),
+        Some("っず"),
+        None,
+        Some("っびゃ"),
+        Some("っびぇ"),
+        Some("っびぃ"),
+        Some("っびょ"),
+        Some("っびゅ"),
+        Some("っちゃ"),
+        Some("っちぇ"),
+        Some("っち"),
+        Some("っちょ"),
+        Some("っちゅ"),
+        None,
+        Some("っちゃ"),
+        Some("っちぇ"),
+        Some("っちぃ"),
+        Some("っちょ"),
+        Some("っちゅ"),
+        Some("ちゃ"),
+        Some("ちぇ"),
+        Some("ちぃ"),
+        Some("ちょ"),
+        Some("ちゅ"),
+        Some("っでゃ"),
+        Some("っでぇ"),
+        Some("っでぃ"),
+        Some("っでょ"),
+        Some("っでゅ"),
+        Some("っどぁ"),
+        Some("っどぇ"),
+        Some("っどぃ"),
+        Some("っどぉ"),
+        Some("っどぅ"),
+        Some("っぢゃ"),
+        Some("っぢぇ"),
+        Some("っぢぃ"),
+        Some("っぢょ"),
+        Some("っぢゅ"),
+        Some("っふぁ"),
+        Some("っふぇ"),
+        Some("っふぃ"),
+        Some("っふぉ"),
+        Some("っふぅ"),
+        Some("っふゃ"),
+        Some("っふぇ"),
+        Some("っふぃ"),
+        Some("っふょ"),
+        Some("っふゅ"),
+        Some("っぐぁ"),
+        Some("っぐぇ"),
+        Some("っぐぃ"),
+        Some("っぐぉ"),
+        Some("っぐぅ"),
+        Some("っぎゃ"),
+        Some("っぎぇ"),
+        Some("っぎぃ"),
+        Some("っぎょ"),
+        Some("っぎゅ"),
+        Some("っひゃ"),
+        Some("っひぇ"),
+        Some("っひぃ"),
+        Some("っひょ"),
+        Some("っひゅ"),
+        Some("っじゃ"),
+        Some("っじぇ"),
+        Some("っじぃ"),
+        Some("っじょ"),
+        Some("っじゅ"),
+        Some("っくぁ"),
+        Some("っきゃ"),
+        Some("っきぇ"),
+        Some("っきぃ"),
+        Some("っきょ"),
+        Some("っきゅ"),
+        Some("っ"),
+        Some("っみゃ"),
+        Some("っみぇ"),
+        Some("っみぃ"),
+        Some("っみょ"),
+        Some("っみゅ"),
+        Some("っぴゃ"),
+        Some("っぴぇ"),
+        Some("っぴぃ"),
+        Some("っぴょ"),
+        Some("っぴゅ"),
+        Some("っくぁ"),
+        Some("っくぇ"),
+        Some("っくぃ"),
+        Some("っくぉ"),
+        Some("っくぅ"),
+        Some("っくゃ"),
+        Some("っくぇ"),
+        Some("っくぃ"),
+        Some("っくょ"),
+        Some("っくゅ"),
+        Some("っりゃ"),
+        Some("っりぇ"),
+        Some("っりぃ"),
+        Some("っりょ"),
+        Some("っりゅ"),
+        Some("しゃ"),
+        Some("しぇ"),
+        Some("しぃ"),
+        Some("しょ"),
+        Some("しゅ"),
+        Some("っしゃ"),
+        Some("っしぇ"),
+        Some("っし"),
+        Some("っしょ"),
+        Some("っしゅ"),
+        None,
+        Some("っすぁ"),
+        Some("っすぇ"),
+        Some("っすぃ"),
+        Some("っすぉ"),
+        Some("っすぅ"),
+        Some("っしゃ"),
+        Some("っしぇ"),
+        Some("っしぃ"),
+        Some("っしょ"),
+        Some("っしゅ"),
+        Some("ってゃ"),
+        Some("ってぇ"),
+        Some("ってぃ"),
+        Some("ってょ"),
+        Some("ってゅ"),
+        Some("っつぁ"),
+        Some("っつぇ"),
+        Some("っつぃ"),
+        Some("っつぉ"),
+        Some("っつ"),
+        Some("っとぁ"),
+        Some("っとぇ"),
+        Some("っとぃ"),
+        Some("っとぉ"),
+        Some("っとぅ"),
+        Some("っちゃ"),
+        Some("っちぇ"),
+        Some("っちぃ"),
+        Some("っちょ"),
+        Some("っちゅ"),
+        Some("っゔゃ"),
+        Some("っゔぇ"),
+        Some("っゔぃ"),
+        Some("っゔょ"),
+        Some("っゔゅ"),
+        Some("っうぁ"),
+        Some("っうぇ"),
+        Some("っうぃ"),
+        Some("っうぉ"),
+        Some("っう"),
+        Some("っ"),
+        Some("っじゃ"),
+        Some("っじぇ"),
+        Some("っじぃ"),
+        Some("っじょ"),
+        Some("っじゅ"),
+        Some("っちゃ"),
+        Some("っちぇ"),
+        Some("っちぃ"),
+        Some("っちょ"),
+        Some("っちゅ"),
+        Some("っしゃ"),
+        Some("っしぇ"),
+        Some("っしぃ"),
+        Some("っしょ"),
+        Some("っしゅ"),
+    ],
+};
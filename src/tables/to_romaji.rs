@@ -0,0 +1,3627 @@
+//! Generated by upstream_conversions from data/to_romaji.json, do not edit.
+
+use crate::trie::Trie;
+
+pub(crate) static TO_ROMAJI_TRIE: Trie = Trie {
+    root: (0, 107),
+    transitions: &[
+        ('\u{3000}', 107, 107),
+        ('、', 107, 107),
+        ('。', 107, 107),
+        ('「', 107, 107),
+        ('」', 107, 107),
+        ('『', 107, 107),
+        ('』', 107, 107),
+        ('〜', 107, 107),
+        ('ぁ', 107, 107),
+        ('あ', 107, 107),
+        ('ぃ', 107, 107),
+        ('い', 107, 107),
+        ('ぅ', 107, 107),
+        ('う', 107, 107),
+        ('ぇ', 107, 107),
+        ('え', 107, 107),
+        ('ぉ', 107, 107),
+        ('お', 107, 107),
+        ('か', 107, 107),
+        ('が', 107, 107),
+        ('き', 107, 112),
+        ('ぎ', 112, 117),
+        ('く', 117, 122),
+        ('ぐ', 122, 122),
+        ('け', 122, 122),
+        ('げ', 122, 122),
+        ('こ', 122, 122),
+        ('ご', 122, 122),
+        ('さ', 122, 122),
+        ('ざ', 122, 122),
+        ('し', 122, 127),
+        ('じ', 127, 132),
+        ('す', 132, 132),
+        ('ず', 132, 132),
+        ('せ', 132, 132),
+        ('ぜ', 132, 132),
+        ('そ', 132, 132),
+        ('ぞ', 132, 132),
+        ('た', 132, 132),
+        ('だ', 132, 132),
+        ('ち', 132, 137),
+        ('ぢ', 137, 142),
+        ('っ', 142, 243),
+        ('つ', 243, 243),
+        ('づ', 243, 243),
+        ('て', 243, 243),
+        ('で', 243, 243),
+        ('と', 243, 243),
+        ('ど', 243, 243),
+        ('な', 243, 243),
+        ('に', 243, 248),
+        ('ぬ', 248, 248),
+        ('ね', 248, 248),
+        ('の', 248, 248),
+        ('は', 248, 248),
+        ('ば', 248, 248),
+        ('ぱ', 248, 248),
+        ('ひ', 248, 253),
+        ('び', 253, 258),
+        ('ぴ', 258, 263),
+        ('ふ', 263, 268),
+        ('ぶ', 268, 268),
+        ('ぷ', 268, 268),
+        ('へ', 268, 268),
+        ('べ', 268, 268),
+        ('ぺ', 268, 268),
+        ('ほ', 268, 268),
+        ('ぼ', 268, 268),
+        ('ぽ', 268, 268),
+        ('ま', 268, 268),
+        ('み', 268, 273),
+        ('む', 273, 273),
+        ('め', 273, 273),
+        ('も', 273, 273),
+        ('ゃ', 273, 273),
+        ('や', 273, 273),
+        ('ゅ', 273, 273),
+        ('ゆ', 273, 273),
+        ('ょ', 273, 273),
+        ('よ', 273, 273),
+        ('ら', 273, 273),
+        ('り', 273, 278),
+        ('る', 278, 278),
+        ('れ', 278, 278),
+        ('ろ', 278, 278),
+        ('ゎ', 278, 278),
+        ('わ', 278, 278),
+        ('ゐ', 278, 278),
+        ('ゑ', 278, 278),
+        ('を', 278, 278),
+        ('ん', 278, 286),
+        ('ゔ', 286, 293),
+        ('ヷ', 293, 293),
+        ('ヸ', 293, 293),
+        ('ヹ', 293, 293),
+        ('ヺ', 293, 293),
+        ('・', 293, 293),
+        ('ー', 293, 293),
+        ('！', 293, 293),
+        ('（', 293, 293),
+        ('）', 293, 293),
+        ('：', 293, 293),
+        ('？', 293, 293),
+        ('［', 293, 293),
+        ('］', 293, 293),
+        ('｛', 293, 293),
+        ('｝', 293, 293),
+        ('ぃ', 293, 293),
+        ('ぇ', 293, 293),
+        ('ゃ', 293, 293),
+        ('ゅ', 293, 293),
+        ('ょ', 293, 293),
+        ('ぃ', 293, 293),
+        ('ぇ', 293, 293),
+        ('ゃ', 293, 293),
+        ('ゅ', 293, 293),
+        ('ょ', 293, 293),
+        ('ぃ', 293, 293),
+        ('ぇ', 293, 293),
+        ('ゃ', 293, 293),
+        ('ゅ', 293, 293),
+        ('ょ', 293, 293),
+        ('ぃ', 293, 293),
+        ('ぇ', 293, 293),
+        ('ゃ', 293, 293),
+        ('ゅ', 293, 293),
+        ('ょ', 293, 293),
+        ('ぃ', 293, 293),
+        ('ぇ', 293, 293),
+        ('ゃ', 293, 293),
+        ('ゅ', 293, 293),
+        ('ょ', 293, 293),
+        ('ぃ', 293, 293),
+        ('ぇ', 293, 293),
+        ('ゃ', 293, 293),
+        ('ゅ', 293, 293),
+        ('ょ', 293, 293),
+        ('ぃ', 293, 293),
+        ('ぇ', 293, 293),
+        ('ゃ', 293, 293),
+        ('ゅ', 293, 293),
+        ('ょ', 293, 293),
+        ('\u{3000}', 293, 293),
+        ('、', 293, 293),
+        ('。', 293, 293),
+        ('「', 293, 293),
+        ('」', 293, 293),
+        ('『', 293, 293),
+        ('』', 293, 293),
+        ('〜', 293, 293),
+        ('ぁ', 293, 293),
+        ('あ', 293, 293),
+        ('ぃ', 293, 293),
+        ('い', 293, 293),
+        ('ぅ', 293, 293),
+        ('う', 293, 293),
+        ('ぇ', 293, 293),
+        ('え', 293, 293),
+        ('ぉ', 293, 293),
+        ('お', 293, 293),
+        ('か', 293, 293),
+        ('が', 293, 293),
+        ('き', 293, 298),
+        ('ぎ', 298, 303),
+        ('く', 303, 308),
+        ('ぐ', 308, 308),
+        ('け', 308, 308),
+        ('げ', 308, 308),
+        ('こ', 308, 308),
+        ('ご', 308, 308),
+        ('さ', 308, 308),
+        ('ざ', 308, 308),
+        ('し', 308, 313),
+        ('じ', 313, 318),
+        ('す', 318, 318),
+        ('ず', 318, 318),
+        ('せ', 318, 318),
+        ('ぜ', 318, 318),
+        ('そ', 318, 318),
+        ('ぞ', 318, 318),
+        ('た', 318, 318),
+        ('だ', 318, 318),
+        ('ち', 318, 323),
+        ('ぢ', 323, 328),
+        ('つ', 328, 328),
+        ('づ', 328, 328),
+        ('て', 328, 328),
+        ('で', 328, 328),
+        ('と', 328, 328),
+        ('ど', 328, 328),
+        ('な', 328, 328),
+        ('に', 328, 333),
+        ('ぬ', 333, 333),
+        ('ね', 333, 333),
+        ('の', 333, 333),
+        ('は', 333, 333),
+        ('ば', 333, 333),
+        ('ぱ', 333, 333),
+        ('ひ', 333, 338),
+        ('び', 338, 343),
+        ('ぴ', 343, 348),
+        ('ふ', 348, 353),
+        ('ぶ', 353, 353),
+        ('ぷ', 353, 353),
+        ('へ', 353, 353),
+        ('べ', 353, 353),
+        ('ぺ', 353, 353),
+        ('ほ', 353, 353),
+        ('ぼ', 353, 353),
+        ('ぽ', 353, 353),
+        ('ま', 353, 353),
+        ('み', 353, 358),
+        ('む', 358, 358),
+        ('め', 358, 358),
+        ('も', 358, 358),
+        ('ゃ', 358, 358),
+        ('や', 358, 358),
+        ('ゅ', 358, 358),
+        ('ゆ', 358, 358),
+        ('ょ', 358, 358),
+        ('よ', 358, 358),
+        ('ら', 358, 358),
+        ('り', 358, 363),
+        ('る', 363, 363),
+        ('れ', 363, 363),
+        ('ろ', 363, 363),
+        ('わ', 363, 363),
+        ('ゐ', 363, 363),
+        ('ゑ', 363, 363),
+        ('を', 363, 363),
+        ('ん', 363, 363),
+        ('ゔ', 363, 370),
+        ('・', 370, 370),
+        ('ー', 370, 370),
+        ('！', 370, 370),
+        ('（', 370, 370),
+        ('）', 370, 370),
+        ('：', 370, 370),
+        ('？', 370, 370),
+        ('［', 370, 370),
+        ('］', 370, 370),
+        ('｛', 370, 370),
+        ('｝', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('あ', 370, 370),
+        ('い', 370, 370),
+        ('う', 370, 370),
+        ('え', 370, 370),
+        ('お', 370, 370),
+        ('や', 370, 370),
+        ('ゆ', 370, 370),
+        ('よ', 370, 370),
+        ('ぁ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ぉ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぁ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ぉ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+    ],
+    outputs: &[
+        Some(" "),
+        Some(","),
+        Some("."),
+        Some("‘"),
+        Some("’"),
+        Some("“"),
+        Some("”"),
+        Some("~"),
+        Some("a"),
+        Some("a"),
+        Some("i"),
+        Some("i"),
+        Some("u"),
+        Some("u"),
+        Some("e"),
+        Some("e"),
+        Some("o"),
+        Some("o"),
+        Some("ka"),
+        Some("ga"),
+        Some("ki"),
+        Some("gi"),
+        Some("ku"),
+        Some("gu"),
+        Some("ke"),
+        Some("ge"),
+        Some("ko"),
+        Some("go"),
+        Some("sa"),
+        Some("za"),
+        Some("shi"),
+        Some("ji"),
+        Some("su"),
+        Some("zu"),
+        Some("se"),
+        Some("ze"),
+        Some("so"),
+        Some("zo"),
+        Some("ta"),
+        Some("da"),
+        Some("chi"),
+        Some("ji"),
+        Some(""),
+        Some("tsu"),
+        Some("zu"),
+        Some("te"),
+        Some("de"),
+        Some("to"),
+        Some("do"),
+        Some("na"),
+        Some("ni"),
+        Some("nu"),
+        Some("ne"),
+        Some("no"),
+        Some("ha"),
+        Some("ba"),
+        Some("pa"),
+        Some("hi"),
+        Some("bi"),
+        Some("pi"),
+        Some("fu"),
+        Some("bu"),
+        Some("pu"),
+        Some("he"),
+        Some("be"),
+        Some("pe"),
+        Some("ho"),
+        Some("bo"),
+        Some("po"),
+        Some("ma"),
+        Some("mi"),
+        Some("mu"),
+        Some("me"),
+        Some("mo"),
+        Some("ya"),
+        Some("ya"),
+        Some("yu"),
+        Some("yu"),
+        Some("yo"),
+        Some("yo"),
+        Some("ra"),
+        Some("ri"),
+        Some("ru"),
+        Some("re"),
+        Some("ro"),
+        Some("wa"),
+        Some("wa"),
+        Some("wi"),
+        Some("we"),
+        Some("wo"),
+        Some("n"),
+        Some("vu"),
+        Some("va"),
+        Some("vi"),
+        Some("ve"),
+        Some("vo"),
+        Some("/"),
+        Some("-"),
+        Some("!"),
+        Some("("),
+        Some(")"),
+        Some(":"),
+        Some("?"),
+        Some("["),
+        Some("]"),
+        Some("{"),
+        Some("}"),
+        Some("kyi"),
+        Some("kye"),
+        Some("kya"),
+        Some("kyu"),
+        Some("kyo"),
+        Some("gyi"),
+        Some("gye"),
+        Some("gya"),
+        Some("gyu"),
+        Some("gyo"),
+        Some("kyi"),
+        Some("kye"),
+        Some("kya"),
+        Some("kyu"),
+        Some("kyo"),
+        Some("shyi"),
+        Some("she"),
+        Some("sha"),
+        Some("shu"),
+        Some("sho"),
+        Some("jyi"),
+        Some("je"),
+        Some("ja"),
+        Some("ju"),
+        Some("jo"),
+        Some("chyi"),
+        Some("che"),
+        Some("cha"),
+        Some("chu"),
+        Some("cho"),
+        Some("jyi"),
+        Some("je"),
+        Some("ja"),
+        Some("ju"),
+        Some("jo"),
+        Some(" "),
+        Some(","),
+        Some("."),
+        Some("‘"),
+        Some("’"),
+        Some("“"),
+        Some("”"),
+        Some("~"),
+        Some("a"),
+        Some("a"),
+        Some("i"),
+        Some("i"),
+        Some("u"),
+        Some("u"),
+        Some("e"),
+        Some("e"),
+        Some("o"),
+        Some("o"),
+        Some("kka"),
+        Some("gga"),
+        Some("kki"),
+        Some("ggi"),
+        Some("kku"),
+        Some("ggu"),
+        Some("kke"),
+        Some("gge"),
+        Some("kko"),
+        Some("ggo"),
+        Some("ssa"),
+        Some("zza"),
+        Some("sshi"),
+        Some("jji"),
+        Some("ssu"),
+        Some("zzu"),
+        Some("sse"),
+        Some("zze"),
+        Some("sso"),
+        Some("zzo"),
+        Some("tta"),
+        Some("dda"),
+        Some("tchi"),
+        Some("jji"),
+        Some("ttsu"),
+        Some("zzu"),
+        Some("tte"),
+        Some("dde"),
+        Some("tto"),
+        Some("ddo"),
+        Some("na"),
+        Some("ni"),
+        Some("nu"),
+        Some("ne"),
+        Some("no"),
+        Some("hha"),
+        Some("bba"),
+        Some("ppa"),
+        Some("hhi"),
+        Some("bbi"),
+        Some("ppi"),
+        Some("ffu"),
+        Some("bbu"),
+        Some("ppu"),
+        Some("hhe"),
+        Some("bbe"),
+        Some("ppe"),
+        Some("hho"),
+        Some("bbo"),
+        Some("ppo"),
+        Some("mma"),
+        Some("mmi"),
+        Some("mmu"),
+        Some("mme"),
+        Some("mmo"),
+        Some("ya"),
+        Some("ya"),
+        Some("yu"),
+        Some("yu"),
+        Some("yo"),
+        Some("yo"),
+        Some("rra"),
+        Some("rri"),
+        Some("rru"),
+        Some("rre"),
+        Some("rro"),
+        Some("wwa"),
+        Some("wwi"),
+        Some("wwe"),
+        Some("wwo"),
+        Some("n"),
+        Some("vvu"),
+        Some("/"),
+        Some("-"),
+        Some("!"),
+        Some("("),
+        Some(")"),
+        Some(":"),
+        Some("?"),
+        Some("["),
+        Some("]"),
+        Some("{"),
+        Some("}"),
+        Some("nyi"),
+        Some("nye"),
+        Some("nya"),
+        Some("nyu"),
+        Some("nyo"),
+        Some("hyi"),
+        Some("hye"),
+        Some("hya"),
+        Some("hyu"),
+        Some("hyo"),
+        Some("byi"),
+        Some("bye"),
+        Some("bya"),
+        Some("byu"),
+        Some("byo"),
+        Some("pyi"),
+        Some("pye"),
+        Some("pya"),
+        Some("pyu"),
+        Some("pyo"),
+        Some("fyi"),
+        Some("fye"),
+        Some("fya"),
+        Some("fyu"),
+        Some("fyo"),
+        Some("myi"),
+        Some("mye"),
+        Some("mya"),
+        Some("myu"),
+        Some("myo"),
+        Some("ryi"),
+        Some("rye"),
+        Some("rya"),
+        Some("ryu"),
+        Some("ryo"),
+        Some("n'a"),
+        Some("n'i"),
+        Some("n'u"),
+        Some("n'e"),
+        Some("n'o"),
+        Some("n'ya"),
+        Some("n'yu"),
+        Some("n'yo"),
+        Some("va"),
+        Some("vi"),
+        Some("ve"),
+        Some("vo"),
+        Some("vya"),
+        Some("vyu"),
+        Some("vyo"),
+        Some("kkyi"),
+        Some("kkye"),
+        Some("kkya"),
+        Some("kkyu"),
+        Some("kkyo"),
+        Some("ggyi"),
+        Some("ggye"),
+        Some("ggya"),
+        Some("ggyu"),
+        Some("ggyo"),
+        Some("kkyi"),
+        Some("kkye"),
+        Some("kkya"),
+        Some("kkyu"),
+        Some("kkyo"),
+        Some("sshyi"),
+        Some("sshe"),
+        Some("ssha"),
+        Some("sshu"),
+        Some("ssho"),
+        Some("jjyi"),
+        Some("jje"),
+        Some("jja"),
+        Some("jju"),
+        Some("jjo"),
+        Some("tchyi"),
+        Some("tche"),
+        Some("tcha"),
+        Some("tchu"),
+        Some("tcho"),
+        Some("jjyi"),
+        Some("jje"),
+        Some("jja"),
+        Some("jju"),
+        Some("jjo"),
+        Some("nyi"),
+        Some("nye"),
+        Some("nya"),
+        Some("nyu"),
+        Some("nyo"),
+        Some("hhyi"),
+        Some("hhye"),
+        Some("hhya"),
+        Some("hhyu"),
+        Some("hhyo"),
+        Some("bbyi"),
+        Some("bbye"),
+        Some("bbya"),
+        Some("bbyu"),
+        Some("bbyo"),
+        Some("ppyi"),
+        Some("ppye"),
+        Some("ppya"),
+        Some("ppyu"),
+        Some("ppyo"),
+        Some("ffyi"),
+        Some("ffye"),
+        Some("ffya"),
+        Some("ffyu"),
+        Some("ffyo"),
+        Some("mmyi"),
+        Some("mmye"),
+        Some("mmya"),
+        Some("mmyu"),
+        Some("mmyo"),
+        Some("rryi"),
+        Some("rrye"),
+        Some("rrya"),
+        Some("rryu"),
+        Some("rryo"),
+        Some("vva"),
+        Some("vvi"),
+        Some("vve"),
+        Some("vvo"),
+        Some("vvya"),
+        Some("vvyu"),
+        Some("vvyo"),
+    ],
+};
+
+pub(crate) static TO_ROMAJI_TRIE_KUNREI: Trie = Trie {
+    root: (0, 107),
+    transitions: &[
+        ('\u{3000}', 107, 107),
+        ('、', 107, 107),
+        ('。', 107, 107),
+        ('「', 107, 107),
+        ('」', 107, 107),
+        ('『', 107, 107),
+        ('』', 107, 107),
+        ('〜', 107, 107),
+        ('ぁ', 107, 107),
+        ('あ', 107, 107),
+        ('ぃ', 107, 107),
+        ('い', 107, 107),
+        ('ぅ', 107, 107),
+        ('う', 107, 107),
+        ('ぇ', 107, 107),
+        ('え', 107, 107),
+        ('ぉ', 107, 107),
+        ('お', 107, 107),
+        ('か', 107, 107),
+        ('が', 107, 107),
+        ('き', 107, 112),
+        ('ぎ', 112, 117),
+        ('く', 117, 122),
+        ('ぐ', 122, 122),
+        ('け', 122, 122),
+        ('げ', 122, 122),
+        ('こ', 122, 122),
+        ('ご', 122, 122),
+        ('さ', 122, 122),
+        ('ざ', 122, 122),
+        ('し', 122, 127),
+        ('じ', 127, 132),
+        ('す', 132, 132),
+        ('ず', 132, 132),
+        ('せ', 132, 132),
+        ('ぜ', 132, 132),
+        ('そ', 132, 132),
+        ('ぞ', 132, 132),
+        ('た', 132, 132),
+        ('だ', 132, 132),
+        ('ち', 132, 137),
+        ('ぢ', 137, 142),
+        ('っ', 142, 243),
+        ('つ', 243, 243),
+        ('づ', 243, 243),
+        ('て', 243, 243),
+        ('で', 243, 243),
+        ('と', 243, 243),
+        ('ど', 243, 243),
+        ('な', 243, 243),
+        ('に', 243, 248),
+        ('ぬ', 248, 248),
+        ('ね', 248, 248),
+        ('の', 248, 248),
+        ('は', 248, 248),
+        ('ば', 248, 248),
+        ('ぱ', 248, 248),
+        ('ひ', 248, 253),
+        ('び', 253, 258),
+        ('ぴ', 258, 263),
+        ('ふ', 263, 268),
+        ('ぶ', 268, 268),
+        ('ぷ', 268, 268),
+        ('へ', 268, 268),
+        ('べ', 268, 268),
+        ('ぺ', 268, 268),
+        ('ほ', 268, 268),
+        ('ぼ', 268, 268),
+        ('ぽ', 268, 268),
+        ('ま', 268, 268),
+        ('み', 268, 273),
+        ('む', 273, 273),
+        ('め', 273, 273),
+        ('も', 273, 273),
+        ('ゃ', 273, 273),
+        ('や', 273, 273),
+        ('ゅ', 273, 273),
+        ('ゆ', 273, 273),
+        ('ょ', 273, 273),
+        ('よ', 273, 273),
+        ('ら', 273, 273),
+        ('り', 273, 278),
+        ('る', 278, 278),
+        ('れ', 278, 278),
+        ('ろ', 278, 278),
+        ('ゎ', 278, 278),
+        ('わ', 278, 278),
+        ('ゐ', 278, 278),
+        ('ゑ', 278, 278),
+        ('を', 278, 278),
+        ('ん', 278, 286),
+        ('ゔ', 286, 293),
+        ('ヷ', 293, 293),
+        ('ヸ', 293, 293),
+        ('ヹ', 293, 293),
+        ('ヺ', 293, 293),
+        ('・', 293, 293),
+        ('ー', 293, 293),
+        ('！', 293, 293),
+        ('（', 293, 293),
+        ('）', 293, 293),
+        ('：', 293, 293),
+        ('？', 293, 293),
+        ('［', 293, 293),
+        ('］', 293, 293),
+        ('｛', 293, 293),
+        ('｝', 293, 293),
+        ('ぃ', 293, 293),
+        ('ぇ', 293, 293),
+        ('ゃ', 293, 293),
+        ('ゅ', 293, 293),
+        ('ょ', 293, 293),
+        ('ぃ', 293, 293),
+        ('ぇ', 293, 293),
+        ('ゃ', 293, 293),
+        ('ゅ', 293, 293),
+        ('ょ', 293, 293),
+        ('ぃ', 293, 293),
+        ('ぇ', 293, 293),
+        ('ゃ', 293, 293),
+        ('ゅ', 293, 293),
+        ('ょ', 293, 293),
+        ('ぃ', 293, 293),
+        ('ぇ', 293, 293),
+        ('ゃ', 293, 293),
+        ('ゅ', 293, 293),
+        ('ょ', 293, 293),
+        ('ぃ', 293, 293),
+        ('ぇ', 293, 293),
+        ('ゃ', 293, 293),
+        ('ゅ', 293, 293),
+        ('ょ', 293, 293),
+        ('ぃ', 293, 293),
+        ('ぇ', 293, 293),
+        ('ゃ', 293, 293),
+        ('ゅ', 293, 293),
+        ('ょ', 293, 293),
+        ('ぃ', 293, 293),
+        ('ぇ', 293, 293),
+        ('ゃ', 293, 293),
+        ('ゅ', 293, 293),
+        ('ょ', 293, 293),
+        ('\u{3000}', 293, 293),
+        ('、', 293, 293),
+        ('。', 293, 293),
+        ('「', 293, 293),
+        ('」', 293, 293),
+        ('『', 293, 293),
+        ('』', 293, 293),
+        ('〜', 293, 293),
+        ('ぁ', 293, 293),
+        ('あ', 293, 293),
+        ('ぃ', 293, 293),
+        ('い', 293, 293),
+        ('ぅ', 293, 293),
+        ('う', 293, 293),
+        ('ぇ', 293, 293),
+        ('え', 293, 293),
+        ('ぉ', 293, 293),
+        ('お', 293, 293),
+        ('か', 293, 293),
+        ('が', 293, 293),
+        ('き', 293, 298),
+        ('ぎ', 298, 303),
+        ('く', 303, 308),
+        ('ぐ', 308, 308),
+        ('け', 308, 308),
+        ('げ', 308, 308),
+        ('こ', 308, 308),
+        ('ご', 308, 308),
+        ('さ', 308, 308),
+        ('ざ', 308, 308),
+        ('し', 308, 313),
+        ('じ', 313, 318),
+        ('す', 318, 318),
+        ('ず', 318, 318),
+        ('せ', 318, 318),
+        ('ぜ', 318, 318),
+        ('そ', 318, 318),
+        ('ぞ', 318, 318),
+        ('た', 318, 318),
+        ('だ', 318, 318),
+        ('ち', 318, 323),
+        ('ぢ', 323, 328),
+        ('つ', 328, 328),
+        ('づ', 328, 328),
+        ('て', 328, 328),
+        ('で', 328, 328),
+        ('と', 328, 328),
+        ('ど', 328, 328),
+        ('な', 328, 328),
+        ('に', 328, 333),
+        ('ぬ', 333, 333),
+        ('ね', 333, 333),
+        ('の', 333, 333),
+        ('は', 333, 333),
+        ('ば', 333, 333),
+        ('ぱ', 333, 333),
+        ('ひ', 333, 338),
+        ('び', 338, 343),
+        ('ぴ', 343, 348),
+        ('ふ', 348, 353),
+        ('ぶ', 353, 353),
+        ('ぷ', 353, 353),
+        ('へ', 353, 353),
+        ('べ', 353, 353),
+        ('ぺ', 353, 353),
+        ('ほ', 353, 353),
+        ('ぼ', 353, 353),
+        ('ぽ', 353, 353),
+        ('ま', 353, 353),
+        ('み', 353, 358),
+        ('む', 358, 358),
+        ('め', 358, 358),
+        ('も', 358, 358),
+        ('ゃ', 358, 358),
+        ('や', 358, 358),
+        ('ゅ', 358, 358),
+        ('ゆ', 358, 358),
+        ('ょ', 358, 358),
+        ('よ', 358, 358),
+        ('ら', 358, 358),
+        ('り', 358, 363),
+        ('る', 363, 363),
+        ('れ', 363, 363),
+        ('ろ', 363, 363),
+        ('わ', 363, 363),
+        ('ゐ', 363, 363),
+        ('ゑ', 363, 363),
+        ('を', 363, 363),
+        ('ん', 363, 363),
+        ('ゔ', 363, 370),
+        ('・', 370, 370),
+        ('ー', 370, 370),
+        ('！', 370, 370),
+        ('（', 370, 370),
+        ('）', 370, 370),
+        ('：', 370, 370),
+        ('？', 370, 370),
+        ('［', 370, 370),
+        ('］', 370, 370),
+        ('｛', 370, 370),
+        ('｝', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('あ', 370, 370),
+        ('い', 370, 370),
+        ('う', 370, 370),
+        ('え', 370, 370),
+        ('お', 370, 370),
+        ('や', 370, 370),
+        ('ゆ', 370, 370),
+        ('よ', 370, 370),
+        ('ぁ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ぉ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぁ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ぉ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+    ],
+    outputs: &[
+        Some(" "),
+        Some(","),
+        Some("."),
+        Some("‘"),
+        Some("’"),
+        Some("“"),
+        Some("”"),
+        Some("~"),
+        Some("a"),
+        Some("a"),
+        Some("i"),
+        Some("i"),
+        Some("u"),
+        Some("u"),
+        Some("e"),
+        Some("e"),
+        Some("o"),
+        Some("o"),
+        Some("ka"),
+        Some("ga"),
+        Some("ki"),
+        Some("gi"),
+        Some("ku"),
+        Some("gu"),
+        Some("ke"),
+        Some("ge"),
+        Some("ko"),
+        Some("go"),
+        Some("sa"),
+        Some("za"),
+        Some("si"),
+        Some("zi"),
+        Some("su"),
+        Some("zu"),
+        Some("se"),
+        Some("ze"),
+        Some("so"),
+        Some("zo"),
+        Some("ta"),
+        Some("da"),
+        Some("ti"),
+        Some("zi"),
+        Some(""),
+        Some("tu"),
+        Some("zu"),
+        Some("te"),
+        Some("de"),
+        Some("to"),
+        Some("do"),
+        Some("na"),
+        Some("ni"),
+        Some("nu"),
+        Some("ne"),
+        Some("no"),
+        Some("ha"),
+        Some("ba"),
+        Some("pa"),
+        Some("hi"),
+        Some("bi"),
+        Some("pi"),
+        Some("hu"),
+        Some("bu"),
+        Some("pu"),
+        Some("he"),
+        Some("be"),
+        Some("pe"),
+        Some("ho"),
+        Some("bo"),
+        Some("po"),
+        Some("ma"),
+        Some("mi"),
+        Some("mu"),
+        Some("me"),
+        Some("mo"),
+        Some("ya"),
+        Some("ya"),
+        Some("yu"),
+        Some("yu"),
+        Some("yo"),
+        Some("yo"),
+        Some("ra"),
+        Some("ri"),
+        Some("ru"),
+        Some("re"),
+        Some("ro"),
+        Some("wa"),
+        Some("wa"),
+        Some("i"),
+        Some("e"),
+        Some("o"),
+        Some("n"),
+        Some("vu"),
+        Some("va"),
+        Some("vi"),
+        Some("ve"),
+        Some("vo"),
+        Some("/"),
+        Some("-"),
+        Some("!"),
+        Some("("),
+        Some(")"),
+        Some(":"),
+        Some("?"),
+        Some("["),
+        Some("]"),
+        Some("{"),
+        Some("}"),
+        Some("kyi"),
+        Some("kye"),
+        Some("kya"),
+        Some("kyu"),
+        Some("kyo"),
+        Some("gyi"),
+        Some("gye"),
+        Some("gya"),
+        Some("gyu"),
+        Some("gyo"),
+        Some("kyi"),
+        Some("kye"),
+        Some("kya"),
+        Some("kyu"),
+        Some("kyo"),
+        Some("syi"),
+        Some("sye"),
+        Some("sya"),
+        Some("syu"),
+        Some("syo"),
+        Some("zyi"),
+        Some("zye"),
+        Some("zya"),
+        Some("zyu"),
+        Some("zyo"),
+        Some("tyi"),
+        Some("tye"),
+        Some("tya"),
+        Some("tyu"),
+        Some("tyo"),
+        Some("zyi"),
+        Some("zye"),
+        Some("zya"),
+        Some("zyu"),
+        Some("zyo"),
+        Some(" "),
+        Some(","),
+        Some("."),
+        Some("‘"),
+        Some("’"),
+        Some("“"),
+        Some("”"),
+        Some("~"),
+        Some("a"),
+        Some("a"),
+        Some("i"),
+        Some("i"),
+        Some("u"),
+        Some("u"),
+        Some("e"),
+        Some("e"),
+        Some("o"),
+        Some("o"),
+        Some("kka"),
+        Some("gga"),
+        Some("kki"),
+        Some("ggi"),
+        Some("kku"),
+        Some("ggu"),
+        Some("kke"),
+        Some("gge"),
+        Some("kko"),
+        Some("ggo"),
+        Some("ssa"),
+        Some("zza"),
+        Some("ssi"),
+        Some("zzi"),
+        Some("ssu"),
+        Some("zzu"),
+        Some("sse"),
+        Some("zze"),
+        Some("sso"),
+        Some("zzo"),
+        Some("tta"),
+        Some("dda"),
+        Some("tti"),
+        Some("zzi"),
+        Some("ttu"),
+        Some("zzu"),
+        Some("tte"),
+        Some("dde"),
+        Some("tto"),
+        Some("ddo"),
+        Some("na"),
+        Some("ni"),
+        Some("nu"),
+        Some("ne"),
+        Some("no"),
+        Some("hha"),
+        Some("bba"),
+        Some("ppa"),
+        Some("hhi"),
+        Some("bbi"),
+        Some("ppi"),
+        Some("hhu"),
+        Some("bbu"),
+        Some("ppu"),
+        Some("hhe"),
+        Some("bbe"),
+        Some("ppe"),
+        Some("hho"),
+        Some("bbo"),
+        Some("ppo"),
+        Some("mma"),
+        Some("mmi"),
+        Some("mmu"),
+        Some("mme"),
+        Some("mmo"),
+        Some("ya"),
+        Some("ya"),
+        Some("yu"),
+        Some("yu"),
+        Some("yo"),
+        Some("yo"),
+        Some("rra"),
+        Some("rri"),
+        Some("rru"),
+        Some("rre"),
+        Some("rro"),
+        Some("wwa"),
+        Some("ii"),
+        Some("ee"),
+        Some("oo"),
+        Some("n"),
+        Some("vvu"),
+        Some("/"),
+        Some("-"),
+        Some("!"),
+        Some("("),
+        Some(")"),
+        Some(":"),
+        Some("?"),
+        Some("["),
+        Some("]"),
+        Some("{"),
+        Some("}"),
+        Some("nyi"),
+        Some("nye"),
+        Some("nya"),
+        Some("nyu"),
+        Some("nyo"),
+        Some("hyi"),
+        Some("hye"),
+        Some("hya"),
+        Some("hyu"),
+        Some("hyo"),
+        Some("byi"),
+        Some("bye"),
+        Some("bya"),
+        Some("byu"),
+        Some("byo"),
+        Some("pyi"),
+        Some("pye"),
+        Some("pya"),
+        Some("pyu"),
+        Some("pyo"),
+        Some("fyi"),
+        Some("fye"),
+        Some("fya"),
+        Some("fyu"),
+        Some("fyo"),
+        Some("myi"),
+        Some("mye"),
+        Some("mya"),
+        Some("myu"),
+        Some("myo"),
+        Some("ryi"),
+        Some("rye"),
+        Some("rya"),
+        Some("ryu"),
+        Some("ryo"),
+        Some("n'a"),
+        Some("n'i"),
+        Some("n'u"),
+        Some("n'e"),
+        Some("n'o"),
+        Some("n'ya"),
+        Some("n'yu"),
+        Some("n'yo"),
+        Some("va"),
+        Some("vi"),
+        Some("ve"),
+        Some("vo"),
+        Some("vya"),
+        Some("vyu"),
+        Some("vyo"),
+        Some("kkyi"),
+        Some("kkye"),
+        Some("kkya"),
+        Some("kkyu"),
+        Some("kkyo"),
+        Some("ggyi"),
+        Some("ggye"),
+        Some("ggya"),
+        Some("ggyu"),
+        Some("ggyo"),
+        Some("kkyi"),
+        Some("kkye"),
+        Some("kkya"),
+        Some("kkyu"),
+        Some("kkyo"),
+        Some("ssyi"),
+        Some("ssye"),
+        Some("ssya"),
+        Some("ssyu"),
+        Some("ssyo"),
+        Some("zzyi"),
+        Some("zzye"),
+        Some("zzya"),
+        Some("zzyu"),
+        Some("zzyo"),
+        Some("ttyi"),
+        Some("ttye"),
+        Some("ttya"),
+        Some("ttyu"),
+        Some("ttyo"),
+        Some("zzyi"),
+        Some("zzye"),
+        Some("zzya"),
+        Some("zzyu"),
+        Some("zzyo"),
+        Some("nyi"),
+        Some("nye"),
+        Some("nya"),
+        Some("nyu"),
+        Some("nyo"),
+        Some("hhyi"),
+        Some("hhye"),
+        Some("hhya"),
+        Some("hhyu"),
+        Some("hhyo"),
+        Some("bbyi"),
+        Some("bbye"),
+        Some("bbya"),
+        Some("bbyu"),
+        Some("bbyo"),
+        Some("ppyi"),
+        Some("ppye"),
+        Some("ppya"),
+        Some("ppyu"),
+        Some("ppyo"),
+        Some("ffyi"),
+        Some("ffye"),
+        Some("ffya"),
+        Some("ffyu"),
+        Some("ffyo"),
+        Some("mmyi"),
+        Some("mmye"),
+        Some("mmya"),
+        Some("mmyu"),
+        Some("mmyo"),
+        Some("rryi"),
+        Some("rrye"),
+        Some("rrya"),
+        Some("rryu"),
+        Some("rryo"),
+        Some("vva"),
+        Some("vvi"),
+        Some("vve"),
+        Some("vvo"),
+        Some("vvya"),
+        Some("vvyu"),
+        Some("vvyo"),
+    ],
+};
+
+pub(crate) static TO_ROMAJI_TRIE_NIHON: Trie = Trie {
+    root: (0, 107),
+    transitions: &[
+        ('\u{3000}', 107, 107),
+        ('、', 107, 107),
+        ('。', 107, 107),
+        ('「', 107, 107),
+        ('」', 107, 107),
+        ('『', 107, 107),
+        ('』', 107, 107),
+        ('〜', 107, 107),
+        ('ぁ', 107, 107),
+        ('あ', 107, 107),
+        ('ぃ', 107, 107),
+        ('い', 107, 107),
+        ('ぅ', 107, 107),
+        ('う', 107, 107),
+        ('ぇ', 107, 107),
+        ('え', 107, 107),
+        ('ぉ', 107, 107),
+        ('お', 107, 107),
+        ('か', 107, 107),
+        ('が', 107, 107),
+        ('き', 107, 112),
+        ('ぎ', 112, 117),
+        ('く', 117, 123),
+        ('ぐ', 123, 124),
+        ('け', 124, 124),
+        ('げ', 124, 124),
+        ('こ', 124, 124),
+        ('ご', 124, 124),
+        ('さ', 124, 124),
+        ('ざ', 124, 124),
+        ('し', 124, 129),
+        ('じ', 129, 134),
+        ('す', 134, 134),
+        ('ず', 134, 134),
+        ('せ', 134, 134),
+        ('ぜ', 134, 134),
+        ('そ', 134, 134),
+        ('ぞ', 134, 134),
+        ('た', 134, 134),
+        ('だ', 134, 134),
+        ('ち', 134, 139),
+        ('ぢ', 139, 144),
+        ('っ', 144, 245),
+        ('つ', 245, 245),
+        ('づ', 245, 245),
+        ('て', 245, 245),
+        ('で', 245, 245),
+        ('と', 245, 245),
+        ('ど', 245, 245),
+        ('な', 245, 245),
+        ('に', 245, 250),
+        ('ぬ', 250, 250),
+        ('ね', 250, 250),
+        ('の', 250, 250),
+        ('は', 250, 250),
+        ('ば', 250, 250),
+        ('ぱ', 250, 250),
+        ('ひ', 250, 255),
+        ('び', 255, 260),
+        ('ぴ', 260, 265),
+        ('ふ', 265, 270),
+        ('ぶ', 270, 270),
+        ('ぷ', 270, 270),
+        ('へ', 270, 270),
+        ('べ', 270, 270),
+        ('ぺ', 270, 270),
+        ('ほ', 270, 270),
+        ('ぼ', 270, 270),
+        ('ぽ', 270, 270),
+        ('ま', 270, 270),
+        ('み', 270, 275),
+        ('む', 275, 275),
+        ('め', 275, 275),
+        ('も', 275, 275),
+        ('ゃ', 275, 275),
+        ('や', 275, 275),
+        ('ゅ', 275, 275),
+        ('ゆ', 275, 275),
+        ('ょ', 275, 275),
+        ('よ', 275, 275),
+        ('ら', 275, 275),
+        ('り', 275, 280),
+        ('る', 280, 280),
+        ('れ', 280, 280),
+        ('ろ', 280, 280),
+        ('ゎ', 280, 280),
+        ('わ', 280, 280),
+        ('ゐ', 280, 280),
+        ('ゑ', 280, 280),
+        ('を', 280, 280),
+        ('ん', 280, 288),
+        ('ゔ', 288, 295),
+        ('ヷ', 295, 295),
+        ('ヸ', 295, 295),
+        ('ヹ', 295, 295),
+        ('ヺ', 295, 295),
+        ('・', 295, 295),
+        ('ー', 295, 295),
+        ('！', 295, 295),
+        ('（', 295, 295),
+        ('）', 295, 295),
+        ('：', 295, 295),
+        ('？', 295, 295),
+        ('［', 295, 295),
+        ('］', 295, 295),
+        ('｛', 295, 295),
+        ('｝', 295, 295),
+        ('ぃ', 295, 295),
+        ('ぇ', 295, 295),
+        ('ゃ', 295, 295),
+        ('ゅ', 295, 295),
+        ('ょ', 295, 295),
+        ('ぃ', 295, 295),
+        ('ぇ', 295, 295),
+        ('ゃ', 295, 295),
+        ('ゅ', 295, 295),
+        ('ょ', 295, 295),
+        ('ぃ', 295, 295),
+        ('ぇ', 295, 295),
+        ('ゃ', 295, 295),
+        ('ゅ', 295, 295),
+        ('ょ', 295, 295),
+        ('ゎ', 295, 295),
+        ('ゎ', 295, 295),
+        ('ぃ', 295, 295),
+        ('ぇ', 295, 295),
+        ('ゃ', 295, 295),
+        ('ゅ', 295, 295),
+        ('ょ', 295, 295),
+        ('ぃ', 295, 295),
+        ('ぇ', 295, 295),
+        ('ゃ', 295, 295),
+        ('ゅ', 295, 295),
+        ('ょ', 295, 295),
+        ('ぃ', 295, 295),
+        ('ぇ', 295, 295),
+        ('ゃ', 295, 295),
+        ('ゅ', 295, 295),
+        ('ょ', 295, 295),
+        ('ぃ', 295, 295),
+        ('ぇ', 295, 295),
+        ('ゃ', 295, 295),
+        ('ゅ', 295, 295),
+        ('ょ', 295, 295),
+        ('\u{3000}', 295, 295),
+        ('、', 295, 295),
+        ('。', 295, 295),
+        ('「', 295, 295),
+        ('」', 295, 295),
+        ('『', 295, 295),
+        ('』', 295, 295),
+        ('〜', 295, 295),
+        ('ぁ', 295, 295),
+        ('あ', 295, 295),
+        ('ぃ', 295, 295),
+        ('い', 295, 295),
+        ('ぅ', 295, 295),
+        ('う', 295, 295),
+        ('ぇ', 295, 295),
+        ('え', 295, 295),
+        ('ぉ', 295, 295),
+        ('お', 295, 295),
+        ('か', 295, 295),
+        ('が', 295, 295),
+        ('き', 295, 300),
+        ('ぎ', 300, 305),
+        ('く', 305, 311),
+        ('ぐ', 311, 312),
+        ('け', 312, 312),
+        ('げ', 312, 312),
+        ('こ', 312, 312),
+        ('ご', 312, 312),
+        ('さ', 312, 312),
+        ('ざ', 312, 312),
+        ('し', 312, 317),
+        ('じ', 317, 322),
+        ('す', 322, 322),
+        ('ず', 322, 322),
+        ('せ', 322, 322),
+        ('ぜ', 322, 322),
+        ('そ', 322, 322),
+        ('ぞ', 322, 322),
+        ('た', 322, 322),
+        ('だ', 322, 322),
+        ('ち', 322, 327),
+        ('ぢ', 327, 332),
+        ('つ', 332, 332),
+        ('づ', 332, 332),
+        ('て', 332, 332),
+        ('で', 332, 332),
+        ('と', 332, 332),
+        ('ど', 332, 332),
+        ('な', 332, 332),
+        ('に', 332, 337),
+        ('ぬ', 337, 337),
+        ('ね', 337, 337),
+        ('の', 337, 337),
+        ('は', 337, 337),
+        ('ば', 337, 337),
+        ('ぱ', 337, 337),
+        ('ひ', 337, 342),
+        ('び', 342, 347),
+        ('ぴ', 347, 352),
+        ('ふ', 352, 357),
+        ('ぶ', 357, 357),
+        ('ぷ', 357, 357),
+        ('へ', 357, 357),
+        ('べ', 357, 357),
+        ('ぺ', 357, 357),
+        ('ほ', 357, 357),
+        ('ぼ', 357, 357),
+        ('ぽ', 357, 357),
+        ('ま', 357, 357),
+        ('み', 357, 362),
+        ('む', 362, 362),
+        ('め', 362, 362),
+        ('も', 362, 362),
+        ('ゃ', 362, 362),
+        ('や', 362, 362),
+        ('ゅ', 362, 362),
+        ('ゆ', 362, 362),
+        ('ょ', 362, 362),
+        ('よ', 362, 362),
+        ('ら', 362, 362),
+        ('り', 362, 367),
+        ('る', 367, 367),
+        ('れ', 367, 367),
+        ('ろ', 367, 367),
+        ('わ', 367, 367),
+        ('ゐ', 367, 367),
+        ('ゑ', 367, 367),
+        ('を', 367, 367),
+        ('ん', 367, 367),
+        ('ゔ', 367, 374),
+        ('・', 374, 374),
+        ('ー', 374, 374),
+        ('！', 374, 374),
+        ('（', 374, 374),
+        ('）', 374, 374),
+        ('：', 374, 374),
+        ('？', 374, 374),
+        ('［', 374, 374),
+        ('］', 374, 374),
+        ('｛', 374, 374),
+        ('｝', 374, 374),
+        ('ぃ', 374, 374),
+        ('ぇ', 374, 374),
+        ('ゃ', 374, 374),
+        ('ゅ', 374, 374),
+        ('ょ', 374, 374),
+        ('ぃ', 374, 374),
+        ('ぇ', 374, 374),
+        ('ゃ', 374, 374),
+        ('ゅ', 374, 374),
+        ('ょ', 374, 374),
+        ('ぃ', 374, 374),
+        ('ぇ', 374, 374),
+        ('ゃ', 374, 374),
+        ('ゅ', 374, 374),
+        ('ょ', 374, 374),
+        ('ぃ', 374, 374),
+        ('ぇ', 374, 374),
+        ('ゃ', 374, 374),
+        ('ゅ', 374, 374),
+        ('ょ', 374, 374),
+        ('ぃ', 374, 374),
+        ('ぇ', 374, 374),
+        ('ゃ', 374, 374),
+        ('ゅ', 374, 374),
+        ('ょ', 374, 374),
+        ('ぃ', 374, 374),
+        ('ぇ', 374, 374),
+        ('ゃ', 374, 374),
+        ('ゅ', 374, 374),
+        ('ょ', 374, 374),
+        ('ぃ', 374, 374),
+        ('ぇ', 374, 374),
+        ('ゃ', 374, 374),
+        ('ゅ', 374, 374),
+        ('ょ', 374, 374),
+        ('あ', 374, 374),
+        ('い', 374, 374),
+        ('う', 374, 374),
+        ('え', 374, 374),
+        ('お', 374, 374),
+        ('や', 374, 374),
+        ('ゆ', 374, 374),
+        ('よ', 374, 374),
+        ('ぁ', 374, 374),
+        ('ぃ', 374, 374),
+        ('ぇ', 374, 374),
+        ('ぉ', 374, 374),
+        ('ゃ', 374, 374),
+        ('ゅ', 374, 374),
+        ('ょ', 374, 374),
+        ('ぃ', 374, 374),
+        ('ぇ', 374, 374),
+        ('ゃ', 374, 374),
+        ('ゅ', 374, 374),
+        ('ょ', 374, 374),
+        ('ぃ', 374, 374),
+        ('ぇ', 374, 374),
+        ('ゃ', 374, 374),
+        ('ゅ', 374, 374),
+        ('ょ', 374, 374),
+        ('ぃ', 374, 374),
+        ('ぇ', 374, 374),
+        ('ゃ', 374, 374),
+        ('ゅ', 374, 374),
+        ('ょ', 374, 374),
+        ('ゎ', 374, 374),
+        ('ゎ', 374, 374),
+        ('ぃ', 374, 374),
+        ('ぇ', 374, 374),
+        ('ゃ', 374, 374),
+        ('ゅ', 374, 374),
+        ('ょ', 374, 374),
+        ('ぃ', 374, 374),
+        ('ぇ', 374, 374),
+        ('ゃ', 374, 374),
+        ('ゅ', 374, 374),
+        ('ょ', 374, 374),
+        ('ぃ', 374, 374),
+        ('ぇ', 374, 374),
+        ('ゃ', 374, 374),
+        ('ゅ', 374, 374),
+        ('ょ', 374, 374),
+        ('ぃ', 374, 374),
+        ('ぇ', 374, 374),
+        ('ゃ', 374, 374),
+        ('ゅ', 374, 374),
+        ('ょ', 374, 374),
+        ('ぃ', 374, 374),
+        ('ぇ', 374, 374),
+        ('ゃ', 374, 374),
+        ('ゅ', 374, 374),
+        ('ょ', 374, 374),
+        ('ぃ', 374, 374),
+        ('ぇ', 374, 374),
+        ('ゃ', 374, 374),
+        ('ゅ', 374, 374),
+        ('ょ', 374, 374),
+        ('ぃ', 374, 374),
+        ('ぇ', 374, 374),
+        ('ゃ', 374, 374),
+        ('ゅ', 374, 374),
+        ('ょ', 374, 374),
+        ('ぃ', 374, 374),
+        ('ぇ', 374, 374),
+        ('ゃ', 374, 374),
+        ('ゅ', 374, 374),
+        ('ょ', 374, 374),
+        ('ぃ', 374, 374),
+        ('ぇ', 374, 374),
+        ('ゃ', 374, 374),
+        ('ゅ', 374, 374),
+        ('ょ', 374, 374),
+        ('ぃ', 374, 374),
+        ('ぇ', 374, 374),
+        ('ゃ', 374, 374),
+        ('ゅ', 374, 374),
+        ('ょ', 374, 374),
+        ('ぃ', 374, 374),
+        ('ぇ', 374, 374),
+        ('ゃ', 374, 374),
+        ('ゅ', 374, 374),
+        ('ょ', 374, 374),
+        ('ぁ', 374, 374),
+        ('ぃ', 374, 374),
+        ('ぇ', 374, 374),
+        ('ぉ', 374, 374),
+        ('ゃ', 374, 374),
+        ('ゅ', 374, 374),
+        ('ょ', 374, 374),
+    ],
+    outputs: &[
+        Some(" "),
+        Some(","),
+        Some("."),
+        Some("‘"),
+        Some("’"),
+        Some("“"),
+        Some("”"),
+        Some("~"),
+        Some("a"),
+        Some("a"),
+        Some("i"),
+        Some("i"),
+        Some("u"),
+        Some("u"),
+        Some("e"),
+        Some("e"),
+        Some("o"),
+        Some("o"),
+        Some("ka"),
+        Some("ga"),
+        Some("ki"),
+        Some("gi"),
+        Some("ku"),
+        Some("gu"),
+        Some("ke"),
+        Some("ge"),
+        Some("ko"),
+        Some("go"),
+        Some("sa"),
+        Some("za"),
+        Some("si"),
+        Some("zi"),
+        Some("su"),
+        Some("zu"),
+        Some("se"),
+        Some("ze"),
+        Some("so"),
+        Some("zo"),
+        Some("ta"),
+        Some("da"),
+        Some("ti"),
+        Some("di"),
+        Some(""),
+        Some("tu"),
+        Some("du"),
+        Some("te"),
+        Some("de"),
+        Some("to"),
+        Some("do"),
+        Some("na"),
+        Some("ni"),
+        Some("nu"),
+        Some("ne"),
+        Some("no"),
+        Some("ha"),
+        Some("ba"),
+        Some("pa"),
+        Some("hi"),
+        Some("bi"),
+        Some("pi"),
+        Some("hu"),
+        Some("bu"),
+        Some("pu"),
+        Some("he"),
+        Some("be"),
+        Some("pe"),
+        Some("ho"),
+        Some("bo"),
+        Some("po"),
+        Some("ma"),
+        Some("mi"),
+        Some("mu"),
+        Some("me"),
+        Some("mo"),
+        Some("ya"),
+        Some("ya"),
+        Some("yu"),
+        Some("yu"),
+        Some("yo"),
+        Some("yo"),
+        Some("ra"),
+        Some("ri"),
+        Some("ru"),
+        Some("re"),
+        Some("ro"),
+        Some("wa"),
+        Some("wa"),
+        Some("wi"),
+        Some("we"),
+        Some("wo"),
+        Some("n"),
+        Some("vu"),
+        Some("va"),
+        Some("vi"),
+        Some("ve"),
+        Some("vo"),
+        Some("/"),
+        Some("-"),
+        Some("!"),
+        Some("("),
+        Some(")"),
+        Some(":"),
+        Some("?"),
+        Some("["),
+        Some("]"),
+        Some("{"),
+        Some("}"),
+        Some("kyi"),
+        Some("kye"),
+        Some("kya"),
+        Some("kyu"),
+        Some("kyo"),
+        Some("gyi"),
+        Some("gye"),
+        Some("gya"),
+        Some("gyu"),
+        Some("gyo"),
+        Some("kyi"),
+        Some("kye"),
+        Some("kya"),
+        Some("kyu"),
+        Some("kyo"),
+        Some("kwa"),
+        Some("gwa"),
+        Some("syi"),
+        Some("sye"),
+        Some("sya"),
+        Some("syu"),
+        Some("syo"),
+        Some("zyi"),
+        Some("zye"),
+        Some("zya"),
+        Some("zyu"),
+        Some("zyo"),
+        Some("tyi"),
+        Some("tye"),
+        Some("tya"),
+        Some("tyu"),
+        Some("tyo"),
+        Some("dyi"),
+        Some("dye"),
+        Some("dya"),
+        Some("dyu"),
+        Some("dyo"),
+        Some(" "),
+        Some(","),
+        Some("."),
+        Some("‘"),
+        Some("’"),
+        Some("“"),
+        Some("”"),
+        Some("~"),
+        Some("a"),
+        Some("a"),
+        Some("i"),
+        Some("i"),
+        Some("u"),
+        Some("u"),
+        Some("e"),
+        Some("e"),
+        Some("o"),
+        Some("o"),
+        Some("kka"),
+        Some("gga"),
+        Some("kki"),
+        Some("ggi"),
+        Some("kku"),
+        Some("ggu"),
+        Some("kke"),
+        Some("gge"),
+        Some("kko"),
+        Some("ggo"),
+        Some("ssa"),
+        Some("zza"),
+        Some("ssi"),
+        Some("zzi"),
+        Some("ssu"),
+        Some("zzu"),
+        Some("sse"),
+        Some("zze"),
+        Some("sso"),
+        Some("zzo"),
+        Some("tta"),
+        Some("dda"),
+        Some("tti"),
+        Some("ddi"),
+        Some("ttu"),
+        Some("ddu"),
+        Some("tte"),
+        Some("dde"),
+        Some("tto"),
+        Some("ddo"),
+        Some("na"),
+        Some("ni"),
+        Some("nu"),
+        Some("ne"),
+        Some("no"),
+        Some("hha"),
+        Some("bba"),
+        Some("ppa"),
+        Some("hhi"),
+        Some("bbi"),
+        Some("ppi"),
+        Some("hhu"),
+        Some("bbu"),
+        Some("ppu"),
+        Some("hhe"),
+        Some("bbe"),
+        Some("ppe"),
+        Some("hho"),
+        Some("bbo"),
+        Some("ppo"),
+        Some("mma"),
+        Some("mmi"),
+        Some("mmu"),
+        Some("mme"),
+        Some("mmo"),
+        Some("ya"),
+        Some("ya"),
+        Some("yu"),
+        Some("yu"),
+        Some("yo"),
+        Some("yo"),
+        Some("rra"),
+        Some("rri"),
+        Some("rru"),
+        Some("rre"),
+        Some("rro"),
+        Some("wwa"),
+        Some("wwi"),
+        Some("wwe"),
+        Some("wwo"),
+        Some("n"),
+        Some("vvu"),
+        Some("/"),
+        Some("-"),
+        Some("!"),
+        Some("("),
+        Some(")"),
+        Some(":"),
+        Some("?"),
+        Some("["),
+        Some("]"),
+        Some("{"),
+        Some("}"),
+        Some("nyi"),
+        Some("nye"),
+        Some("nya"),
+        Some("nyu"),
+        Some("nyo"),
+        Some("hyi"),
+        Some("hye"),
+        Some("hya"),
+        Some("hyu"),
+        Some("hyo"),
+        Some("byi"),
+        Some("bye"),
+        Some("bya"),
+        Some("byu"),
+        Some("byo"),
+        Some("pyi"),
+        Some("pye"),
+        Some("pya"),
+        Some("pyu"),
+        Some("pyo"),
+        Some("fyi"),
+        Some("fye"),
+        Some("fya"),
+        Some("fyu"),
+        Some("fyo"),
+        Some("myi"),
+        Some("mye"),
+        Some("mya"),
+        Some("myu"),
+        Some("myo"),
+        Some("ryi"),
+        Some("rye"),
+        Some("rya"),
+        Some("ryu"),
+        Some("ryo"),
+        Some("n'a"),
+        Some("n'i"),
+        Some("n'u"),
+        Some("n'e"),
+        Some("n'o"),
+        Some("n'ya"),
+        Some("n'yu"),
+        Some("n'yo"),
+        Some("va"),
+        Some("vi"),
+        Some("ve"),
+        Some("vo"),
+        Some("vya"),
+        Some("vyu"),
+        Some("vyo"),
+        Some("kkyi"),
+        Some("kkye"),
+        Some("kkya"),
+        Some("kkyu"),
+        Some("kkyo"),
+        Some("ggyi"),
+        Some("ggye"),
+        Some("ggya"),
+        Some("ggyu"),
+        Some("ggyo"),
+        Some("kkyi"),
+        Some("kkye"),
+        Some("kkya"),
+        Some("kkyu"),
+        Some("kkyo"),
+        Some("kkwa"),
+        Some("ggwa"),
+        Some("ssyi"),
+        Some("ssye"),
+        Some("ssya"),
+        Some("ssyu"),
+        Some("ssyo"),
+        Some("zzyi"),
+        Some("zzye"),
+        Some("zzya"),
+        Some("zzyu"),
+        Some("zzyo"),
+        Some("ttyi"),
+        Some("ttye"),
+        Some("ttya"),
+        Some("ttyu"),
+        Some("ttyo"),
+        Some("ddyi"),
+        Some("ddye"),
+        Some("ddya"),
+        Some("ddyu"),
+        Some("ddyo"),
+        Some("nyi"),
+        Some("nye"),
+        Some("nya"),
+        Some("nyu"),
+        Some("nyo"),
+        Some("hhyi"),
+        Some("hhye"),
+        Some("hhya"),
+        Some("hhyu"),
+        Some("hhyo"),
+        Some("bbyi"),
+        Some("bbye"),
+        Some("bbya"),
+        Some("bbyu"),
+        Some("bbyo"),
+        Some("ppyi"),
+        Some("ppye"),
+        Some("ppya"),
+        Some("ppyu"),
+        Some("ppyo"),
+        Some("ffyi"),
+        Some("ffye"),
+        Some("ffya"),
+        Some("ffyu"),
+        Some("ffyo"),
+        Some("mmyi"),
+        Some("mmye"),
+        Some("mmya"),
+        Some("mmyu"),
+        Some("mmyo"),
+        Some("rryi"),
+        Some("rrye"),
+        Some("rrya"),
+        Some("rryu"),
+        Some("rryo"),
+        Some("vva"),
+        Some("vvi"),
+        Some("vve"),
+        Some("vvo"),
+        Some("vvya"),
+        Some("vvyu"),
+        Some("vvyo"),
+    ],
+};
+
+pub(crate) static TO_ROMAJI_TRIE_PASSPORT: Trie = Trie {
+    root: (0, 107),
+    transitions: &[
+        ('\u{3000}', 107, 107),
+        ('、', 107, 107),
+        ('。', 107, 107),
+        ('「', 107, 107),
+        ('」', 107, 107),
+        ('『', 107, 107),
+        ('』', 107, 107),
+        ('〜', 107, 107),
+        ('ぁ', 107, 107),
+        ('あ', 107, 107),
+        ('ぃ', 107, 107),
+        ('い', 107, 107),
+        ('ぅ', 107, 107),
+        ('う', 107, 107),
+        ('ぇ', 107, 107),
+        ('え', 107, 107),
+        ('ぉ', 107, 107),
+        ('お', 107, 107),
+        ('か', 107, 107),
+        ('が', 107, 107),
+        ('き', 107, 112),
+        ('ぎ', 112, 117),
+        ('く', 117, 122),
+        ('ぐ', 122, 122),
+        ('け', 122, 122),
+        ('げ', 122, 122),
+        ('こ', 122, 122),
+        ('ご', 122, 122),
+        ('さ', 122, 122),
+        ('ざ', 122, 122),
+        ('し', 122, 127),
+        ('じ', 127, 132),
+        ('す', 132, 132),
+        ('ず', 132, 132),
+        ('せ', 132, 132),
+        ('ぜ', 132, 132),
+        ('そ', 132, 132),
+        ('ぞ', 132, 132),
+        ('た', 132, 132),
+        ('だ', 132, 132),
+        ('ち', 132, 137),
+        ('ぢ', 137, 142),
+        ('っ', 142, 243),
+        ('つ', 243, 243),
+        ('づ', 243, 243),
+        ('て', 243, 243),
+        ('で', 243, 243),
+        ('と', 243, 243),
+        ('ど', 243, 243),
+        ('な', 243, 243),
+        ('に', 243, 248),
+        ('ぬ', 248, 248),
+        ('ね', 248, 248),
+        ('の', 248, 248),
+        ('は', 248, 248),
+        ('ば', 248, 248),
+        ('ぱ', 248, 248),
+        ('ひ', 248, 253),
+        ('び', 253, 258),
+        ('ぴ', 258, 263),
+        ('ふ', 263, 268),
+        ('ぶ', 268, 268),
+        ('ぷ', 268, 268),
+        ('へ', 268, 268),
+        ('べ', 268, 268),
+        ('ぺ', 268, 268),
+        ('ほ', 268, 268),
+        ('ぼ', 268, 268),
+        ('ぽ', 268, 268),
+        ('ま', 268, 268),
+        ('み', 268, 273),
+        ('む', 273, 273),
+        ('め', 273, 273),
+        ('も', 273, 273),
+        ('ゃ', 273, 273),
+        ('や', 273, 273),
+        ('ゅ', 273, 273),
+        ('ゆ', 273, 273),
+        ('ょ', 273, 273),
+        ('よ', 273, 273),
+        ('ら', 273, 273),
+        ('り', 273, 278),
+        ('る', 278, 278),
+        ('れ', 278, 278),
+        ('ろ', 278, 278),
+        ('ゎ', 278, 278),
+        ('わ', 278, 278),
+        ('ゐ', 278, 278),
+        ('ゑ', 278, 278),
+        ('を', 278, 278),
+        ('ん', 278, 286),
+        ('ゔ', 286, 293),
+        ('ヷ', 293, 293),
+        ('ヸ', 293, 293),
+        ('ヹ', 293, 293),
+        ('ヺ', 293, 293),
+        ('・', 293, 293),
+        ('ー', 293, 293),
+        ('！', 293, 293),
+        ('（', 293, 293),
+        ('）', 293, 293),
+        ('：', 293, 293),
+        ('？', 293, 293),
+        ('［', 293, 293),
+        ('］', 293, 293),
+        ('｛', 293, 293),
+        ('｝', 293, 293),
+        ('ぃ', 293, 293),
+        ('ぇ', 293, 293),
+        ('ゃ', 293, 293),
+        ('ゅ', 293, 293),
+        ('ょ', 293, 293),
+        ('ぃ', 293, 293),
+        ('ぇ', 293, 293),
+        ('ゃ', 293, 293),
+        ('ゅ', 293, 293),
+        ('ょ', 293, 293),
+        ('ぃ', 293, 293),
+        ('ぇ', 293, 293),
+        ('ゃ', 293, 293),
+        ('ゅ', 293, 293),
+        ('ょ', 293, 293),
+        ('ぃ', 293, 293),
+        ('ぇ', 293, 293),
+        ('ゃ', 293, 293),
+        ('ゅ', 293, 293),
+        ('ょ', 293, 293),
+        ('ぃ', 293, 293),
+        ('ぇ', 293, 293),
+        ('ゃ', 293, 293),
+        ('ゅ', 293, 293),
+        ('ょ', 293, 293),
+        ('ぃ', 293, 293),
+        ('ぇ', 293, 293),
+        ('ゃ', 293, 293),
+        ('ゅ', 293, 293),
+        ('ょ', 293, 293),
+        ('ぃ', 293, 293),
+        ('ぇ', 293, 293),
+        ('ゃ', 293, 293),
+        ('ゅ', 293, 293),
+        ('ょ', 293, 293),
+        ('\u{3000}', 293, 293),
+        ('、', 293, 293),
+        ('。', 293, 293),
+        ('「', 293, 293),
+        ('」', 293, 293),
+        ('『', 293, 293),
+        ('』', 293, 293),
+        ('〜', 293, 293),
+        ('ぁ', 293, 293),
+        ('あ', 293, 293),
+        ('ぃ', 293, 293),
+        ('い', 293, 293),
+        ('ぅ', 293, 293),
+        ('う', 293, 293),
+        ('ぇ', 293, 293),
+        ('え', 293, 293),
+        ('ぉ', 293, 293),
+        ('お', 293, 293),
+        ('か', 293, 293),
+        ('が', 293, 293),
+        ('き', 293, 298),
+        ('ぎ', 298, 303),
+        ('く', 303, 308),
+        ('ぐ', 308, 308),
+        ('け', 308, 308),
+        ('げ', 308, 308),
+        ('こ', 308, 308),
+        ('ご', 308, 308),
+        ('さ', 308, 308),
+        ('ざ', 308, 308),
+        ('し', 308, 313),
+        ('じ', 313, 318),
+        ('す', 318, 318),
+        ('ず', 318, 318),
+        ('せ', 318, 318),
+        ('ぜ', 318, 318),
+        ('そ', 318, 318),
+        ('ぞ', 318, 318),
+        ('た', 318, 318),
+        ('だ', 318, 318),
+        ('ち', 318, 323),
+        ('ぢ', 323, 328),
+        ('つ', 328, 328),
+        ('づ', 328, 328),
+        ('て', 328, 328),
+        ('で', 328, 328),
+        ('と', 328, 328),
+        ('ど', 328, 328),
+        ('な', 328, 328),
+        ('に', 328, 333),
+        ('ぬ', 333, 333),
+        ('ね', 333, 333),
+        ('の', 333, 333),
+        ('は', 333, 333),
+        ('ば', 333, 333),
+        ('ぱ', 333, 333),
+        ('ひ', 333, 338),
+        ('び', 338, 343),
+        ('ぴ', 343, 348),
+        ('ふ', 348, 353),
+        ('ぶ', 353, 353),
+        ('ぷ', 353, 353),
+        ('へ', 353, 353),
+        ('べ', 353, 353),
+        ('ぺ', 353, 353),
+        ('ほ', 353, 353),
+        ('ぼ', 353, 353),
+        ('ぽ', 353, 353),
+        ('ま', 353, 353),
+        ('み', 353, 358),
+        ('む', 358, 358),
+        ('め', 358, 358),
+        ('も', 358, 358),
+        ('ゃ', 358, 358),
+        ('や', 358, 358),
+        ('ゅ', 358, 358),
+        ('ゆ', 358, 358),
+        ('ょ', 358, 358),
+        ('よ', 358, 358),
+        ('ら', 358, 358),
+        ('り', 358, 363),
+        ('る', 363, 363),
+        ('れ', 363, 363),
+        ('ろ', 363, 363),
+        ('わ', 363, 363),
+        ('ゐ', 363, 363),
+        ('ゑ', 363, 363),
+        ('を', 363, 363),
+        ('ん', 363, 363),
+        ('ゔ', 363, 370),
+        ('・', 370, 370),
+        ('ー', 370, 370),
+        ('！', 370, 370),
+        ('（', 370, 370),
+        ('）', 370, 370),
+        ('：', 370, 370),
+        ('？', 370, 370),
+        ('［', 370, 370),
+        ('］', 370, 370),
+        ('｛', 370, 370),
+        ('｝', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('あ', 370, 370),
+        ('い', 370, 370),
+        ('う', 370, 370),
+        ('え', 370, 370),
+        ('お', 370, 370),
+        ('や', 370, 370),
+        ('ゆ', 370, 370),
+        ('よ', 370, 370),
+        ('ぁ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ぉ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+        ('ぁ', 370, 370),
+        ('ぃ', 370, 370),
+        ('ぇ', 370, 370),
+        ('ぉ', 370, 370),
+        ('ゃ', 370, 370),
+        ('ゅ', 370, 370),
+        ('ょ', 370, 370),
+    ],
+    outputs: &[
+        Some(" "),
+        Some(","),
+        Some("."),
+        Some("‘"),
+        Some("’"),
+        Some("“"),
+        Some("”"),
+        Some("~"),
+        Some("a"),
+        Some("a"),
+        Some("i"),
+        Some("i"),
+        Some("u"),
+        Some("u"),
+        Some("e"),
+        Some("e"),
+        Some("o"),
+        Some("o"),
+        Some("ka"),
+        Some("ga"),
+        Some("ki"),
+        Some("gi"),
+        Some("ku"),
+        Some("gu"),
+        Some("ke"),
+        Some("ge"),
+        Some("ko"),
+        Some("go"),
+        Some("sa"),
+        Some("za"),
+        Some("shi"),
+        Some("ji"),
+        Some("su"),
+        Some("zu"),
+        Some("se"),
+        Some("ze"),
+        Some("so"),
+        Some("zo"),
+        Some("ta"),
+        Some("da"),
+        Some("chi"),
+        Some("ji"),
+        Some(""),
+        Some("tsu"),
+        Some("zu"),
+        Some("te"),
+        Some("de"),
+        Some("to"),
+        Some("do"),
+        Some("na"),
+        Some("ni"),
+        Some("nu"),
+        Some("ne"),
+        Some("no"),
+        Some("ha"),
+        Some("ba"),
+        Some("pa"),
+        Some("hi"),
+        Some("bi"),
+        Some("pi"),
+        Some("fu"),
+        Some("bu"),
+        Some("pu"),
+        Some("he"),
+        Some("be"),
+        Some("pe"),
+        Some("ho"),
+        Some("bo"),
+        Some("po"),
+        Some("ma"),
+        Some("mi"),
+        Some("mu"),
+        Some("me"),
+        Some("mo"),
+        Some("ya"),
+        Some("ya"),
+        Some("yu"),
+        Some("yu"),
+        Some("yo"),
+        Some("yo"),
+        Some("ra"),
+        Some("ri"),
+        Some("ru"),
+        Some("re"),
+        Some("ro"),
+        Some("wa"),
+        Some("wa"),
+        Some("wi"),
+        Some("we"),
+        Some("wo"),
+        Some("n"),
+        Some("vu"),
+        Some("va"),
+        Some("vi"),
+        Some("ve"),
+        Some("vo"),
+        Some("/"),
+        Some("-"),
+        Some("!"),
+        Some("("),
+        Some(")"),
+        Some(":"),
+        Some("?"),
+        Some("["),
+        Some("]"),
+        Some("{"),
+        Some("}"),
+        Some("kyi"),
+        Some("kye"),
+        Some("kya"),
+        Some("kyu"),
+        Some("kyo"),
+        Some("gyi"),
+        Some("gye"),
+        Some("gya"),
+        Some("gyu"),
+        Some("gyo"),
+        Some("kyi"),
+        Some("kye"),
+        Some("kya"),
+        Some("kyu"),
+        Some("kyo"),
+        Some("shyi"),
+        Some("she"),
+        Some("sha"),
+        Some("shu"),
+        Some("sho"),
+        Some("jyi"),
+        Some("je"),
+        Some("ja"),
+        Some("ju"),
+        Some("jo"),
+        Some("chyi"),
+        Some("che"),
+        Some("cha"),
+        Some("chu"),
+        Some("cho"),
+        Some("jyi"),
+        Some("je"),
+        Some("ja"),
+        Some("ju"),
+        Some("jo"),
+        Some(" "),
+        Some(","),
+        Some("."),
+        Some("‘"),
+        Some("’"),
+        Some("“"),
+        Some("”"),
+        Some("~"),
+        Some("a"),
+        Some("a"),
+        Some("i"),
+        Some("i"),
+        Some("u"),
+        Some("u"),
+        Some("e"),
+        Some("e"),
+        Some("o"),
+        Some("o"),
+        Some("kka"),
+        Some("gga"),
+        Some("kki"),
+        Some("ggi"),
+        Some("kku"),
+        Some("ggu"),
+        Some("kke"),
+        Some("gge"),
+        Some("kko"),
+        Some("ggo"),
+        Some("ssa"),
+        Some("zza"),
+        Some("sshi"),
+        Some("jji"),
+        Some("ssu"),
+        Some("zzu"),
+        Some("sse"),
+        Some("zze"),
+        Some("sso"),
+        Some("zzo"),
+        Some("tta"),
+        Some("dda"),
+        Some("tchi"),
+        Some("jji"),
+        Some("ttsu"),
+        Some("zzu"),
+        Some("tte"),
+        Some("dde"),
+        Some("tto"),
+        Some("ddo"),
+        Some("na"),
+        Some("ni"),
+        Some("nu"),
+        Some("ne"),
+        Some("no"),
+        Some("hha"),
+        Some("bba"),
+        Some("ppa"),
+        Some("hhi"),
+        Some("bbi"),
+        Some("ppi"),
+        Some("ffu"),
+        Some("bbu"),
+        Some("ppu"),
+        Some("hhe"),
+        Some("bbe"),
+        Some("ppe"),
+        Some("hho"),
+        Some("bbo"),
+        Some("ppo"),
+        Some("mma"),
+        Some("mmi"),
+        Some("mmu"),
+        Some("mme"),
+        Some("mmo"),
+        Some("ya"),
+        Some("ya"),
+        Some("yu"),
+        Some("yu"),
+        Some("yo"),
+        Some("yo"),
+        Some("rra"),
+        Some("rri"),
+        Some("rru"),
+        Some("rre"),
+        Some("rro"),
+        Some("wwa"),
+        Some("wwi"),
+        Some("wwe"),
+        Some("wwo"),
+        Some("n"),
+        Some("vvu"),
+        Some("/"),
+        Some("-"),
+        Some("!"),
+        Some("("),
+        Some(")"),
+        Some(":"),
+        Some("?"),
+        Some("["),
+        Some("]"),
+        Some("{"),
+        Some("}"),
+        Some("nyi"),
+        Some("nye"),
+        Some("nya"),
+        Some("nyu"),
+        Some("nyo"),
+        Some("hyi"),
+        Some("hye"),
+        Some("hya"),
+        Some("hyu"),
+        Some("hyo"),
+        Some("byi"),
+        Some("bye"),
+        Some("bya"),
+        Some("byu"),
+        Some("byo"),
+        Some("pyi"),
+        Some("pye"),
+        Some("pya"),
+        Some("pyu"),
+        Some("pyo"),
+        Some("fyi"),
+        Some("fye"),
+        Some("fya"),
+        Some("fyu"),
+        Some("fyo"),
+        Some("myi"),
+        Some("mye"),
+        Some("mya"),
+        Some("myu"),
+        Some("myo"),
+        Some("ryi"),
+        Some("rye"),
+        Some("rya"),
+        Some("ryu"),
+        Some("ryo"),
+        Some("na"),
+        Some("ni"),
+        Some("nu"),
+        Some("ne"),
+        Some("no"),
+        Some("nya"),
+        Some("nyu"),
+        Some("nyo"),
+        Some("va"),
+        Some("vi"),
+        Some("ve"),
+        Some("vo"),
+        Some("vya"),
+        Some("vyu"),
+        Some("vyo"),
+        Some("kkyi"),
+        Some("kkye"),
+        Some("kkya"),
+        Some("kkyu"),
+        Some("kkyo"),
+        Some("ggyi"),
+        Some("ggye"),
+        Some("ggya"),
+        Some("ggyu"),
+        Some("ggyo"),
+        Some("kkyi"),
+        Some("kkye"),
+        Some("kkya"),
+        Some("kkyu"),
+        Some("kkyo"),
+        Some("sshyi"),
+        Some("sshe"),
+        Some("ssha"),
+        Some("sshu"),
+        Some("ssho"),
+        Some("jjyi"),
+        Some("jje"),
+        Some("jja"),
+        Some("jju"),
+        Some("jjo"),
+        Some("tchyi"),
+        Some("tche"),
+        Some("tcha"),
+        Some("tchu"),
+        Some("tcho"),
+        Some("jjyi"),
+        Some("jje"),
+        Some("jja"),
+        Some("jju"),
+        Some("jjo"),
+        Some("nyi"),
+        Some("nye"),
+        Some("nya"),
+        Some("nyu"),
+        Some("nyo"),
+        Some("hhyi"),
+        Some("hhye"),
+        Some("hhya"),
+        Some("hhyu"),
+        Some("hhyo"),
+        Some("bbyi"),
+        Some("bbye"),
+        Some("bbya"),
+        Some("bbyu"),
+        Some("bbyo"),
+        Some("ppyi"),
+        Some("ppye"),
+        Some("ppya"),
+        Some("ppyu"),
+        Some("ppyo"),
+        Some("ffyi"),
+        Some("ffye"),
+        Some("ffya"),
+        Some("ffyu"),
+        Some("ffyo"),
+        Some("mmyi"),
+        Some("mmye"),
+        Some("mmya"),
+        Some("mmyu"),
+        Some("mmyo"),
+        Some("rryi"),
+        Some("rrye"),
+        Some("rrya"),
+        Some("rryu"),
+        Some("rryo"),
+        Some("vva"),
+        Some("vvi"),
+        Some("vve"),
+        Some("vvo"),
+        Some("vvya"),
+        Some("vvyu"),
+        Some("vvyo"),
+    ],
+};
+
+pub(crate) static TO_ROMAJI_TRIE_STRICT: Trie = Trie {
+    root: (0, 103),
+    transitions: &[
+        ('\u{3000}', 103, 103),
+        ('、', 103, 103),
+        ('。', 103, 103),
+        ('「', 103, 103),
+        ('」', 103, 103),
+        ('『', 103, 103),
+        ('』', 103, 103),
+        ('〜', 103, 103),
+        ('ぁ', 103, 103),
+        ('あ', 103, 103),
+        ('ぃ', 103, 103),
+        ('い', 103, 103),
+        ('ぅ', 103, 103),
+        ('う', 103, 103),
+        ('ぇ', 103, 103),
+        ('え', 103, 103),
+        ('ぉ', 103, 103),
+        ('お', 103, 103),
+        ('か', 103, 103),
+        ('が', 103, 103),
+        ('き', 103, 108),
+        ('ぎ', 108, 113),
+        ('く', 113, 113),
+        ('ぐ', 113, 113),
+        ('け', 113, 113),
+        ('げ', 113, 113),
+        ('こ', 113, 113),
+        ('ご', 113, 113),
+        ('さ', 113, 113),
+        ('ざ', 113, 113),
+        ('し', 113, 118),
+        ('じ', 118, 123),
+        ('す', 123, 123),
+        ('ず', 123, 123),
+        ('せ', 123, 123),
+        ('ぜ', 123, 123),
+        ('そ', 123, 123),
+        ('ぞ', 123, 123),
+        ('た', 123, 123),
+        ('だ', 123, 123),
+        ('ち', 123, 128),
+        ('ぢ', 128, 133),
+        ('っ', 133, 191),
+        ('つ', 191, 191),
+        ('づ', 191, 191),
+        ('て', 191, 191),
+        ('で', 191, 191),
+        ('と', 191, 191),
+        ('ど', 191, 191),
+        ('な', 191, 191),
+        ('に', 191, 196),
+        ('ぬ', 196, 196),
+        ('ね', 196, 196),
+        ('の', 196, 196),
+        ('は', 196, 196),
+        ('ば', 196, 196),
+        ('ぱ', 196, 196),
+        ('ひ', 196, 201),
+        ('び', 201, 206),
+        ('ぴ', 206, 211),
+        ('ふ', 211, 216),
+        ('ぶ', 216, 216),
+        ('ぷ', 216, 216),
+        ('へ', 216, 216),
+        ('べ', 216, 216),
+        ('ぺ', 216, 216),
+        ('ほ', 216, 216),
+        ('ぼ', 216, 216),
+        ('ぽ', 216, 216),
+        ('ま', 216, 216),
+        ('み', 216, 221),
+        ('む', 221, 221),
+        ('め', 221, 221),
+        ('も', 221, 221),
+        ('ゃ', 221, 221),
+        ('や', 221, 221),
+        ('ゅ', 221, 221),
+        ('ゆ', 221, 221),
+        ('ょ', 221, 221),
+        ('よ', 221, 221),
+        ('ら', 221, 221),
+        ('り', 221, 226),
+        ('る', 226, 226),
+        ('れ', 226, 226),
+        ('ろ', 226, 226),
+        ('ゎ', 226, 226),
+        ('わ', 226, 226),
+        ('ゐ', 226, 226),
+        ('ゑ', 226, 226),
+        ('を', 226, 226),
+        ('ん', 226, 234),
+        ('ゔ', 234, 241),
+        ('・', 241, 241),
+        ('ー', 241, 241),
+        ('！', 241, 241),
+        ('（', 241, 241),
+        ('）', 241, 241),
+        ('：', 241, 241),
+        ('？', 241, 241),
+        ('［', 241, 241),
+        ('］', 241, 241),
+        ('｛', 241, 241),
+        ('｝', 241, 241),
+        ('ぃ', 241, 241),
+        ('ぇ', 241, 241),
+        ('ゃ', 241, 241),
+        ('ゅ', 241, 241),
+        ('ょ', 241, 241),
+        ('ぃ', 241, 241),
+        ('ぇ', 241, 241),
+        ('ゃ', 241, 241),
+        ('ゅ', 241, 241),
+        ('ょ', 241, 241),
+        ('ぃ', 241, 241),
+        ('ぇ', 241, 241),
+        ('ゃ', 241, 241),
+        ('ゅ', 241, 241),
+        ('ょ', 241, 241),
+        ('ぃ', 241, 241),
+        ('ぇ', 241, 241),
+        ('ゃ', 241, 241),
+        ('ゅ', 241, 241),
+        ('ょ', 241, 241),
+        ('ぃ', 241, 241),
+        ('ぇ', 241, 241),
+        ('ゃ', 241, 241),
+        ('ゅ', 241, 241),
+        ('ょ', 241, 241),
+        ('ぃ', 241, 241),
+        ('ぇ', 241, 241),
+        ('ゃ', 241, 241),
+        ('ゅ', 241, 241),
+        ('ょ', 241, 241),
+        ('か', 241, 241),
+        ('が', 241, 241),
+        ('き', 241, 246),
+        ('ぎ', 246, 251),
+        ('く', 251, 251),
+        ('ぐ', 251, 251),
+        ('け', 251, 251),
+        ('げ', 251, 251),
+        ('こ', 251, 251),
+        ('ご', 251, 251),
+        ('さ', 251, 251),
+        ('ざ', 251, 251),
+        ('し', 251, 256),
+        ('じ', 256, 261),
+        ('す', 261, 261),
+        ('ず', 261, 261),
+        ('せ', 261, 261),
+        ('ぜ', 261, 261),
+        ('そ', 261, 261),
+        ('ぞ', 261, 261),
+        ('た', 261, 261),
+        ('だ', 261, 261),
+        ('ち', 261, 266),
+        ('ぢ', 266, 271),
+        ('つ', 271, 271),
+        ('づ', 271, 271),
+        ('て', 271, 271),
+        ('で', 271, 271),
+        ('と', 271, 271),
+        ('ど', 271, 271),
+        ('は', 271, 271),
+        ('ば', 271, 271),
+        ('ぱ', 271, 271),
+        ('ひ', 271, 276),
+        ('び', 276, 281),
+        ('ぴ', 281, 286),
+        ('ふ', 286, 291),
+        ('ぶ', 291, 291),
+        ('ぷ', 291, 291),
+        ('へ', 291, 291),
+        ('べ', 291, 291),
+        ('ぺ', 291, 291),
+        ('ほ', 291, 291),
+        ('ぼ', 291, 291),
+        ('ぽ', 291, 291),
+        ('ま', 291, 291),
+        ('み', 291, 296),
+        ('む', 296, 296),
+        ('め', 296, 296),
+        ('も', 296, 296),
+        ('ら', 296, 296),
+        ('り', 296, 301),
+        ('る', 301, 301),
+        ('れ', 301, 301),
+        ('ろ', 301, 301),
+        ('わ', 301, 301),
+        ('を', 301, 301),
+        ('ゔ', 301, 308),
+        ('ぃ', 308, 308),
+        ('ぇ', 308, 308),
+        ('ゃ', 308, 308),
+        ('ゅ', 308, 308),
+        ('ょ', 308, 308),
+        ('ぃ', 308, 308),
+        ('ぇ', 308, 308),
+        ('ゃ', 308, 308),
+        ('ゅ', 308, 308),
+        ('ょ', 308, 308),
+        ('ぃ', 308, 308),
+        ('ぇ', 308, 308),
+        ('ゃ', 308, 308),
+        ('ゅ', 308, 308),
+        ('ょ', 308, 308),
+        ('ぃ', 308, 308),
+        ('ぇ', 308, 308),
+        ('ゃ', 308, 308),
+        ('ゅ', 308, 308),
+        ('ょ', 308, 308),
+        ('ぃ', 308, 308),
+        ('ぇ', 308, 308),
+        ('ゃ', 308, 308),
+        ('ゅ', 308, 308),
+        ('ょ', 308, 308),
+        ('ぃ', 308, 308),
+        ('ぇ', 308, 308),
+        ('ゃ', 308, 308),
+        ('ゅ', 308, 308),
+        ('ょ', 308, 308),
+        ('ぃ', 308, 308),
+        ('ぇ', 308, 308),
+        ('ゃ', 308, 308),
+        ('ゅ', 308, 308),
+        ('ょ', 308, 308),
+        ('あ', 308, 308),
+        ('い', 308, 308),
+        ('う', 308, 308),
+        ('え', 308, 308),
+        ('お', 308, 308),
+        ('や', 308, 308),
+        ('ゆ', 308, 308),
+        ('よ', 308, 308),
+        ('ぁ', 308, 308),
+        ('ぃ', 308, 308),
+        ('ぇ', 308, 308),
+        ('ぉ', 308, 308),
+        ('ゃ', 308, 308),
+        ('ゅ', 308, 308),
+        ('ょ', 308, 308),
+        ('ぃ', 308, 308),
+        ('ぇ', 308, 308),
+        ('ゃ', 308, 308),
+        ('ゅ', 308, 308),
+        ('ょ', 308, 308),
+        ('ぃ', 308, 308),
+        ('ぇ', 308, 308),
+        ('ゃ', 308, 308),
+        ('ゅ', 308, 308),
+        ('ょ', 308, 308),
+        ('ぃ', 308, 308),
+        ('ぇ', 308, 308),
+        ('ゃ', 308, 308),
+        ('ゅ', 308, 308),
+        ('ょ', 308, 308),
+        ('ぃ', 308, 308),
+        ('ぇ', 308, 308),
+        ('ゃ', 308, 308),
+        ('ゅ', 308, 308),
+        ('ょ', 308, 308),
+        ('ぃ', 308, 308),
+        ('ぇ', 308, 308),
+        ('ゃ', 308, 308),
+        ('ゅ', 308, 308),
+        ('ょ', 308, 308),
+        ('ぃ', 308, 308),
+        ('ぇ', 308, 308),
+        ('ゃ', 308, 308),
+        ('ゅ', 308, 308),
+        ('ょ', 308, 308),
+        ('ぃ', 308, 308),
+        ('ぇ', 308, 308),
+        ('ゃ', 308, 308),
+        ('ゅ', 308, 308),
+        ('ょ', 308, 308),
+        ('ぃ', 308, 308),
+        ('ぇ', 308, 308),
+        ('ゃ', 308, 308),
+        ('ゅ', 308, 308),
+        ('ょ', 308, 308),
+        ('ぃ', 308, 308),
+        ('ぇ', 308, 308),
+        ('ゃ', 308, 308),
+        ('ゅ', 308, 308),
+        ('ょ', 308, 308),
+        ('ぃ', 308, 308),
+        ('ぇ', 308, 308),
+        ('ゃ', 308, 308),
+        ('ゅ', 308, 308),
+        ('ょ', 308, 308),
+        ('ぃ', 308, 308),
+        ('ぇ', 308, 308),
+        ('ゃ', 308, 308),
+        ('ゅ', 308, 308),
+        ('ょ', 308, 308),
+        ('ぃ', 308, 308),
+        ('ぇ', 308, 308),
+        ('ゃ', 308, 308),
+        ('ゅ', 308, 308),
+        ('ょ', 308, 308),
+        ('ぁ', 308, 308),
+        ('ぃ', 308, 308),
+        ('ぇ', 308, 308),
+        ('ぉ', 308, 308),
+        ('ゃ', 308, 308),
+        ('ゅ', 308, 308),
+        ('ょ', 308, 308),
+    ],
+    outputs: &[
+        Some("\u{3000}"),
+        Some(","),
+        Some("."),
+        Some("‘"),
+        Some("’"),
+        Some("“"),
+        Some("”"),
+        Some("~"),
+        Some("xa"),
+        Some("a"),
+        Some("xi"),
+        Some("i"),
+        Some("xu"),
+        Some("u"),
+        Some("xe"),
+        Some("e"),
+        Some("xo"),
+        Some("o"),
+        Some("ka"),
+        Some("ga"),
+        Some("ki"),
+        Some("gi"),
+        Some("ku"),
+        Some("gu"),
+        Some("ke"),
+        Some("ge"),
+        Some("ko"),
+        Some("go"),
+        Some("sa"),
+        Some("za"),
+        Some("si"),
+        Some("zi"),
+        Some("su"),
+        Some("zu"),
+        Some("se"),
+        Some("ze"),
+        Some("so"),
+        Some("zo"),
+        Some("ta"),
+        Some("da"),
+        Some("ti"),
+        Some("di"),
+        Some("xtu"),
+        Some("tu"),
+        Some("du"),
+        Some("te"),
+        Some("de"),
+        Some("to"),
+        Some("do"),
+        Some("na"),
+        Some("ni"),
+        Some("nu"),
+        Some("ne"),
+        Some("no"),
+        Some("ha"),
+        Some("ba"),
+        Some("pa"),
+        Some("hi"),
+        Some("bi"),
+        Some("pi"),
+        Some("hu"),
+        Some("bu"),
+        Some("pu"),
+        Some("he"),
+        Some("be"),
+        Some("pe"),
+        Some("ho"),
+        Some("bo"),
+        Some("po"),
+        Some("ma"),
+        Some("mi"),
+        Some("mu"),
+        Some("me"),
+        Some("mo"),
+        Some("xya"),
+        Some("ya"),
+        Some("xyu"),
+        Some("yu"),
+        Some("xyo"),
+        Some("yo"),
+        Some("ra"),
+        Some("ri"),
+        Some("ru"),
+        Some("re"),
+        Some("ro"),
+        Some("xwa"),
+        Some("wa"),
+        Some("wi"),
+        Some("we"),
+        Some("wo"),
+        Some("n"),
+        Some("vu"),
+        Some("/"),
+        Some("-"),
+        Some("!"),
+        Some("("),
+        Some(")"),
+        Some(":"),
+        Some("?"),
+        Some("["),
+        Some("]"),
+        Some("{"),
+        Some("}"),
+        Some("kyi"),
+        Some("kye"),
+        Some("kya"),
+        Some("kyu"),
+        Some("kyo"),
+        Some("gyi"),
+        Some("gye"),
+        Some("gya"),
+        Some("gyu"),
+        Some("gyo"),
+        Some("syi"),
+        Some("sye"),
+        Some("sya"),
+        Some("syu"),
+        Some("syo"),
+        Some("zyi"),
+        Some("zye"),
+        Some("zya"),
+        Some("zyu"),
+        Some("zyo"),
+        Some("tyi"),
+        Some("tye"),
+        Some("tya"),
+        Some("tyu"),
+        Some("tyo"),
+        Some("dyi"),
+        Some("dye"),
+        Some("dya"),
+        Some("dyu"),
+        Some("dyo"),
+        Some("kka"),
+        Some("gga"),
+        Some("kki"),
+        Some("ggi"),
+        Some("kku"),
+        Some("ggu"),
+        Some("kke"),
+        Some("gge"),
+        Some("kko"),
+        Some("ggo"),
+        Some("ssa"),
+        Some("zza"),
+        Some("ssi"),
+        Some("zzi"),
+        Some("ssu"),
+        Some("zzu"),
+        Some("sse"),
+        Some("zze"),
+        Some("sso"),
+        Some("zzo"),
+        Some("tta"),
+        Some("dda"),
+        Some("tti"),
+        Some("ddi"),
+        Some("ttu"),
+        Some("ddu"),
+        Some("tte"),
+        Some("dde"),
+        Some("tto"),
+        Some("ddo"),
+        Some("hha"),
+        Some("bba"),
+        Some("ppa"),
+        Some("hhi"),
+        Some("bbi"),
+        Some("ppi"),
+        Some("hhu"),
+        Some("bbu"),
+        Some("ppu"),
+        Some("hhe"),
+        Some("bbe"),
+        Some("ppe"),
+        Some("hho"),
+        Some("bbo"),
+        Some("ppo"),
+        Some("mma"),
+        Some("mmi"),
+        Some("mmu"),
+        Some("mme"),
+        Some("mmo"),
+        Some("rra"),
+        Some("rri"),
+        Some("rru"),
+        Some("rre"),
+        Some("rro"),
+        Some("wwa"),
+        Some("wwo"),
+        Some("vvu"),
+        Some("nyi"),
+        Some("nye"),
+        Some("nya"),
+        Some("nyu"),
+        Some("nyo"),
+        Some("hyi"),
+        Some("hye"),
+        Some("hya"),
+        Some("hyu"),
+        Some("hyo"),
+        Some("byi"),
+        Some("bye"),
+        Some("bya"),
+        Some("byu"),
+        Some("byo"),
+        Some("pyi"),
+        Some("pye"),
+        Some("pya"),
+        Some("pyu"),
+        Some("pyo"),
+        Some("fyi"),
+        Some("fye"),
+        Some("fya"),
+        Some("fyu"),
+        Some("fyo"),
+        Some("myi"),
+        Some("mye"),
+        Some("mya"),
+        Some("myu"),
+        Some("myo"),
+        Some("ryi"),
+        Some("rye"),
+        Some("rya"),
+        Some("ryu"),
+        Some("ryo"),
+        Some("n'a"),
+        Some("n'i"),
+        Some("n'u"),
+        Some("n'e"),
+        Some("n'o"),
+        Some("n'ya"),
+        Some("n'yu"),
+        Some("n'yo"),
+        Some("va"),
+        Some("vi"),
+        Some("ve"),
+        Some("vo"),
+        Some("vya"),
+        Some("vyu"),
+        Some("vyo"),
+        Some("kkyi"),
+        Some("kkye"),
+        Some("kkya"),
+        Some("kkyu"),
+        Some("kkyo"),
+        Some("ggyi"),
+        Some("ggye"),
+        Some("ggya"),
+        Some("ggyu"),
+        Some("ggyo"),
+        Some("ssyi"),
+        Some("ssye"),
+        Some("ssya"),
+        Some("ssyu"),
+        Some("ssyo"),
+        Some("zzyi"),
+        Some("zzye"),
+        Some("zzya"),
+        Some("zzyu"),
+        Some("zzyo"),
+        Some("ttyi"),
+        Some("ttye"),
+        Some("ttya"),
+        Some("ttyu"),
+        Some("ttyo"),
+        Some("ddyi"),
+        Some("ddye"),
+        Some("ddya"),
+        Some("ddyu"),
+        Some("ddyo"),
+        Some("hhyi"),
+        Some("hhye"),
+        Some("hhya"),
+        Some("hhyu"),
+        Some("hhyo"),
+        Some("bbyi"),
+        Some("bbye"),
+        Some("bbya"),
+        Some("bbyu"),
+        Some("bbyo"),
+        Some("ppyi"),
+        Some("ppye"),
+        Some("ppya"),
+        Some("ppyu"),
+        Some("ppyo"),
+        Some("ffyi"),
+        Some("ffye"),
+        Some("ffya"),
+        Some("ffyu"),
+        Some("ffyo"),
+        Some("mmyi"),
+        Some("mmye"),
+        Some("mmya"),
+        Some("mmyu"),
+        Some("mmyo"),
+        Some("rryi"),
+        Some("rrye"),
+        Some("rrya"),
+        Some("rryu"),
+        Some("rryo"),
+        Some("vva"),
+        Some("vvi"),
+        Some("vve"),
+        Some("vvo"),
+        Some("vvya"),
+        Some("vvyu"),
+        Some("vvyo"),
+    ],
+};
@@ -0,0 +1,887 @@
+//! Generated by upstream_conversions from data/to_ukrainian.json, do not edit.
+
+use crate::trie::Trie;
+
+pub(crate) static TO_UKRAINIAN_TRIE: Trie = Trie {
+    root: (0, 103),
+    transitions: &[
+        ('\u{3000}', 103, 103),
+        ('、', 103, 103),
+        ('。', 103, 103),
+        ('「', 103, 103),
+        ('」', 103, 103),
+        ('『', 103, 103),
+        ('』', 103, 103),
+        ('〜', 103, 103),
+        ('ぁ', 103, 103),
+        ('あ', 103, 103),
+        ('ぃ', 103, 105),
+        ('い', 105, 107),
+        ('ぅ', 107, 107),
+        ('う', 107, 107),
+        ('ぇ', 107, 107),
+        ('え', 107, 108),
+        ('ぉ', 108, 108),
+        ('お', 108, 110),
+        ('か', 110, 111),
+        ('が', 111, 111),
+        ('き', 111, 115),
+        ('ぎ', 115, 119),
+        ('く', 119, 123),
+        ('ぐ', 123, 124),
+        ('け', 124, 124),
+        ('げ', 124, 124),
+        ('こ', 124, 125),
+        ('ご', 125, 126),
+        ('さ', 126, 126),
+        ('ざ', 126, 126),
+        ('し', 126, 130),
+        ('じ', 130, 134),
+        ('す', 134, 134),
+        ('ず', 134, 134),
+        ('せ', 134, 134),
+        ('ぜ', 134, 134),
+        ('そ', 134, 135),
+        ('ぞ', 135, 136),
+        ('た', 136, 136),
+        ('だ', 136, 136),
+        ('ち', 136, 140),
+        ('ぢ', 140, 144),
+        ('っ', 144, 245),
+        ('つ', 245, 245),
+        ('づ', 245, 245),
+        ('て', 245, 245),
+        ('で', 245, 246),
+        ('と', 246, 247),
+        ('ど', 247, 248),
+        ('な', 248, 248),
+        ('に', 248, 252),
+        ('ぬ', 252, 252),
+        ('ね', 252, 253),
+        ('の', 253, 253),
+        ('は', 253, 253),
+        ('ば', 253, 254),
+        ('ぱ', 254, 254),
+        ('ひ', 254, 258),
+        ('び', 258, 262),
+        ('ぴ', 262, 266),
+        ('ふ', 266, 270),
+        ('ぶ', 270, 270),
+        ('ぷ', 270, 270),
+        ('へ', 270, 270),
+        ('べ', 270, 270),
+        ('ぺ', 270, 270),
+        ('ほ', 270, 271),
+        ('ぼ', 271, 272),
+        ('ぽ', 272, 273),
+        ('ま', 273, 273),
+        ('み', 273, 277),
+        ('む', 277, 277),
+        ('め', 277, 277),
+        ('も', 277, 278),
+        ('ゃ', 278, 278),
+        ('や', 278, 278),
+        ('ゅ', 278, 278),
+        ('ゆ', 278, 279),
+        ('ょ', 279, 279),
+        ('よ', 279, 280),
+        ('ら', 280, 280),
+        ('り', 280, 284),
+        ('る', 284, 284),
+        ('れ', 284, 284),
+        ('ろ', 284, 285),
+        ('ゎ', 285, 285),
+        ('わ', 285, 285),
+        ('ゐ', 285, 285),
+        ('ゑ', 285, 285),
+        ('を', 285, 286),
+        ('ん', 286, 310),
+        ('ゔ', 310, 311),
+        ('・', 311, 311),
+        ('ー', 311, 311),
+        ('！', 311, 311),
+        ('（', 311, 311),
+        ('）', 311, 311),
+        ('：', 311, 311),
+        ('？', 311, 311),
+        ('［', 311, 311),
+        ('］', 311, 311),
+        ('｛', 311, 311),
+        ('｝', 311, 311),
+        ('ぃ', 311, 311),
+        ('ぇ', 311, 311),
+        ('い', 311, 311),
+        ('え', 311, 312),
+        ('い', 312, 312),
+        ('う', 312, 312),
+        ('お', 312, 312),
+        ('あ', 312, 312),
+        ('ぇ', 312, 312),
+        ('ゃ', 312, 312),
+        ('ゅ', 312, 312),
+        ('ょ', 312, 313),
+        ('ぇ', 313, 313),
+        ('ゃ', 313, 313),
+        ('ゅ', 313, 313),
+        ('ょ', 313, 314),
+        ('ぇ', 314, 314),
+        ('ゃ', 314, 314),
+        ('ゅ', 314, 314),
+        ('ょ', 314, 315),
+        ('う', 315, 315),
+        ('う', 315, 315),
+        ('う', 315, 315),
+        ('ぇ', 315, 315),
+        ('ゃ', 315, 315),
+        ('ゅ', 315, 316),
+        ('ょ', 316, 317),
+        ('ぇ', 317, 317),
+        ('ゃ', 317, 317),
+        ('ゅ', 317, 317),
+        ('ょ', 317, 318),
+        ('う', 318, 318),
+        ('う', 318, 318),
+        ('ぇ', 318, 318),
+        ('ゃ', 318, 318),
+        ('ゅ', 318, 318),
+        ('ょ', 318, 319),
+        ('ぇ', 319, 319),
+        ('ゃ', 319, 319),
+        ('ゅ', 319, 319),
+        ('ょ', 319, 320),
+        ('\u{3000}', 320, 320),
+        ('、', 320, 320),
+        ('。', 320, 320),
+        ('「', 320, 320),
+        ('」', 320, 320),
+        ('『', 320, 320),
+        ('』', 320, 320),
+        ('〜', 320, 320),
+        ('ぁ', 320, 320),
+        ('あ', 320, 320),
+        ('ぃ', 320, 320),
+        ('い', 320, 320),
+        ('ぅ', 320, 320),
+        ('う', 320, 320),
+        ('ぇ', 320, 320),
+        ('え', 320, 320),
+        ('ぉ', 320, 320),
+        ('お', 320, 320),
+        ('か', 320, 320),
+        ('が', 320, 320),
+        ('き', 320, 324),
+        ('ぎ', 324, 328),
+        ('く', 328, 332),
+        ('ぐ', 332, 332),
+        ('け', 332, 332),
+        ('げ', 332, 332),
+        ('こ', 332, 333),
+        ('ご', 333, 334),
+        ('さ', 334, 334),
+        ('ざ', 334, 334),
+        ('し', 334, 338),
+        ('じ', 338, 342),
+        ('す', 342, 342),
+        ('ず', 342, 342),
+        ('せ', 342, 342),
+        ('ぜ', 342, 342),
+        ('そ', 342, 343),
+        ('ぞ', 343, 344),
+        ('た', 344, 344),
+        ('だ', 344, 344),
+        ('ち', 344, 348),
+        ('ぢ', 348, 352),
+        ('つ', 352, 352),
+        ('づ', 352, 352),
+        ('て', 352, 352),
+        ('で', 352, 352),
+        ('と', 352, 353),
+        ('ど', 353, 354),
+        ('な', 354, 354),
+        ('に', 354, 358),
+        ('ぬ', 358, 358),
+        ('ね', 358, 358),
+        ('の', 358, 359),
+        ('は', 359, 359),
+        ('ば', 359, 359),
+        ('ぱ', 359, 359),
+        ('ひ', 359, 363),
+        ('び', 363, 367),
+        ('ぴ', 367, 371),
+        ('ふ', 371, 375),
+        ('ぶ', 375, 375),
+        ('ぷ', 375, 375),
+        ('へ', 375, 375),
+        ('べ', 375, 375),
+        ('ぺ', 375, 375),
+        ('ほ', 375, 376),
+        ('ぼ', 376, 377),
+        ('ぽ', 377, 378),
+        ('ま', 378, 378),
+        ('み', 378, 382),
+        ('む', 382, 382),
+        ('め', 382, 382),
+        ('も', 382, 383),
+        ('ゃ', 383, 383),
+        ('や', 383, 383),
+        ('ゅ', 383, 383),
+        ('ゆ', 383, 383),
+        ('ょ', 383, 384),
+        ('よ', 384, 385),
+        ('ら', 385, 385),
+        ('り', 385, 389),
+        ('る', 389, 389),
+        ('れ', 389, 389),
+        ('ろ', 389, 390),
+        ('わ', 390, 390),
+        ('ゐ', 390, 390),
+        ('ゑ', 390, 390),
+        ('を', 390, 391),
+        ('ん', 391, 391),
+        ('ゔ', 391, 392),
+        ('・', 392, 392),
+        ('ー', 392, 392),
+        ('！', 392, 392),
+        ('（', 392, 392),
+        ('）', 392, 392),
+        ('：', 392, 392),
+        ('？', 392, 392),
+        ('［', 392, 392),
+        ('］', 392, 392),
+        ('｛', 392, 392),
+        ('｝', 392, 392),
+        ('ぃ', 392, 392),
+        ('う', 392, 392),
+        ('う', 392, 392),
+        ('ぇ', 392, 392),
+        ('ゃ', 392, 392),
+        ('ゅ', 392, 392),
+        ('ょ', 392, 393),
+        ('え', 393, 393),
+        ('あ', 393, 393),
+        ('ぇ', 393, 393),
+        ('ゃ', 393, 393),
+        ('ゅ', 393, 393),
+        ('ょ', 393, 394),
+        ('ぇ', 394, 394),
+        ('ゃ', 394, 394),
+        ('ゅ', 394, 394),
+        ('ょ', 394, 395),
+        ('ぇ', 395, 395),
+        ('ゃ', 395, 395),
+        ('ゅ', 395, 395),
+        ('ょ', 395, 396),
+        ('ぇ', 396, 396),
+        ('ゃ', 396, 396),
+        ('ゅ', 396, 396),
+        ('ょ', 396, 397),
+        ('う', 397, 397),
+        ('う', 397, 397),
+        ('う', 397, 397),
+        ('ぇ', 397, 397),
+        ('ゃ', 397, 397),
+        ('ゅ', 397, 397),
+        ('ょ', 397, 398),
+        ('う', 398, 398),
+        ('う', 398, 398),
+        ('う', 398, 398),
+        ('え', 398, 398),
+        ('や', 398, 398),
+        ('ゅ', 398, 399),
+        ('ょ', 399, 400),
+        ('う', 400, 400),
+        ('う', 400, 400),
+        ('「', 400, 408),
+        ('あ', 408, 408),
+        ('い', 408, 408),
+        ('う', 408, 408),
+        ('え', 408, 408),
+        ('お', 408, 408),
+        ('ば', 408, 408),
+        ('ぱ', 408, 408),
+        ('び', 408, 411),
+        ('ぴ', 411, 414),
+        ('ぶ', 414, 414),
+        ('ぷ', 414, 414),
+        ('べ', 414, 414),
+        ('ぺ', 414, 414),
+        ('ぼ', 414, 414),
+        ('ぽ', 414, 415),
+        ('ま', 415, 415),
+        ('み', 415, 418),
+        ('む', 418, 418),
+        ('め', 418, 418),
+        ('も', 418, 419),
+        ('や', 419, 419),
+        ('ゆ', 419, 420),
+        ('よ', 420, 421),
+        ('う', 421, 421),
+        ('い', 421, 421),
+        ('う', 421, 421),
+        ('う', 421, 421),
+        ('う', 421, 421),
+        ('う', 421, 421),
+        ('う', 421, 421),
+        ('う', 421, 421),
+        ('う', 421, 421),
+        ('う', 421, 421),
+        ('ぇ', 421, 421),
+        ('ゃ', 421, 421),
+        ('ゅ', 421, 421),
+        ('ょ', 421, 422),
+        ('ぇ', 422, 422),
+        ('ゃ', 422, 422),
+        ('ゅ', 422, 422),
+        ('ょ', 422, 423),
+        ('ぇ', 423, 423),
+        ('ゃ', 423, 423),
+        ('ゅ', 423, 423),
+        ('ょ', 423, 424),
+        ('う', 424, 424),
+        ('う', 424, 424),
+        ('ぇ', 424, 424),
+        ('ゃ', 424, 424),
+        ('ゅ', 424, 424),
+        ('ょ', 424, 425),
+        ('ぇ', 425, 425),
+        ('ゃ', 425, 425),
+        ('ゅ', 425, 425),
+        ('ょ', 425, 426),
+        ('う', 426, 426),
+        ('う', 426, 426),
+        ('ぇ', 426, 426),
+        ('ゃ', 426, 426),
+        ('ゅ', 426, 426),
+        ('ょ', 426, 427),
+        ('ぇ', 427, 427),
+        ('ゃ', 427, 427),
+        ('ゅ', 427, 427),
+        ('ょ', 427, 428),
+        ('う', 428, 428),
+        ('う', 428, 428),
+        ('ぇ', 428, 428),
+        ('ゃ', 428, 428),
+        ('ゅ', 428, 428),
+        ('ょ', 428, 428),
+        ('う', 428, 428),
+        ('ぇ', 428, 428),
+        ('ゃ', 428, 428),
+        ('ゅ', 428, 428),
+        ('ょ', 428, 429),
+        ('ぇ', 429, 429),
+        ('ゃ', 429, 429),
+        ('ゅ', 429, 429),
+        ('ょ', 429, 430),
+        ('ぇ', 430, 430),
+        ('ゃ', 430, 430),
+        ('ゅ', 430, 430),
+        ('ょ', 430, 431),
+        ('ぇ', 431, 431),
+        ('ゃ', 431, 431),
+        ('ゅ', 431, 431),
+        ('ょ', 431, 432),
+        ('う', 432, 432),
+        ('う', 432, 432),
+        ('う', 432, 432),
+        ('ぇ', 432, 432),
+        ('ゃ', 432, 432),
+        ('ゅ', 432, 432),
+        ('ょ', 432, 433),
+        ('う', 433, 433),
+        ('う', 433, 433),
+        ('う', 433, 433),
+        ('ぇ', 433, 433),
+        ('ゃ', 433, 433),
+        ('ゅ', 433, 433),
+        ('ょ', 433, 434),
+        ('う', 434, 434),
+        ('う', 434, 434),
+        ('う', 434, 434),
+        ('う', 434, 434),
+        ('う', 434, 434),
+        ('う', 434, 434),
+        ('う', 434, 434),
+        ('う', 434, 434),
+        ('う', 434, 434),
+        ('う', 434, 434),
+        ('う', 434, 434),
+        ('あ', 434, 434),
+        ('い', 434, 434),
+        ('う', 434, 434),
+        ('え', 434, 434),
+        ('お', 434, 434),
+        ('や', 434, 434),
+        ('ゆ', 434, 434),
+        ('よ', 434, 435),
+        ('ゃ', 435, 435),
+        ('ゅ', 435, 435),
+        ('ょ', 435, 436),
+        ('ゃ', 436, 436),
+        ('ゅ', 436, 436),
+        ('ょ', 436, 437),
+        ('う', 437, 437),
+        ('ゃ', 437, 437),
+        ('ゅ', 437, 437),
+        ('ょ', 437, 438),
+        ('う', 438, 438),
+        ('う', 438, 438),
+        ('う', 438, 438),
+        ('う', 438, 438),
+        ('う', 438, 438),
+        ('う', 438, 438),
+        ('う', 438, 438),
+        ('う', 438, 438),
+        ('う', 438, 438),
+        ('う', 438, 438),
+        ('う', 438, 438),
+        ('う', 438, 438),
+        ('う', 438, 438),
+        ('う', 438, 438),
+        ('う', 438, 438),
+        ('う', 438, 438),
+        ('う', 438, 438),
+        ('う', 438, 438),
+        ('う', 438, 438),
+        ('う', 438, 438),
+    ],
+    outputs: &[
+        Some(" "),
+        Some(","),
+        Some("."),
+        Some("'"),
+        Some("’"),
+        Some("“"),
+        Some("”"),
+        Some("~"),
+        Some("а"),
+        Some("а"),
+        Some("і"),
+        Some("і"),
+        Some("у"),
+        Some("у"),
+        Some("е"),
+        Some("е"),
+        Some("о"),
+        Some("о"),
+        Some("ка"),
+        Some("ґа"),
+        Some("кі"),
+        Some("ґі"),
+        Some("ку"),
+        Some("ґу"),
+        Some("ке"),
+        Some("ґе"),
+        Some("ко"),
+        Some("ґо"),
+        Some("са"),
+        Some("дза"),
+        Some("ші"),
+        Some("джі"),
+        Some("су"),
+        Some("дзу"),
+        Some("се"),
+        Some("дзе"),
+        Some("со"),
+        Some("дзо"),
+        Some("та"),
+        Some("да"),
+        Some("чі"),
+        Some("джі"),
+        Some(""),
+        Some("цу"),
+        Some("дзу"),
+        Some("те"),
+        Some("де"),
+        Some("то"),
+        Some("до"),
+        Some("на"),
+        Some("ні"),
+        Some("ну"),
+        Some("не"),
+        Some("но"),
+        Some("ха"),
+        Some("ба"),
+        Some("па"),
+        Some("хі"),
+        Some("бі"),
+        Some("пі"),
+        Some("фу"),
+        Some("бу"),
+        Some("пу"),
+        Some("хе"),
+        Some("бе"),
+        Some("пе"),
+        Some("хо"),
+        Some("бо"),
+        Some("по"),
+        Some("ма"),
+        Some("мі"),
+        Some("му"),
+        Some("ме"),
+        Some("мо"),
+        Some("я"),
+        Some("я"),
+        Some("ю"),
+        Some("ю"),
+        Some("йо"),
+        Some("йо"),
+        Some("ра"),
+        Some("рі"),
+        Some("ру"),
+        Some("ре"),
+        Some("ро"),
+        Some("ва"),
+        Some("ва"),
+        Some("ві"),
+        Some("ве"),
+        Some("во"),
+        Some("н"),
+        Some("во"),
+        Some("/"),
+        Some("-"),
+        Some("!"),
+        Some("("),
+        Some(")"),
+        Some(":"),
+        Some("?"),
+        Some("["),
+        Some("]"),
+        Some("{"),
+        Some("}"),
+        Some("ії"),
+        Some("i'є"),
+        Some("ії"),
+        Some("іє"),
+        Some("ей"),
+        Some("о"),
+        Some("о"),
+        Some("ка"),
+        Some("к'є"),
+        Some("кя"),
+        Some("кю"),
+        Some("кьо"),
+        Some("ґ'є"),
+        Some("ґя"),
+        Some("ґю"),
+        Some("ґьо"),
+        Some("к'є"),
+        Some("кя"),
+        Some("кю"),
+        Some("кьо"),
+        Some("ґу"),
+        Some("ко"),
+        Some("ґо"),
+        Some("ш'є"),
+        Some("шя"),
+        Some("шю"),
+        Some("шьо"),
+        Some("дж'є"),
+        Some("джя"),
+        Some("джю"),
+        Some("джьо"),
+        Some("со"),
+        Some("дзо"),
+        Some("ч'є"),
+        Some("чя"),
+        Some("чю"),
+        Some("чьо"),
+        Some("дж'є"),
+        Some("джя"),
+        Some("джю"),
+        Some("джьо"),
+        Some(" "),
+        Some(","),
+        Some("."),
+        Some("‘"),
+        Some("’"),
+        Some("“"),
+        Some("”"),
+        Some("~"),
+        Some("а"),
+        Some("а"),
+        Some("і"),
+        Some("і"),
+        Some("у"),
+        Some("у"),
+        Some("е"),
+        Some("е"),
+        Some("о"),
+        Some("о"),
+        Some("кка"),
+        Some("ґґа"),
+        Some("ккі"),
+        Some("ґґі"),
+        Some("кку"),
+        Some("ґґу"),
+        Some("кке"),
+        Some("ґґе"),
+        Some("кко"),
+        Some("ґґо"),
+        Some("сса"),
+        Some("ддза"),
+        Some("шші"),
+        Some("дджі"),
+        Some("ссу"),
+        Some("ддзу"),
+        Some("ссе"),
+        Some("ддзе"),
+        Some("ссо"),
+        Some("ддзо"),
+        Some("тта"),
+        Some("дда"),
+        Some("ччі"),
+        Some("дджі"),
+        Some("ццу"),
+        Some("ддзу"),
+        Some("тте"),
+        Some("дде"),
+        Some("тто"),
+        Some("ддо"),
+        Some("на"),
+        Some("ні"),
+        Some("ну"),
+        Some("не"),
+        Some("но"),
+        Some("хха"),
+        Some("бба"),
+        Some("ппа"),
+        Some("ххі"),
+        Some("ббі"),
+        Some("ппі"),
+        Some("ффу"),
+        Some("ббу"),
+        Some("ппу"),
+        Some("ххе"),
+        Some("ббе"),
+        Some("ппе"),
+        Some("ххо"),
+        Some("ббо"),
+        Some("ппо"),
+        Some("мма"),
+        Some("ммі"),
+        Some("мму"),
+        Some("мме"),
+        Some("ммо"),
+        Some("я"),
+        Some("я"),
+        Some("ю"),
+        Some("ю"),
+        Some("ьо"),
+        Some("ьо"),
+        Some("рра"),
+        Some("ррі"),
+        Some("рру"),
+        Some("рре"),
+        Some("рро"),
+        Some("вва"),
+        Some("вві"),
+        Some("вве"),
+        Some("вво"),
+        Some("н"),
+        Some("вво"),
+        Some("/"),
+        Some("-"),
+        Some("!"),
+        Some("("),
+        Some(")"),
+        Some(":"),
+        Some("?"),
+        Some("["),
+        Some("]"),
+        Some("{"),
+        Some("}"),
+        Some("ді"),
+        Some("то"),
+        Some("до"),
+        Some("н'є"),
+        Some("ня"),
+        Some("ню"),
+        Some("ньо"),
+        Some("не"),
+        Some("ба"),
+        Some("х'є"),
+        Some("хя"),
+        Some("хю"),
+        Some("хьо"),
+        Some("б'є"),
+        Some("бя"),
+        Some("бю"),
+        Some("бьо"),
+        Some("п'є"),
+        Some("пя"),
+        Some("пю"),
+        Some("пьо"),
+        Some("ф'є"),
+        Some("фя"),
+        Some("фю"),
+        Some("фьо"),
+        Some("хо"),
+        Some("бо"),
+        Some("по"),
+        Some("м'є"),
+        Some("мя"),
+        Some("мю"),
+        Some("мьо"),
+        Some("мо"),
+        Some("ю"),
+        Some("йо"),
+        Some("р'є"),
+        Some("ря"),
+        Some("рю"),
+        Some("рьо"),
+        Some("ро"),
+        Some("во"),
+        Some("н'"),
+        Some("n'a"),
+        Some("н'ї"),
+        Some("н'у"),
+        Some("н'є"),
+        Some("н'о"),
+        Some("мба"),
+        Some("мпа"),
+        Some("мбі"),
+        Some("мпі"),
+        Some("мбу"),
+        Some("мпу"),
+        Some("мбе"),
+        Some("мпе"),
+        Some("мбо"),
+        Some("мпо"),
+        Some("мма"),
+        Some("ммі"),
+        Some("мму"),
+        Some("мме"),
+        Some("ммо"),
+        Some("н'я"),
+        Some("н'ю"),
+        Some("нйо"),
+        Some("во"),
+        Some("ієї"),
+        Some("кьо"),
+        Some("ґьо"),
+        Some("кьо"),
+        Some("шю"),
+        Some("шьо"),
+        Some("джьо"),
+        Some("чьо"),
+        Some("джьо"),
+        Some("кк'є"),
+        Some("ккя"),
+        Some("ккю"),
+        Some("ккьо"),
+        Some("ґґ'є"),
+        Some("ґґя"),
+        Some("ґґю"),
+        Some("ґґьо"),
+        Some("кк'є"),
+        Some("ккя"),
+        Some("ккю"),
+        Some("ккьо"),
+        Some("кко"),
+        Some("ґґо"),
+        Some("шш'є"),
+        Some("шшя"),
+        Some("шшю"),
+        Some("шшьо"),
+        Some("ддж'є"),
+        Some("дджя"),
+        Some("дджю"),
+        Some("дджьо"),
+        Some("ссо"),
+        Some("ддзо"),
+        Some("чч'є"),
+        Some("ччя"),
+        Some("ччю"),
+        Some("ччьо"),
+        Some("ддж'є"),
+        Some("дджя"),
+        Some("дджю"),
+        Some("дджьо"),
+        Some("тто"),
+        Some("ддо"),
+        Some("н'є"),
+        Some("ня"),
+        Some("ню"),
+        Some("нью"),
+        Some("но"),
+        Some("хх'є"),
+        Some("ххя"),
+        Some("ххю"),
+        Some("ххьо"),
+        Some("бб'є"),
+        Some("ббя"),
+        Some("ббю"),
+        Some("ббьо"),
+        Some("пп'є"),
+        Some("ппя"),
+        Some("ппю"),
+        Some("ппьо"),
+        Some("фф'є"),
+        Some("ффя"),
+        Some("ффю"),
+        Some("ффьо"),
+        Some("ххо"),
+        Some("ббо"),
+        Some("ппо"),
+        Some("мм'є"),
+        Some("ммя"),
+        Some("ммю"),
+        Some("ммьо"),
+        Some("ммо"),
+        Some("ьо"),
+        Some("ьо"),
+        Some("рр'є"),
+        Some("рря"),
+        Some("ррю"),
+        Some("ррьо"),
+        Some("рро"),
+        Some("вво"),
+        Some("вво"),
+        Some("ньо"),
+        Some("хьо"),
+        Some("бьо"),
+        Some("пьо"),
+        Some("фьо"),
+        Some("мьо"),
+        Some("рю"),
+        Some("рьо"),
+        Some("n'a"),
+        Some("н'ї"),
+        Some("н'у"),
+        Some("н'є"),
+        Some("н'о"),
+        Some("н'я"),
+        Some("н'ю"),
+        Some("нйо"),
+        Some("мбя"),
+        Some("мбю"),
+        Some("мбьо"),
+        Some("мпя"),
+        Some("мпю"),
+        Some("мпьо"),
+        Some("мпо"),
+        Some("ммя"),
+        Some("ммю"),
+        Some("ммьо"),
+        Some("ммо"),
+        Some("н'ю"),
+        Some("нйо"),
+        Some("ккьо"),
+        Some("ґґьо"),
+        Some("ккьо"),
+        Some("шшьо"),
+        Some("дджьо"),
+        Some("ччьо"),
+        Some("дджьо"),
+        Some("ххьо"),
+        Some("ббьо"),
+        Some("ппьо"),
+        Some("ффьо"),
+        Some("ммьо"),
+        Some("ррьо"),
+        Some("нйо"),
+        Some("мбьо"),
+        Some("мпьо"),
+        Some("ммьо"),
+    ],
+};
@@ -0,0 +1,85 @@
+use alloc::string::String;
+
+use crate::constants::{IDEOGRAPHIC_SPACE, ZENKAKU_ASCII_OFFSET};
+use crate::options::WidthOptions;
+use crate::utils::hankaku_katakana_to_zenkaku::zenkaku_char_indices;
+
+/// Convert ASCII letters, digits, punctuation and half-width katakana to their full-width
+/// (zenkaku) forms
+pub fn to_fullwidth(input: &str) -> String {
+    to_fullwidth_with_opt(input, WidthOptions::default())
+}
+
+/// Convert the classes of chars set in `options` to their full-width (zenkaku) forms
+pub fn to_fullwidth_with_opt(input: &str, options: WidthOptions) -> String {
+    let mut ouput = String::with_capacity(input.len());
+    to_fullwidth_into(input, options, &mut ouput);
+    ouput
+}
+
+/// Convert the classes of chars set in `options` to their full-width (zenkaku) forms,
+/// appending to `ouput`
+pub fn to_fullwidth_into(input: &str, options: WidthOptions, ouput: &mut String) {
+    let widen = |char: char| match char {
+        ' ' if options.converts_ascii(char) => core::char::from_u32(IDEOGRAPHIC_SPACE).unwrap(),
+        '!'..='~' if options.converts_ascii(char) => {
+            core::char::from_u32(char as u32 + ZENKAKU_ASCII_OFFSET).unwrap()
+        }
+        _ => char,
+    };
+    if options.katakana {
+        ouput.extend(zenkaku_char_indices(input).map(|(_, char)| widen(char)));
+    } else {
+        ouput.extend(input.chars().map(widen));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sane_defaults() {
+        assert_eq!(to_fullwidth(""), "");
+    }
+
+    #[test]
+    fn converts_every_class() {
+        assert_eq!(to_fullwidth("ABC xyz 0129"), "ＡＢＣ　ｘｙｚ　０１２９");
+        assert_eq!(to_fullwidth("!\"#~{|}"), "！＂＃～｛｜｝");
+        assert_eq!(to_fullwidth("｢ｶﾞｲｺｸｺﾞ｣ ｺｰﾋｰ｡"), "「ガイコクゴ」　コーヒー。");
+    }
+
+    #[test]
+    fn passes_other_chars_through() {
+        assert_eq!(
+            to_fullwidth("ＡＢＣ かな 漢字 ō\n"),
+            "ＡＢＣ　かな　漢字　ō\n"
+        );
+    }
+
+    #[test]
+    fn converts_only_the_classes_set() {
+        let kana_only = WidthOptions {
+            letters: false,
+            digits: false,
+            punctuation: false,
+            katakana: true,
+        };
+        assert_eq!(
+            to_fullwidth_with_opt("ｶﾅ ABC-123", kana_only),
+            "カナ ABC-123"
+        );
+        let ascii_only = WidthOptions {
+            katakana: false,
+            ..Default::default()
+        };
+        assert_eq!(to_fullwidth_with_opt("ｶﾅ AB-1", ascii_only), "ｶﾅ　ＡＢ－１");
+        let letters = WidthOptions {
+            digits: false,
+            punctuation: false,
+            ..Default::default()
+        };
+        assert_eq!(to_fullwidth_with_opt("AB-1 2", letters), "ＡＢ-1 2");
+    }
+}
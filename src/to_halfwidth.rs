@@ -0,0 +1,134 @@
+use alloc::string::String;
+
+use crate::constants::{IDEOGRAPHIC_SPACE, ZENKAKU_ASCII, ZENKAKU_ASCII_OFFSET};
+use crate::options::WidthOptions;
+use crate::utils::hankaku_katakana_to_zenkaku::composed_char_indices;
+use crate::utils::zenkaku_katakana_to_hankaku::zenkaku_to_hankaku;
+
+/// The classes of full-width chars read as romaji with the `fullwidth_romaji` option
+const ROMAJI_WIDTH: WidthOptions = WidthOptions {
+    letters: true,
+    digits: false,
+    punctuation: true,
+    katakana: false,
+};
+
+/// Convert full-width (zenkaku) letters, digits, punctuation and katakana to ASCII and
+/// half-width katakana
+pub fn to_halfwidth(input: &str) -> String {
+    to_halfwidth_with_opt(input, WidthOptions::default())
+}
+
+/// Convert the classes of chars set in `options` to their half-width (hankaku) forms
+pub fn to_halfwidth_with_opt(input: &str, options: WidthOptions) -> String {
+    let mut ouput = String::with_capacity(input.len());
+    to_halfwidth_into(input, options, &mut ouput);
+    ouput
+}
+
+/// Convert the classes of chars set in `options` to their half-width (hankaku) forms,
+/// appending to `ouput`
+pub fn to_halfwidth_into(input: &str, options: WidthOptions, ouput: &mut String) {
+    if options.katakana {
+        // A kana and its combining voiced sound mark become one half-width kana and ﾞ
+        for (_, char) in composed_char_indices(input) {
+            match zenkaku_to_hankaku(char) {
+                Some((kana, mark)) => {
+                    ouput.push(kana);
+                    ouput.extend(mark);
+                }
+                None => push_halfwidth_ascii(char, options, ouput),
+            }
+        }
+    } else {
+        for char in input.chars() {
+            push_halfwidth_ascii(char, options, ouput);
+        }
+    }
+}
+
+/// Appends the ASCII char of `char` if its class is set in `options`, otherwise `char` as it is
+fn push_halfwidth_ascii(char: char, options: WidthOptions, ouput: &mut String) {
+    match ascii_char(char) {
+        Some(ascii) if options.converts_ascii(ascii) => ouput.push(ascii),
+        _ => ouput.push(char),
+    }
+}
+
+/// Returns the ASCII char of a full-width ASCII char or of the ideographic space
+pub(crate) fn ascii_char(char: char) -> Option<char> {
+    let code = char as u32;
+    if code == IDEOGRAPHIC_SPACE {
+        Some(' ')
+    } else if (ZENKAKU_ASCII[0]..=ZENKAKU_ASCII[1]).contains(&code) {
+        core::char::from_u32(code - ZENKAKU_ASCII_OFFSET)
+    } else {
+        None
+    }
+}
+
+/// Returns the ASCII char of full-width letters and punctuation, or `char` as it is
+pub(crate) fn halfwidth_romaji_char(char: char) -> char {
+    ascii_char(char)
+        .filter(|&ascii| ROMAJI_WIDTH.converts_ascii(ascii))
+        .unwrap_or(char)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::to_fullwidth::to_fullwidth;
+
+    #[test]
+    fn sane_defaults() {
+        assert_eq!(to_halfwidth(""), "");
+    }
+
+    #[test]
+    fn converts_every_class() {
+        assert_eq!(to_halfwidth("ＡＢＣ　ｘｙｚ　０１２９"), "ABC xyz 0129");
+        assert_eq!(to_halfwidth("！＂＃～｛｜｝"), "!\"#~{|}");
+        assert_eq!(to_halfwidth("「ガイコクゴ」　コーヒー。"), "｢ｶﾞｲｺｸｺﾞ｣ ｺｰﾋｰ｡");
+        assert_eq!(to_halfwidth("ハ\u{309A}ン"), "ﾊﾟﾝ");
+    }
+
+    #[test]
+    fn passes_other_chars_through() {
+        assert_eq!(
+            to_halfwidth("abc かな 漢字 ヶ ｟ ￥"),
+            "abc かな 漢字 ヶ ｟ ￥"
+        );
+    }
+
+    #[test]
+    fn converts_only_the_classes_set() {
+        let kana_only = WidthOptions {
+            letters: false,
+            digits: false,
+            punctuation: false,
+            katakana: true,
+        };
+        assert_eq!(
+            to_halfwidth_with_opt("カナ　ＡＢＣ－１２３", kana_only),
+            "ｶﾅ　ＡＢＣ－１２３"
+        );
+        let ascii_only = WidthOptions {
+            katakana: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            to_halfwidth_with_opt("カナ　ＡＢ－１", ascii_only),
+            "カナ AB-1"
+        );
+        assert_eq!(
+            to_halfwidth_with_opt("カ\u{3099}ｎ\u{309A}", ascii_only),
+            "カ\u{3099}n\u{309A}"
+        );
+    }
+
+    #[test]
+    fn round_trips_ascii() {
+        let ascii = (' '..='~').collect::<String>();
+        assert_eq!(to_halfwidth(&to_fullwidth(&ascii)), ascii);
+    }
+}
@@ -1,9 +1,16 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+
 use crate::is_mixed::*;
 use crate::is_romaji::*;
 use crate::options::Options;
+use crate::utils::hankaku_katakana_to_zenkaku::*;
 use crate::utils::is_char_english_punctuation::is_char_english_punctuation;
+use crate::utils::is_char_katakana::is_char_katakana;
+use crate::utils::is_char_romaji::is_char_romaji;
 use crate::utils::katakana_to_hiragana::*;
 use crate::utils::romaji_to_hiragana::romaji_to_hiragana;
+use crate::utils::voiced_sound_marks::is_char_voiced_sound_mark;
 
 #[inline]
 /// Convert input to [Hiragana](https://en.wikipedia.org/wiki/Hiragana)
@@ -13,12 +20,20 @@ pub fn to_hiragana(input: &str) -> String {
 
 /// Convert input to [Hiragana](https://en.wikipedia.org/wiki/Hiragana)
 pub fn to_hiragana_with_opt(input: &str, options: Options) -> String {
+    let mut ouput = String::with_capacity(input.len());
+    to_hiragana_into(input, options, &mut ouput);
+    ouput
+}
+
+/// Convert input to [Hiragana](https://en.wikipedia.org/wiki/Hiragana), appending to `ouput`
+pub fn to_hiragana_into(input: &str, options: Options, ouput: &mut String) {
+    let input = &*zenkaku_katakana_cow(input);
     let config = options;
     if config.pass_romaji {
-        katakana_to_hiragana(input)
+        katakana_to_hiragana_into(input, false, ouput);
     } else if is_mixed(input) {
         let romaji = katakana_to_hiragana(input);
-        romaji_to_hiragana(&romaji, config)
+        ouput.push_str(&romaji_to_hiragana(&romaji, config));
     } else if is_romaji(input)
         || input
             .chars()
@@ -28,15 +43,38 @@ pub fn to_hiragana_with_opt(input: &str, options: Options) -> String {
     {
         // TODO: is it correct to check only the first char (see
         // src\utils\isCharEnglishPunctuation.js)
-        romaji_to_hiragana(input, config)
+        ouput.push_str(&romaji_to_hiragana(input, config));
     } else {
-        katakana_to_hiragana(input)
+        katakana_to_hiragana_into(input, false, ouput);
+    }
+}
+
+/// Convert input to [Hiragana](https://en.wikipedia.org/wiki/Hiragana), borrowing `input` if
+/// nothing changes
+pub fn to_hiragana_cow(input: &str, options: Options) -> Cow<'_, str> {
+    let is_unchanged = !input.chars().any(|char| {
+        is_char_katakana(char)
+            || is_char_romaji(char)
+            || is_char_english_punctuation(char)
+            || hankaku_to_zenkaku(char).is_some()
+            || is_char_voiced_sound_mark(char)
+    });
+    if is_unchanged {
+        return Cow::Borrowed(input);
+    }
+    let hiragana = to_hiragana_with_opt(input, options);
+    if hiragana == input {
+        Cow::Borrowed(input)
+    } else {
+        Cow::Owned(hiragana)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::options::ImeMode;
+    use crate::utils::custom_mapping::mapping;
 
     fn with_obsolete_kana() -> Options {
         Options {
@@ -160,4 +198,59 @@ mod tests {
             "#22 ２２漢字、とうきょう、 おおさか"
         );
     }
+
+    #[test]
+    fn custom_kana_mapping() {
+        let options = Options {
+            custom_kana_mapping: mapping(&[("na", "に"), ("ka", "ば")]),
+            ..Default::default()
+        };
+        assert_eq!(to_hiragana_with_opt("wanakana", options), "わにばに");
+    }
+
+    #[test]
+    fn ime_mode() {
+        for imemode in [ImeMode::On, ImeMode::ToHiragana, ImeMode::ToKatakana] {
+            let options = Options {
+                imemode,
+                ..Default::default()
+            };
+            assert_eq!(to_hiragana_with_opt("shin", options), "しn");
+        }
+    }
+
+    #[test]
+    fn into_appends() {
+        let mut hiragana = String::from("あ");
+        to_hiragana_into("カナ", Options::default(), &mut hiragana);
+        to_hiragana_into(" kana", Options::default(), &mut hiragana);
+        assert_eq!(hiragana, "あかな かな");
+    }
+
+    #[test]
+    fn cow_borrows_unchanged_input() {
+        assert!(matches!(
+            to_hiragana_cow("ひらがな、漢字", Options::default()),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(
+            to_hiragana_cow("げーむ", Options::default()),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(
+            to_hiragana_cow("ゲーム", Options::default()),
+            Cow::Owned(hiragana) if hiragana == "げえむ"
+        ));
+        assert!(matches!(
+            to_hiragana_cow("ｹﾞｰﾑ", Options::default()),
+            Cow::Owned(hiragana) if hiragana == "げえむ"
+        ));
+    }
+
+    #[test]
+    fn converts_hankaku_katakana() {
+        assert_eq!(to_hiragana("ｶﾞｲｺｸｺﾞ"), "がいこくご");
+        assert_eq!(to_hiragana("ｽｰﾊﾟｰ"), "すうぱあ");
+        assert_eq!(to_hiragana("｢ｺﾝﾋﾞﾆ｣ kara"), "「こんびに」 から");
+    }
 }
@@ -1,8 +1,22 @@
-use crate::options::Options;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::conversion_error::{check_spans, ConversionError, ErrorKind};
+use crate::options::{ImeMode, LongVowelMark, Options};
+use crate::to_halfwidth::halfwidth_romaji_char;
 use crate::to_kana_node_tree::{
-    TO_KANA_NODE_TREE, TO_KANA_NODE_TREE_IMEMODE, TO_KANA_NODE_TREE_OBSOLETE,
+    Node, TO_KANA_NODE_TREE, TO_KANA_NODE_TREE_IMEMODE, TO_KANA_NODE_TREE_OBSOLETE,
 };
+use crate::to_romaji_node_tree::TO_ROMAJI_NODE_TREE;
+use crate::traits::Span;
+use crate::utils::custom_mapping::find_custom_mapping;
 use crate::utils::hiragana_to_katakana::*;
+use crate::utils::is_char_hiragana::is_char_hiragana;
+use crate::utils::is_char_kana::is_char_kana;
+use crate::utils::katakana_to_hiragana::long_vowel_kana;
+use crate::utils::long_vowel_to_vowel::long_vowel_to_vowel;
+use crate::utils::utf8_len::utf8_len;
 
 #[inline]
 /// Convert [Romaji](https://en.wikipedia.org/wiki/Romaji) to [Kana](https://en.wikipedia.org/wiki/Kana), lowercase text will result in [Hiragana](https://en.wikipedia.org/wiki/Hiragana) and uppercase text will result in [Katakana](https://en.wikipedia.org/wiki/Katakana).
@@ -12,40 +26,205 @@ pub fn to_kana(input: &str) -> String {
 
 /// Convert [Romaji](https://en.wikipedia.org/wiki/Romaji) to [Kana](https://en.wikipedia.org/wiki/Kana), lowercase text will result in [Hiragana](https://en.wikipedia.org/wiki/Hiragana) and uppercase text will result in [Katakana](https://en.wikipedia.org/wiki/Katakana).
 pub fn to_kana_with_opt(input: &str, options: Options) -> String {
-    let chars = input.chars().collect::<Vec<_>>();
     // Final output array
     let mut ouput = String::with_capacity(input.len());
+    to_kana_into(input, options, &mut ouput);
+    ouput
+}
+
+/// Convert [Romaji](https://en.wikipedia.org/wiki/Romaji) to [Kana](https://en.wikipedia.org/wiki/Kana), appending the kana to `ouput`.
+pub fn to_kana_into(input: &str, options: Options, ouput: &mut String) {
+    if passes_through(input, &options) {
+        ouput.push_str(input);
+        return;
+    }
+    let chars = input.chars().collect::<Vec<_>>();
+    push_kana(&chars, &options, ouput, None, None);
+}
+
+/// Convert [Romaji](https://en.wikipedia.org/wiki/Romaji) to [Kana](https://en.wikipedia.org/wiki/Kana), borrowing `input` if nothing changes.
+pub fn to_kana_cow(input: &str, options: Options) -> Cow<'_, str> {
+    if passes_through(input, &options) {
+        return Cow::Borrowed(input);
+    }
+    let kana = to_kana_with_opt(input, options);
+    if kana == input {
+        Cow::Borrowed(input)
+    } else {
+        Cow::Owned(kana)
+    }
+}
+
+/// Returns true if no char of `input` starts a romaji syllable, so that the conversion returns
+/// `input` as it is.
+fn passes_through(input: &str, options: &Options) -> bool {
+    let tree = kana_tree(options);
+    options.custom_kana_mapping.is_empty() && input.chars().all(|char| tree.walk(&[char]).1 == 0)
+}
+
+/// Convert [Romaji](https://en.wikipedia.org/wiki/Romaji) to [Kana](https://en.wikipedia.org/wiki/Kana), and return which byte range of `input` produced which byte range of the output.
+pub fn to_kana_with_spans(input: &str, options: Options) -> (String, Vec<Span>) {
+    let chars = input.chars().collect::<Vec<_>>();
+    let mut ouput = String::with_capacity(input.len());
+    let mut spans = Vec::new();
+    push_kana(&chars, &options, &mut ouput, None, Some(&mut spans));
+    (ouput, spans)
+}
+
+/// Convert [Romaji](https://en.wikipedia.org/wiki/Romaji) to [Kana](https://en.wikipedia.org/wiki/Kana), failing if letters are left which are not romaji syllables (like "kq") or are not kana (like kanji).
+pub fn try_to_kana(input: &str, options: Options) -> Result<String, ConversionError> {
+    let fullwidth_romaji = options.fullwidth_romaji;
+    let (kana, spans) = to_kana_with_spans(input, options);
+    check_spans(input, kana, &spans, |char| {
+        let char = if fullwidth_romaji {
+            halfwidth_romaji_char(char)
+        } else {
+            char
+        };
+        if char.is_ascii_alphabetic() || long_vowel_to_vowel(char).is_some() {
+            Some(ErrorKind::InvalidRomaji)
+        } else if char.is_alphabetic() && !is_char_kana(char) {
+            Some(ErrorKind::UnsupportedChar)
+        } else {
+            None
+        }
+    })
+}
+
+/// Converts romaji `chars` and appends the kana to `ouput`. `previous` is the kana converted
+/// before `chars`, if there is any. The segments of `chars` and `ouput` which correspond are added
+/// to `spans`, with byte offsets from the start of `chars`.
+pub(crate) fn push_kana(
+    chars: &[char],
+    options: &Options,
+    ouput: &mut String,
+    mut previous: Option<char>,
+    mut spans: Option<&mut Vec<Span>>,
+) {
     let len = chars.len();
     // Position in the string that is being evaluated
     let mut curr_pos = 0;
+    let mut input_start = 0;
+    let tree = kana_tree(options);
 
     while curr_pos != len {
-        let result = if options.use_obsolete_kana {
-            TO_KANA_NODE_TREE_OBSOLETE.get(&chars[curr_pos..])
-        } else if options.imemode {
-            TO_KANA_NODE_TREE_IMEMODE.get(&chars[curr_pos..])
-        } else {
-            TO_KANA_NODE_TREE.get(&chars[curr_pos..])
-        };
+        let segment_start = curr_pos;
+        let output_start = ouput.len();
+        curr_pos += push_next_kana(&tree, options, &chars[curr_pos..], previous, ouput);
+        previous = ouput[output_start..].chars().last().or(previous);
 
-        // nothing found, pass through
-        if result.1 == 0 {
-            ouput.push(chars[curr_pos]);
-            curr_pos += 1;
+        if let Some(spans) = spans.as_deref_mut() {
+            let input_end = input_start + utf8_len(&chars[segment_start..curr_pos]);
+            spans.push((input_start..input_end, output_start..ouput.len()));
+            input_start = input_end;
+        }
+    }
+}
+
+/// Converts the longest romaji at the start of `chars` and appends the kana to `ouput`, or passes
+/// the first char through if nothing matches. `previous` is the last char of the output so far.
+/// Returns the number of chars converted.
+pub(crate) fn push_next_kana(
+    tree: &Node,
+    options: &Options,
+    chars: &[char],
+    previous: Option<char>,
+    ouput: &mut String,
+) -> usize {
+    let result = next_match(tree, options, chars);
+
+    // nothing found, pass through
+    if result.1 == 0 {
+        ouput.push(chars[0]);
+        return 1;
+    }
+    let is_katakana = match options.imemode {
+        ImeMode::ToHiragana => false,
+        ImeMode::ToKatakana => true,
+        ImeMode::Off | ImeMode::On => chars[..result.1]
+            .iter()
+            .filter(|c| c.is_alphabetic())
+            .all(|c| char::is_uppercase(*c)),
+    };
+    let long_vowel_mark = match (result.0, previous) {
+        (Some("ー"), Some(previous)) if is_char_hiragana(previous) => {
+            hiragana_long_vowel_mark(previous, options)
+        }
+        _ => None,
+    };
+    if let Some(mark) = long_vowel_mark {
+        ouput.push(mark);
+    } else if is_katakana {
+        ouput.push_str(&hiragana_to_katakana(result.0.unwrap()));
+    } else {
+        ouput.push_str(result.0.unwrap());
+    }
+    // Lengthen vowels with macron or circumflex (kyō => きょう)
+    if let Some(vowel) = long_vowel_to_vowel(chars[result.1 - 1]) {
+        if is_katakana {
+            ouput.push('ー');
         } else {
-            if chars[curr_pos..curr_pos + result.1]
-                .iter()
-                .all(|c| char::is_uppercase(*c))
-            {
-                ouput.push_str(&hiragana_to_katakana(result.0.unwrap()));
-            } else {
-                ouput.push_str(result.0.unwrap());
-            }
-            curr_pos += result.1;
+            ouput.extend(long_vowel_kana(vowel, options.long_o_kana));
         }
     }
+    result.1
+}
 
-    ouput
+/// Returns the kana of the longest romaji at the start of `chars` and its number of chars, or
+/// `(None, 0)` if nothing matches.
+pub(crate) fn next_match<'a>(
+    tree: &Node,
+    options: &'a Options,
+    chars: &[char],
+) -> (Option<&'a str>, usize) {
+    let mut result: (Option<&str>, usize) = tree.get(chars);
+    let mapping = &options.custom_kana_mapping;
+    let halfwidth;
+    let chars = if options.fullwidth_romaji && !mapping.is_empty() {
+        let longest_key = mapping.keys().map(|key| key.chars().count()).max();
+        halfwidth = chars
+            .iter()
+            .take(longest_key.unwrap_or(0))
+            .map(|&char| halfwidth_romaji_char(char))
+            .collect::<Vec<_>>();
+        &halfwidth
+    } else {
+        chars
+    };
+    // Custom rules win over built-in ones of the same length
+    if let Some((kana, custom_len)) = find_custom_mapping(mapping, chars) {
+        if custom_len >= result.1 {
+            result = (Some(kana), custom_len);
+        }
+    }
+    result
+}
+
+/// Returns the tree used to look up romaji with `options`
+pub(crate) fn kana_tree(options: &Options) -> Node {
+    let tree = if options.use_obsolete_kana {
+        TO_KANA_NODE_TREE_OBSOLETE
+    } else if options.imemode != ImeMode::Off {
+        TO_KANA_NODE_TREE_IMEMODE
+    } else {
+        TO_KANA_NODE_TREE
+    };
+    tree.with_fullwidth_romaji(options.fullwidth_romaji)
+}
+
+/// Returns what replaces 'ー' after the hiragana `previous`, or `None` to keep it
+fn hiragana_long_vowel_mark(previous: char, options: &Options) -> Option<char> {
+    match options.long_vowel_mark {
+        LongVowelMark::LongDash => None,
+        LongVowelMark::Hyphen => Some('-'),
+        LongVowelMark::Vowel => {
+            let kana = TO_ROMAJI_NODE_TREE
+                .find_transition_node(previous)
+                .and_then(|node| node.output().chars().last())
+                .and_then(|vowel| long_vowel_kana(vowel, options.long_o_kana));
+            Some(kana.unwrap_or('-'))
+        }
+    }
 }
 
 #[cfg(test)]
@@ -59,7 +238,7 @@ mod tests {
 
     fn with_ime_mode() -> Options {
         Options {
-            imemode: true,
+            imemode: ImeMode::On,
             ..Default::default()
         }
     }
@@ -76,6 +255,8 @@ mod tests {
     ];
 
     use super::*;
+    use crate::options::LongOKana;
+    use crate::utils::custom_mapping::mapping;
 
     #[test]
     fn sane_defaults() {
@@ -112,6 +293,12 @@ mod tests {
         assert_eq!(to_kana("WaniKani"), "わにかに");
     }
 
+    #[test]
+    fn apostrophes_dont_change_the_case_of_a_mora() {
+        assert_eq!(to_kana("SHIN'YOU"), "シンヨウ");
+        assert_eq!(to_kana("shin'you"), "しんよう");
+    }
+
     #[test]
     fn non_romaji_will_be_passed_through() {
         assert_eq!(
@@ -145,6 +332,36 @@ mod tests {
         assert_eq!(to_kana(&en), ja)
     }
 
+    mod long_vowels {
+        use super::*;
+        #[test]
+        fn macrons_are_lengthened_with_kana() {
+            assert_eq!(to_kana("tōkyō"), "とうきょう");
+            assert_eq!(to_kana("kūkō"), "くうこう");
+            assert_eq!(to_kana("okāsan onēsan"), "おかあさん おねえさん");
+        }
+        #[test]
+        fn circumflexes_are_lengthened_with_kana() {
+            assert_eq!(to_kana("tôkyô"), "とうきょう");
+            assert_eq!(to_kana("sûgaku"), "すうがく");
+        }
+        #[test]
+        fn katakana_are_lengthened_with_long_dash() {
+            assert_eq!(to_kana("SŪPĀ"), "スーパー");
+            assert_eq!(to_kana("KÔHÎ"), "コーヒー");
+            assert_eq!(to_kana("Tōkyō"), "とうきょう");
+        }
+        #[test]
+        fn long_o_kana_option() {
+            let options = Options {
+                long_o_kana: LongOKana::Oo,
+                ..Default::default()
+            };
+            assert_eq!(to_kana_with_opt("ōsaka", options.clone()), "おおさか");
+            assert_eq!(to_kana_with_opt("ŌSAKA", options), "オーサカ");
+        }
+    }
+
     mod without_ime_mode {
         use super::*;
         #[test]
@@ -172,6 +389,30 @@ mod tests {
         fn double_ns_are_transliterated_to_singleん() {
             assert_eq!(to_kana_with_opt("nn", with_ime_mode()), "ん");
         }
+        #[test]
+        fn case_picks_the_script() {
+            assert_eq!(to_kana_with_opt("wani KANI", with_ime_mode()), "わに カニ");
+        }
+        #[test]
+        fn to_hiragana_forces_hiragana() {
+            let options = Options {
+                imemode: ImeMode::ToHiragana,
+                ..Default::default()
+            };
+            assert_eq!(to_kana_with_opt("wani KANI", options.clone()), "わに かに");
+            assert_eq!(to_kana_with_opt("SHIN'YOU", options.clone()), "しんよう");
+            assert_eq!(to_kana_with_opt("KŌHĪ", options), "こうひい");
+        }
+        #[test]
+        fn to_katakana_forces_katakana() {
+            let options = Options {
+                imemode: ImeMode::ToKatakana,
+                ..Default::default()
+            };
+            assert_eq!(to_kana_with_opt("wani KANI", options.clone()), "ワニ カニ");
+            assert_eq!(to_kana_with_opt("shinnbunn", options.clone()), "シンブン");
+            assert_eq!(to_kana_with_opt("kōhī", options), "コーヒー");
+        }
     }
 
     mod use_obsolete_kana {
@@ -198,4 +439,255 @@ mod tests {
             assert_eq!(to_kana_with_opt("WE", with_obsolete_kana()), "ヱ");
         }
     }
+
+    mod custom_kana_mapping {
+        use super::*;
+
+        fn with_mapping(pairs: &[(&str, &str)]) -> Options {
+            Options {
+                custom_kana_mapping: mapping(pairs),
+                ..Default::default()
+            }
+        }
+        #[test]
+        fn applies_custom_mapping() {
+            let options = with_mapping(&[("na", "に"), ("ka", "bana")]);
+            assert_eq!(to_kana_with_opt("wanakana", options), "わにbanaに");
+        }
+        #[test]
+        fn adds_new_rules() {
+            let options = with_mapping(&[("tsi", "つぃ"), ("va", "ゔぁ")]);
+            assert_eq!(to_kana_with_opt("tsiva", options.clone()), "つぃゔぁ");
+            assert_eq!(to_kana_with_opt("TSIVA", options), "ツィヴァ");
+        }
+        #[test]
+        fn longest_match_wins() {
+            let options = with_mapping(&[("k", "く")]);
+            assert_eq!(to_kana_with_opt("kak", options), "かく");
+        }
+    }
+
+    #[test]
+    fn with_spans() {
+        let input = "tōkyō KŌHĪ!";
+        let (kana, spans) = to_kana_with_spans(input, Options::default());
+        assert_eq!(kana, "とうきょう コーヒー！");
+        let segments = spans
+            .iter()
+            .map(|(i, o)| (&input[i.clone()], &kana[o.clone()]))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            segments,
+            vec![
+                ("tō", "とう"),
+                ("kyō", "きょう"),
+                (" ", " "),
+                ("KŌ", "コー"),
+                ("HĪ", "ヒー"),
+                ("!", "！")
+            ]
+        );
+    }
+
+    mod try_to_kana {
+        use super::*;
+        use crate::conversion_error::InvalidSpan;
+
+        #[test]
+        fn converts_valid_romaji() {
+            assert_eq!(
+                try_to_kana("onaji BUTTSUUJI, 12!", Options::default()),
+                Ok("おなじ ブッツウジ、 12！".to_string())
+            );
+            assert_eq!(try_to_kana("", Options::default()), Ok(String::new()));
+            assert_eq!(
+                try_to_kana("かな", Options::default()),
+                Ok("かな".to_string())
+            );
+        }
+        #[test]
+        fn reports_invalid_romaji() {
+            let error = try_to_kana("kq shy", Options::default()).unwrap_err();
+            assert_eq!(
+                error.invalid,
+                vec![
+                    InvalidSpan {
+                        range: 0..2,
+                        text: "kq".to_string(),
+                        kind: ErrorKind::InvalidRomaji,
+                    },
+                    InvalidSpan {
+                        range: 3..6,
+                        text: "shy".to_string(),
+                        kind: ErrorKind::InvalidRomaji,
+                    },
+                ]
+            );
+        }
+        #[test]
+        fn reports_unsupported_chars() {
+            let error = try_to_kana("座禅 zazen", Options::default()).unwrap_err();
+            assert_eq!(
+                error.invalid,
+                vec![InvalidSpan {
+                    range: 0..6,
+                    text: "座禅".to_string(),
+                    kind: ErrorKind::UnsupportedChar,
+                }]
+            );
+        }
+    }
+
+    mod long_vowel_mark {
+        use super::*;
+
+        fn with_mark(long_vowel_mark: LongVowelMark) -> Options {
+            Options {
+                long_vowel_mark,
+                ..Default::default()
+            }
+        }
+        #[test]
+        fn long_dash_by_default() {
+            assert_eq!(to_kana("batsuge-mu"), "ばつげーむ");
+        }
+        #[test]
+        fn vowel() {
+            let options = with_mark(LongVowelMark::Vowel);
+            assert_eq!(
+                to_kana_with_opt("batsuge-mu", options.clone()),
+                "ばつげえむ"
+            );
+            assert_eq!(to_kana_with_opt("ko-hi-", options.clone()), "こうひい");
+            assert_eq!(to_kana_with_opt("kya--", options.clone()), "きゃああ");
+            assert_eq!(to_kana_with_opt("n-", options), "ん-");
+            let options = Options {
+                long_o_kana: LongOKana::Oo,
+                ..with_mark(LongVowelMark::Vowel)
+            };
+            assert_eq!(to_kana_with_opt("o-sama", options), "おおさま");
+        }
+        #[test]
+        fn hyphen() {
+            let options = with_mark(LongVowelMark::Hyphen);
+            assert_eq!(to_kana_with_opt("batsuge-mu", options.clone()), "ばつげ-む");
+            assert_eq!(to_kana_with_opt("-", options), "ー");
+        }
+        #[test]
+        fn katakana_keep_the_long_dash() {
+            let options = with_mark(LongVowelMark::Vowel);
+            assert_eq!(to_kana_with_opt("GE-MU", options.clone()), "ゲーム");
+            let options = Options {
+                imemode: ImeMode::ToKatakana,
+                ..options
+            };
+            assert_eq!(to_kana_with_opt("ge-mu", options), "ゲーム");
+        }
+        #[test]
+        fn to_hiragana_uses_the_option() {
+            let options = with_mark(LongVowelMark::Vowel);
+            assert_eq!(
+                crate::to_hiragana::to_hiragana_with_opt("su-pa-", options),
+                "すうぱあ"
+            );
+        }
+    }
+
+    #[test]
+    fn into_appends_without_context() {
+        let options = Options {
+            long_vowel_mark: LongVowelMark::Vowel,
+            ..Default::default()
+        };
+        let mut kana = String::from("か");
+        to_kana_into("-", options.clone(), &mut kana);
+        to_kana_into(" ka-", options, &mut kana);
+        assert_eq!(kana, "かー かあ");
+    }
+
+    #[test]
+    fn cow_borrows_unchanged_input() {
+        assert!(matches!(
+            to_kana_cow("かな 漢字", Options::default()),
+            Cow::Borrowed("かな 漢字")
+        ));
+        assert!(matches!(
+            to_kana_cow("ka", Options::default()),
+            Cow::Owned(kana) if kana == "か"
+        ));
+        assert!(matches!(
+            to_kana_cow("", Options::default()),
+            Cow::Borrowed("")
+        ));
+    }
+
+    mod fullwidth_romaji {
+        use super::*;
+
+        fn with_fullwidth_romaji() -> Options {
+            Options {
+                fullwidth_romaji: true,
+                ..Default::default()
+            }
+        }
+
+        #[test]
+        fn passes_zenkaku_letters_through_by_default() {
+            assert_eq!(to_kana("ｋａｎａ"), "ｋａｎａ");
+        }
+
+        #[test]
+        fn converts_zenkaku_letters_and_punctuation() {
+            let options = with_fullwidth_romaji();
+            assert_eq!(to_kana_with_opt("ｋａｎａ", options.clone()), "かな");
+            assert_eq!(
+                to_kana_with_opt("ｔｏｕｋｙｏｕ，ｏｏｓａｋａ！", options.clone()),
+                "とうきょう、おおさか！"
+            );
+            assert_eq!(
+                to_kana_with_opt("ｂａｔｓｕｇｅ－ｍｕ", options.clone()),
+                "ばつげーむ"
+            );
+            assert_eq!(to_kana_with_opt("ｋａｎ'ｙａ ｋa", options), "かんや か");
+        }
+
+        #[test]
+        fn uppercase_is_katakana() {
+            assert_eq!(
+                to_kana_with_opt("ｏｎａｊｉ　ＢＵＴＴＳＵＵＪＩ", with_fullwidth_romaji()),
+                "おなじ　ブッツウジ"
+            );
+            assert_eq!(
+                to_kana_with_opt("ＷａｎｉＫａｎｉ", with_fullwidth_romaji()),
+                "わにかに"
+            );
+        }
+
+        #[test]
+        fn passes_other_chars_through_as_they_are() {
+            assert_eq!(
+                to_kana_with_opt("ｋｑ＄１２３ 漢字", with_fullwidth_romaji()),
+                "ｋｑ＄１２３ 漢字"
+            );
+        }
+
+        #[test]
+        fn works_with_spans_and_custom_mapping() {
+            let (kana, spans) = to_kana_with_spans("ｋａｎａ", with_fullwidth_romaji());
+            assert_eq!(kana, "かな");
+            assert_eq!(spans, vec![(0..6, 0..3), (6..12, 3..6)]);
+            let options = Options {
+                custom_kana_mapping: mapping(&[("na", "に")]),
+                ..with_fullwidth_romaji()
+            };
+            assert_eq!(to_kana_with_opt("ｗａｎａ", options), "わに");
+        }
+
+        #[test]
+        fn reports_invalid_zenkaku_romaji() {
+            let error = try_to_kana("ｋａｋｑ", with_fullwidth_romaji()).unwrap_err();
+            assert_eq!(error.invalid[0].text, "ｋｑ");
+            assert_eq!(error.invalid[0].kind, ErrorKind::InvalidRomaji);
+        }
+    }
 }
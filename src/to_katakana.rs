@@ -1,8 +1,16 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+
 use crate::is_mixed::*;
 use crate::is_romaji::*;
 use crate::options::Options;
+use crate::utils::hankaku_katakana_to_zenkaku::*;
 use crate::utils::hiragana_to_katakana::*;
+use crate::utils::is_char_hiragana::is_char_hiragana;
+use crate::utils::is_char_romaji::is_char_romaji;
 use crate::utils::romaji_to_hiragana::*;
+use crate::utils::voiced_sound_marks::is_char_voiced_sound_mark;
+use crate::utils::zenkaku_katakana_to_hankaku::zenkaku_katakana_to_hankaku_into;
 
 /// Convert input to [Katakana](https://en.wikipedia.org/wiki/Katakana)
 pub fn to_katakana(input: &str) -> String {
@@ -10,20 +18,61 @@ pub fn to_katakana(input: &str) -> String {
 }
 /// Convert input to [Katakana](https://en.wikipedia.org/wiki/Katakana)
 pub fn to_katakana_with_opt(input: &str, options: Options) -> String {
+    let mut ouput = String::with_capacity(input.len());
+    to_katakana_into(input, options, &mut ouput);
+    ouput
+}
+
+/// Convert input to [Katakana](https://en.wikipedia.org/wiki/Katakana), appending to `ouput`
+pub fn to_katakana_into(input: &str, options: Options, ouput: &mut String) {
+    if options.halfwidth_katakana {
+        let options = Options {
+            halfwidth_katakana: false,
+            ..options
+        };
+        let katakana = to_katakana_with_opt(input, options);
+        zenkaku_katakana_to_hankaku_into(&katakana, ouput);
+        return;
+    }
+    let input = &*zenkaku_katakana_cow(input);
     let config = options;
     if config.pass_romaji {
-        hiragana_to_katakana(input)
+        hiragana_to_katakana_into(input, ouput);
     } else if is_romaji(input) || is_mixed(input) {
         let romaji = romaji_to_hiragana(input, config);
-        hiragana_to_katakana(&romaji)
+        hiragana_to_katakana_into(&romaji, ouput);
+    } else {
+        hiragana_to_katakana_into(input, ouput);
+    }
+}
+
+/// Convert input to [Katakana](https://en.wikipedia.org/wiki/Katakana), borrowing `input` if
+/// nothing changes
+pub fn to_katakana_cow(input: &str, options: Options) -> Cow<'_, str> {
+    // Full-width katakana change too when writing half-width katakana
+    let is_unchanged = !options.halfwidth_katakana
+        && !input.chars().any(|char| {
+            is_char_hiragana(char)
+                || is_char_romaji(char)
+                || hankaku_to_zenkaku(char).is_some()
+                || is_char_voiced_sound_mark(char)
+        });
+    if is_unchanged {
+        return Cow::Borrowed(input);
+    }
+    let katakana = to_katakana_with_opt(input, options);
+    if katakana == input {
+        Cow::Borrowed(input)
     } else {
-        hiragana_to_katakana(input)
+        Cow::Owned(katakana)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::options::ImeMode;
+    use crate::utils::custom_mapping::mapping;
 
     fn with_obsolete_kana() -> Options {
         Options {
@@ -118,4 +167,77 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn custom_kana_mapping() {
+        let options = Options {
+            custom_kana_mapping: mapping(&[("na", "に"), ("ka", "ば")]),
+            ..Default::default()
+        };
+        assert_eq!(to_katakana_with_opt("wanakana", options), "ワニバニ");
+    }
+
+    #[test]
+    fn ime_mode() {
+        for imemode in [ImeMode::On, ImeMode::ToHiragana, ImeMode::ToKatakana] {
+            let options = Options {
+                imemode,
+                ..Default::default()
+            };
+            assert_eq!(to_katakana_with_opt("shin", options), "シn");
+        }
+    }
+
+    #[test]
+    fn into_appends() {
+        let mut katakana = String::from("ア");
+        to_katakana_into("かな", Options::default(), &mut katakana);
+        to_katakana_into(" kana", Options::default(), &mut katakana);
+        assert_eq!(katakana, "アカナ カナ");
+    }
+
+    #[test]
+    fn cow_borrows_unchanged_input() {
+        assert!(matches!(
+            to_katakana_cow("カタカナ、漢字", Options::default()),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(
+            to_katakana_cow("かな", Options::default()),
+            Cow::Owned(katakana) if katakana == "カナ"
+        ));
+        assert!(matches!(
+            to_katakana_cow("ｶﾅ", Options::default()),
+            Cow::Owned(katakana) if katakana == "カナ"
+        ));
+    }
+
+    #[test]
+    fn converts_hankaku_katakana() {
+        assert_eq!(to_katakana("ｶﾞｲｺｸｺﾞ"), "ガイコクゴ");
+        assert_eq!(to_katakana("ﾊﾟﾝとｺｰﾋｰ"), "パントコーヒー");
+        assert_eq!(to_katakana("ｳﾞｧｲｵﾘﾝ desu"), "ヴァイオリン デス");
+    }
+
+    #[test]
+    fn halfwidth_katakana() {
+        let options = Options {
+            halfwidth_katakana: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            to_katakana_with_opt("ぱん、こーひー", options.clone()),
+            "ﾊﾟﾝ､ｺｰﾋｰ"
+        );
+        assert_eq!(to_katakana_with_opt("gakkou", options.clone()), "ｶﾞｯｺｳ");
+        assert_eq!(
+            to_katakana_with_opt("ヴァイオリン ヶ", options.clone()),
+            "ｳﾞｧｲｵﾘﾝ ヶ"
+        );
+        assert!(matches!(
+            to_katakana_cow("カナ", options.clone()),
+            Cow::Owned(katakana) if katakana == "ｶﾅ"
+        ));
+        assert!(matches!(to_katakana_cow("ｶﾅ", options), Cow::Borrowed(_)));
+    }
 }
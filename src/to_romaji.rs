@@ -1,6 +1,23 @@
-use crate::options::Options;
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::conversion_error::{check_spans, ConversionError, ErrorKind};
+use crate::options::{CustomMapping, Options, Romanization};
 pub(crate) use crate::to_romaji_node_tree::TO_ROMAJI_NODE_TREE;
+use crate::to_romaji_node_tree::{
+    Node, TO_ROMAJI_NODE_TREE_KUNREI, TO_ROMAJI_NODE_TREE_NIHON, TO_ROMAJI_NODE_TREE_PASSPORT,
+    TO_ROMAJI_NODE_TREE_STRICT,
+};
+use crate::traits::Span;
+use crate::utils::custom_mapping::{find_custom_mapping, to_hiragana_keys};
+use crate::utils::hankaku_katakana_to_zenkaku::{may_need_zenkaku, zenkaku_char_indices};
+use crate::utils::is_char_kana::is_char_kana;
 use crate::utils::is_char_katakana::is_char_katakana;
+use crate::utils::is_char_long_dash::is_char_long_dash;
+use crate::utils::is_char_romaji::is_char_romaji;
+use crate::utils::is_char_vowel::is_char_vowel;
 use crate::utils::katakana_to_hiragana::*;
 
 /// Convert kana to romaji
@@ -10,52 +27,290 @@ pub fn to_romaji(input: &str) -> String {
 
 /// Convert kana to romaji
 pub fn to_romaji_with_opt(orig: &str, options: Options) -> String {
-    let kana = katakana_to_hiragana_with_opt(orig, true);
-    let orig_chars = orig.chars().collect::<Vec<_>>();
-    let chars = kana.chars().collect::<Vec<_>>();
     let mut ouput = String::with_capacity(orig.len());
+    to_romaji_into(orig, options, &mut ouput);
+    ouput
+}
+
+/// Convert kana to romaji, appending the romaji to `ouput`
+pub fn to_romaji_into(orig: &str, options: Options, ouput: &mut String) {
+    if passes_through(orig, &options) {
+        ouput.push_str(orig);
+    } else {
+        convert(orig, options, ouput, None);
+    }
+}
+
+/// Convert kana to romaji, borrowing `orig` if nothing changes
+pub fn to_romaji_cow(orig: &str, options: Options) -> Cow<'_, str> {
+    if passes_through(orig, &options) {
+        return Cow::Borrowed(orig);
+    }
+    let romaji = to_romaji_with_opt(orig, options);
+    if romaji == orig {
+        Cow::Borrowed(orig)
+    } else {
+        Cow::Owned(romaji)
+    }
+}
+
+/// Returns true if no char of `orig` starts a kana syllable or is changed by the full-width
+/// conversion, so that the conversion returns `orig` as it is.
+fn passes_through(orig: &str, options: &Options) -> bool {
+    let tree = romaji_tree(options.romanization);
+    // The trees have no ASCII transitions, so ASCII can be passed through after a scan of bytes
+    options.custom_romaji_mapping.is_empty()
+        && (orig.is_ascii()
+            || zenkaku_char_indices(orig).all(|(index, char)| {
+                // Composed voiced sound marks and widened half-width katakana are changed
+                orig[index..].starts_with(char)
+                    && tree.find_transition_node(shift_katakana(char)).is_none()
+            }))
+}
+
+/// Convert kana to romaji, and return which byte range of `orig` produced which byte range of the
+/// output.
+pub fn to_romaji_with_spans(orig: &str, options: Options) -> (String, Vec<Span>) {
+    let mut ouput = String::with_capacity(orig.len());
+    let mut spans = Vec::new();
+    convert(orig, options, &mut ouput, Some(&mut spans));
+    (ouput, spans)
+}
+
+/// Convert kana to romaji, failing if letters are left which are not romaji (like kanji).
+pub fn try_to_romaji(orig: &str, options: Options) -> Result<String, ConversionError> {
+    let (romaji, spans) = to_romaji_with_spans(orig, options);
+    check_spans(orig, romaji, &spans, |char| {
+        if char.is_alphabetic() && !is_char_romaji(char) {
+            Some(ErrorKind::UnsupportedChar)
+        } else {
+            None
+        }
+    })
+}
+
+/// Converts `orig` and appends the romaji to `ouput`. The segments of `orig` and `ouput` which
+/// correspond are added to `spans`.
+fn convert(orig: &str, options: Options, ouput: &mut String, mut spans: Option<&mut Vec<Span>>) {
+    // Half-width katakana are converted as full-width ones
+    let orig_chars = if may_need_zenkaku(orig) {
+        zenkaku_char_indices(orig).map(|(_, char)| char).collect()
+    } else {
+        orig.chars().collect::<Vec<_>>()
+    };
+    // The byte offsets in `orig` of `orig_chars`
+    let offsets = match spans {
+        Some(_) => zenkaku_char_indices(orig)
+            .map(|(offset, _)| offset)
+            .collect(),
+        None => Vec::new(),
+    };
+    // Without katakana the hiragana is the input itself
+    let chars = if !orig_chars.iter().any(|char| is_char_katakana(*char)) {
+        Cow::Borrowed(&orig_chars[..])
+    } else if options.romanization == Romanization::Strict {
+        Cow::Owned(
+            orig_chars
+                .iter()
+                .map(|char| shift_katakana(*char))
+                .collect(),
+        )
+    } else {
+        let mut katakana = KatakanaToHiragana::new(true);
+        Cow::Owned(
+            orig_chars
+                .iter()
+                .map(|char| katakana.convert(*char))
+                .collect(),
+        )
+    };
     let len = chars.len();
     // Position in the string that is being evaluated
     let mut curr_pos = 0;
+    let mut input_start = 0;
+    let tree = romaji_tree(options.romanization);
+    let custom_mapping = to_hiragana_keys(&options.custom_romaji_mapping);
 
     while curr_pos != len {
-        let result = TO_ROMAJI_NODE_TREE.get(&chars[curr_pos..]);
-        // nothing found, pass through
-        if result.1 == 0 {
-            ouput.push(chars[curr_pos]);
-            curr_pos += 1;
+        let output_start = ouput.len();
+        curr_pos += push_next_romaji(
+            tree,
+            &custom_mapping,
+            &options,
+            &chars[curr_pos..],
+            &orig_chars[curr_pos..],
+            ouput,
+        );
+
+        if let Some(spans) = spans.as_deref_mut() {
+            let input_end = offsets.get(curr_pos).copied().unwrap_or(orig.len());
+            spans.push((input_start..input_end, output_start..ouput.len()));
+            input_start = input_end;
+        }
+    }
+}
+
+/// Converts the longest kana at the start of `chars` and appends the romaji to `ouput`, or passes
+/// the first char through if nothing matches. `chars` is the hiragana of `orig_chars`, char for
+/// char. Returns the number of chars converted.
+pub(crate) fn push_next_romaji(
+    tree: &Node,
+    custom_mapping: &CustomMapping,
+    options: &Options,
+    chars: &[char],
+    orig_chars: &[char],
+    ouput: &mut String,
+) -> usize {
+    let mut result: (&str, usize) = tree.get(chars);
+    if options.romanization == Romanization::Strict && result.1 > 1 {
+        // Keep syllables which mix hiragana and katakana apart, so they can be cased
+        let is_katakana = |c: &char| is_char_katakana(*c);
+        let syllable = &orig_chars[..result.1];
+        if !syllable.iter().all(is_katakana) && syllable.iter().any(is_katakana) {
+            result = tree.get(&chars[..1]);
+        }
+    }
+    // Custom rules win over built-in ones of the same length
+    if let Some(custom) = find_custom_mapping(custom_mapping, chars) {
+        if custom.1 >= result.1 {
+            result = custom;
+        }
+    }
+    // nothing found, pass through
+    if result.1 == 0 {
+        ouput.push(chars[0]);
+        return 1;
+    }
+    let mut romaji = Cow::Borrowed(result.0);
+    let mut syllable_len = result.1;
+    if let Some(vowel) = result.0.chars().last() {
+        let long_len = long_vowel_len(tree, chars, orig_chars, syllable_len, vowel);
+        let is_word_end = !chars
+            .get(syllable_len + long_len)
+            .is_some_and(|c| is_char_kana(*c));
+        if let (true, Some(long)) = (
+            long_len != 0,
+            long_vowel_spelling(options, vowel, is_word_end),
+        ) {
+            let short = &result.0[..result.0.len() - vowel.len_utf8()];
+            romaji = Cow::Owned(format!("{}{}", short, long));
+            syllable_len += long_len;
+        }
+    }
+    // Keep ん apart from a following vowel, even if it is in another script
+    if options.romanization == Romanization::Strict && romaji == "n" {
+        let next = tree.get(&chars[syllable_len..]).0;
+        if next.starts_with(is_char_vowel) {
+            romaji = Cow::Borrowed("n'");
+        }
+    }
+    // Passport Hepburn writes ん as "m" before b, m and p
+    if options.romanization == Romanization::Passport && romaji == "n" {
+        let next = tree.get(&chars[syllable_len..]).0;
+        if next.starts_with(['b', 'm', 'p']) {
+            romaji = Cow::Borrowed("m");
+        }
+    }
+
+    let convert_romaji_to_uppercase = {
+        if orig_chars[..syllable_len]
+            .iter()
+            .all(|c| is_char_katakana(*c))
+        {
+            options.upcase_katakana
         } else {
-            let convert_romaji_to_uppercase = {
-                if orig_chars[curr_pos..curr_pos + result.1]
-                    .iter()
-                    .all(|c| is_char_katakana(*c))
-                {
-                    options.upcase_katakana
-                } else {
-                    false
-                }
-            };
+            false
+        }
+    };
 
-            if convert_romaji_to_uppercase {
-                ouput.push_str(&result.0.to_uppercase());
-            } else {
-                ouput.push_str(result.0);
-            }
-            curr_pos += result.1;
+    if convert_romaji_to_uppercase {
+        ouput.push_str(&romaji.to_uppercase());
+    } else {
+        ouput.push_str(&romaji);
+    }
+    syllable_len
+}
+
+/// Returns the number of chars at `pos` which lengthen the preceding `vowel`, like the う in
+/// とう or a 'ー'.
+fn long_vowel_len(
+    tree: &Node,
+    chars: &[char],
+    orig_chars: &[char],
+    pos: usize,
+    vowel: char,
+) -> usize {
+    let is_long_dash = |pos: usize| orig_chars.get(pos).is_some_and(|c| is_char_long_dash(*c));
+    if is_long_dash(pos) {
+        let mut len = 1;
+        while is_long_dash(pos + len) {
+            len += 1;
         }
+        return len;
+    }
+    let lengthens = matches!(
+        (vowel, chars.get(pos)),
+        ('a', Some('あ'))
+            | ('u', Some('う'))
+            | ('e', Some('え'))
+            | ('o', Some('う'))
+            | ('o', Some('お'))
+    );
+    if lengthens && tree.get(&chars[pos..]).1 == 1 {
+        1
+    } else {
+        0
     }
+}
 
-    ouput
+/// Returns the spelling of a lengthened `vowel`, or `None` to keep the kana as they are.
+fn long_vowel_spelling(options: &Options, vowel: char, is_word_end: bool) -> Option<&'static str> {
+    match (options.romanization, vowel) {
+        (Romanization::Passport, 'o') if is_word_end => Some("oh"),
+        (Romanization::Passport, 'o') => Some("o"),
+        (Romanization::Passport, 'u') => Some("u"),
+        (Romanization::Passport, _) => None,
+        (Romanization::Strict, _) => None,
+        _ => options.long_vowels.lengthen(vowel),
+    }
+}
+
+pub(crate) fn romaji_tree(romanization: Romanization) -> &'static Node {
+    match romanization {
+        Romanization::Hepburn => &TO_ROMAJI_NODE_TREE,
+        Romanization::Kunrei => &TO_ROMAJI_NODE_TREE_KUNREI,
+        Romanization::Nihon => &TO_ROMAJI_NODE_TREE_NIHON,
+        Romanization::Passport => &TO_ROMAJI_NODE_TREE_PASSPORT,
+        Romanization::Strict => &TO_ROMAJI_NODE_TREE_STRICT,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::{Options, *};
+    use crate::options::LongVowels;
+    use crate::utils::custom_mapping::mapping;
     #[test]
     fn sane_defaults() {
         assert_eq!(to_romaji(""), "");
     }
 
+    #[test]
+    fn the_trees_have_no_ascii_transitions() {
+        // passes_through() relies on this
+        use Romanization::*;
+        for romanization in [Hepburn, Kunrei, Nihon, Passport, Strict] {
+            let tree = romaji_tree(romanization);
+            assert!((0..0x80u8).all(|byte| tree.find_transition_node(byte as char).is_none()));
+        }
+    }
+
+    #[test]
+    fn passes_ascii_and_non_kana_through() {
+        assert_eq!(to_romaji("Tokyo, 2024!"), "Tokyo, 2024!");
+        assert_eq!(to_romaji("Tōkyō 東京"), "Tōkyō 東京");
+    }
+
     #[test]
     fn convert_katakana_to_romaji() {
         assert_eq!(
@@ -80,6 +335,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn convert_kana_with_combining_voiced_sound_marks() {
+        assert_eq!(to_romaji("か\u{3099}っこう ハ\u{309A}ン"), "gakkou pan");
+        assert_eq!(to_romaji("ほ゛く"), "boku");
+        let (romaji, spans) = to_romaji_with_spans("か\u{3099}き", Options::default());
+        assert_eq!(romaji, "gaki");
+        assert_eq!(spans, vec![(0..6, 0..2), (6..9, 2..4)]);
+    }
+
     //#[test]
     // fn will_convert_punctuation_and_full_width_spaces() {
     // assert_eq!(to_romaji(JA_PUNC.join("")), EN_PUNC.join(""));
@@ -113,6 +377,82 @@ mod tests {
         );
     }
 
+    #[test]
+    fn use_the_romanization_option_to_select_the_system() {
+        let with = |romanization| Options {
+            romanization,
+            ..Default::default()
+        };
+        assert_eq!(
+            to_romaji_with_opt("ふじさん　ちゃ", with(Romanization::Hepburn)),
+            "fujisan cha"
+        );
+        assert_eq!(
+            to_romaji_with_opt("ふじさん　ちゃ", with(Romanization::Kunrei)),
+            "huzisan tya"
+        );
+        assert_eq!(
+            to_romaji_with_opt("ふじさん　ちゃ", with(Romanization::Nihon)),
+            "huzisan tya"
+        );
+    }
+
+    mod long_vowels {
+        use super::*;
+
+        fn with(long_vowels: LongVowels) -> Options {
+            Options {
+                long_vowels,
+                ..Default::default()
+            }
+        }
+
+        #[test]
+        fn wapuro_is_the_default() {
+            assert_eq!(to_romaji("とうきょう"), "toukyou");
+            assert_eq!(
+                to_romaji_with_opt("とうきょう", with(LongVowels::Wapuro)),
+                "toukyou"
+            );
+        }
+        #[test]
+        fn macrons() {
+            let opt = || with(LongVowels::Macron);
+            assert_eq!(to_romaji_with_opt("とうきょう", opt()), "tōkyō");
+            assert_eq!(to_romaji_with_opt("おおさか", opt()), "ōsaka");
+            assert_eq!(to_romaji_with_opt("おかあさん", opt()), "okāsan");
+            assert_eq!(to_romaji_with_opt("おねえさん", opt()), "onēsan");
+            assert_eq!(to_romaji_with_opt("くうこう", opt()), "kūkō");
+            assert_eq!(to_romaji_with_opt("にいがた", opt()), "niigata");
+            assert_eq!(to_romaji_with_opt("せんせい", opt()), "sensei");
+            assert_eq!(to_romaji_with_opt("スーパー", opt()), "sūpā");
+            assert_eq!(to_romaji_with_opt("コーヒー", opt()), "kōhī");
+            assert_eq!(to_romaji_with_opt("げーむ", opt()), "gēmu");
+        }
+        #[test]
+        fn macrons_are_uppercased_with_upcase_katakana() {
+            let opt = Options {
+                long_vowels: LongVowels::Macron,
+                upcase_katakana: true,
+                ..Default::default()
+            };
+            assert_eq!(to_romaji_with_opt("トウキョウ", opt), "TŌKYŌ");
+        }
+        #[test]
+        fn circumflexes() {
+            let opt = || with(LongVowels::Circumflex);
+            assert_eq!(to_romaji_with_opt("とうきょう", opt()), "tôkyô");
+            assert_eq!(to_romaji_with_opt("スーパー", opt()), "sûpâ");
+        }
+        #[test]
+        fn doubled_vowels() {
+            let opt = || with(LongVowels::Doubled);
+            assert_eq!(to_romaji_with_opt("とうきょう", opt()), "tookyoo");
+            assert_eq!(to_romaji_with_opt("スーパー", opt()), "suupaa");
+            assert_eq!(to_romaji_with_opt("げーむ", opt()), "geemu");
+        }
+    }
+
     #[test]
     fn converts_long_dash_in_hiragana_to_hyphen() {
         assert_eq!(to_romaji("ばつげーむ"), "batsuge-mu");
@@ -227,4 +567,157 @@ mod tests {
             assert_eq!(to_romaji("シンヨ"), "shin'yo");
         }
     }
+
+    mod custom_romaji_mapping {
+        use super::*;
+
+        fn with_mapping(pairs: &[(&str, &str)]) -> Options {
+            Options {
+                custom_romaji_mapping: mapping(pairs),
+                ..Default::default()
+            }
+        }
+        #[test]
+        fn applies_custom_mapping() {
+            let options = with_mapping(&[("じ", "zi"), ("つ", "tu"), ("り", "li")]);
+            assert_eq!(to_romaji_with_opt("つじぎり", options), "tuzigili");
+        }
+        #[test]
+        fn applies_to_katakana() {
+            let options = Options {
+                upcase_katakana: true,
+                ..with_mapping(&[("ヴ", "v"), ("ん", "n'")])
+            };
+            assert_eq!(to_romaji_with_opt("ヴンしん", options), "VN'shin'");
+        }
+        #[test]
+        fn multi_kana_keys() {
+            let options = with_mapping(&[("きゃく", "kyak"), ("しゃ", "sya")]);
+            assert_eq!(to_romaji_with_opt("きゃくしゃ", options), "kyaksya");
+        }
+        #[test]
+        fn longest_match_wins() {
+            let options = with_mapping(&[("じ", "zi")]);
+            assert_eq!(to_romaji_with_opt("じゃじ", options), "jazi");
+        }
+    }
+
+    #[test]
+    fn with_spans() {
+        let input = "スーパーの ごはん";
+        let options = Options {
+            long_vowels: LongVowels::Macron,
+            ..Default::default()
+        };
+        let (romaji, spans) = to_romaji_with_spans(input, options);
+        assert_eq!(romaji, "sūpāno gohan");
+        let segments = spans
+            .iter()
+            .map(|(i, o)| (&input[i.clone()], &romaji[o.clone()]))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            segments,
+            vec![
+                ("スー", "sū"),
+                ("パー", "pā"),
+                ("の", "no"),
+                (" ", " "),
+                ("ご", "go"),
+                ("は", "ha"),
+                ("ん", "n")
+            ]
+        );
+    }
+
+    #[test]
+    fn converts_hankaku_katakana() {
+        assert_eq!(to_romaji("ｶﾞｲｺｸｺﾞ"), "gaikokugo");
+        assert_eq!(to_romaji("ｼﾞｪｯﾄ ｽｰﾊﾟｰ｡"), "jetto suupaa.");
+        let options = Options {
+            upcase_katakana: true,
+            ..Default::default()
+        };
+        assert_eq!(to_romaji_with_opt("ﾜﾆｶﾆ が", options), "WANIKANI ga");
+        assert_eq!(to_romaji_cow("ｶﾅ", Options::default()), "kana");
+    }
+
+    #[test]
+    fn with_spans_of_hankaku_katakana() {
+        let input = "ｶﾞｯｺｳ ﾊﾟﾝ";
+        let (romaji, spans) = to_romaji_with_spans(input, Options::default());
+        assert_eq!(romaji, "gakkou pan");
+        let segments = spans
+            .iter()
+            .map(|(i, o)| (&input[i.clone()], &romaji[o.clone()]))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            segments,
+            vec![
+                ("ｶﾞ", "ga"),
+                ("ｯｺ", "kko"),
+                ("ｳ", "u"),
+                (" ", " "),
+                ("ﾊﾟ", "pa"),
+                ("ﾝ", "n")
+            ]
+        );
+    }
+
+    mod try_to_romaji {
+        use super::*;
+        use crate::conversion_error::{ErrorKind, InvalidSpan};
+
+        #[test]
+        fn converts_kana() {
+            assert_eq!(
+                try_to_romaji("ワニカニ　が すごい! abc", Options::default()),
+                Ok("wanikani ga sugoi! abc".to_string())
+            );
+        }
+        #[test]
+        fn reports_kanji() {
+            let error = try_to_romaji("日本ごを話す", Options::default()).unwrap_err();
+            assert_eq!(
+                error.invalid,
+                vec![
+                    InvalidSpan {
+                        range: 0..6,
+                        text: "日本".to_string(),
+                        kind: ErrorKind::UnsupportedChar,
+                    },
+                    InvalidSpan {
+                        range: 12..15,
+                        text: "話".to_string(),
+                        kind: ErrorKind::UnsupportedChar,
+                    },
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn rare_katakana_and_long_dashes() {
+        assert_eq!(to_romaji("ヷヸヹヺ"), "vavivevo");
+        assert_eq!(to_romaji("ヷー"), "vaa");
+        assert_eq!(to_romaji("ンー ッー"), "n- -");
+    }
+
+    #[test]
+    fn into_appends() {
+        let mut romaji = String::from("romaji: ");
+        to_romaji_into("ローマじ", Options::default(), &mut romaji);
+        assert_eq!(romaji, "romaji: roomaji");
+    }
+
+    #[test]
+    fn cow_borrows_unchanged_input() {
+        assert!(matches!(
+            to_romaji_cow("romaji 漢字", Options::default()),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(
+            to_romaji_cow("かな", Options::default()),
+            Cow::Owned(romaji) if romaji == "kana"
+        ));
+    }
 }
@@ -1,5 +1,11 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
 use crate::options::Options;
+use crate::traits::Span;
 pub(crate) use crate::to_ukrainian_node_tree::TO_UKRAINIAN_NODE_TREE;
+use crate::utils::custom_mapping::{find_custom_mapping, to_hiragana_keys};
+use crate::utils::hankaku_katakana_to_zenkaku::zenkaku_char_indices;
 use crate::utils::is_char_katakana::is_char_katakana;
 use crate::utils::katakana_to_hiragana::*;
 
@@ -93,39 +99,54 @@ static CONSONANT_BEGINNINGS: &[char] = &[
 
 /// Convert kana to romaji
 pub fn to_ukrainian_with_opt(orig: &str, options: Options) -> String {
+    convert(orig, options, None)
+}
+
+/// Convert kana to ukrainian, and return which byte range of `orig` produced which byte range of
+/// the output.
+pub fn to_ukrainian_with_spans(orig: &str, options: Options) -> (String, Vec<Span>) {
+    let mut spans = Vec::new();
+    let ouput = convert(orig, options, Some(&mut spans));
+    (ouput, spans)
+}
+
+fn convert(orig: &str, options: Options, mut spans: Option<&mut Vec<Span>>) -> String {
     let mut result = String::new();
-    orig.to_owned().push(' ');
-    let mut chars = orig.chars().peekable();
+    // Byte offset in `orig` of each char of `result`
+    let mut offsets = Vec::new();
+    // Half-width katakana are converted as full-width ones
+    let mut chars = zenkaku_char_indices(orig).peekable();
 
     let mut last_char = 'ä';
 
-    while let Some(c) = chars.next() {
+    while let Some((i, c)) = chars.next() {
 
 
         // vowel + _ + い = "аї", "ії", "уї", "еї", "ої"
         // い + _ + consonant/whitespace/none = "ай", "ій", "уй", "ей", "ой"
         if VOWEL_ENDINGS.contains(&last_char) 
             && c == '_' 
-            && chars.next_if(|x| x == &'い' || x == &'イ').is_some() 
+            && chars.next_if(|(_, x)| x == &'い' || x == &'イ').is_some() 
         {
-            if chars.next_if(|x| x == &'え' || x == &'エ').is_some() {
-                result.push_str("їє")
-            } else {
-                result.push('ї')
+            result.push('ї');
+            offsets.push(i);
+            if let Some((e, _)) = chars.next_if(|(_, x)| x == &'え' || x == &'エ') {
+                result.push('є');
+                offsets.push(e);
             }
         } else if VOWEL_ENDINGS.contains(&last_char)
             && (c == 'い' || c == 'イ')
         {
-            result.push('й')
-        } else {
+            result.push('й');
+            offsets.push(i);
+        } else if c != '_' {
             result.push(c);
+            offsets.push(i);
         }
 
         last_char = c;
     }
 
-    let result = result.replace('_', "");
-
 
     let kana = katakana_to_hiragana_with_opt(&result, true);
     let orig_chars = result.chars().collect::<Vec<_>>();
@@ -134,9 +155,18 @@ pub fn to_ukrainian_with_opt(orig: &str, options: Options) -> String {
     let len = chars.len();
     // Position in the string that is being evaluated
     let mut curr_pos = 0;
+    let custom_mapping = to_hiragana_keys(&options.custom_romaji_mapping);
 
     while curr_pos != len {
-        let result = TO_UKRAINIAN_NODE_TREE.get(&chars[curr_pos..]);
+        let segment_start = curr_pos;
+        let output_start = ouput.len();
+        let mut result: (&str, usize) = TO_UKRAINIAN_NODE_TREE.get(&chars[curr_pos..]);
+        // Custom rules win over built-in ones of the same length
+        if let Some(custom) = find_custom_mapping(&custom_mapping, &chars[curr_pos..]) {
+            if custom.1 >= result.1 {
+                result = custom;
+            }
+        }
         // nothing found, pass through
         if result.1 == 0 {
             ouput.push(chars[curr_pos]);
@@ -160,13 +190,20 @@ pub fn to_ukrainian_with_opt(orig: &str, options: Options) -> String {
             }
             curr_pos += result.1;
         }
+
+        if let Some(spans) = spans.as_deref_mut() {
+            let input_end = offsets.get(curr_pos).copied().unwrap_or(orig.len());
+            spans.push((offsets[segment_start]..input_end, output_start..ouput.len()));
+        }
     }
+    // "є" and "е" have the same length, the spans stay valid
     ouput.replace("іе", "іє")
 }
 
 #[cfg(test)]
 mod tests {
     use super::{Options, *};
+    use crate::utils::custom_mapping::mapping;
     #[test]
     fn sane_defaults() {
         assert_eq!(to_ukrainian(""), "");
@@ -343,4 +380,43 @@ mod tests {
             assert_eq!(to_ukrainian("シンヨ"), "шінйо");
         }
     }
+
+    #[test]
+    fn custom_romaji_mapping() {
+        let options = Options {
+            custom_romaji_mapping: mapping(&[("ぢ", "дзі"), ("かに", "краб")]),
+            ..Default::default()
+        };
+        assert_eq!(to_ukrainian_with_opt("ワニカニ ぢ", options), "ванікраб дзі");
+    }
+
+    #[test]
+    fn converts_hankaku_katakana() {
+        assert_eq!(to_ukrainian("ｶﾞｲｺｸｺﾞ"), to_ukrainian("ガイコクゴ"));
+        let (ukrainian, spans) = to_ukrainian_with_spans("ﾊﾟﾝ", Options::default());
+        assert_eq!(ukrainian, "пан");
+        assert_eq!(spans[0], (0..6, 0..4));
+    }
+
+    #[test]
+    fn with_spans() {
+        let input = "かい か_いえ";
+        let (ukrainian, spans) = to_ukrainian_with_spans(input, Options::default());
+        assert_eq!(ukrainian, "кай каїє");
+        let segments = spans
+            .iter()
+            .map(|(i, o)| (&input[i.clone()], &ukrainian[o.clone()]))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            segments,
+            vec![
+                ("か", "ка"),
+                ("い", "й"),
+                (" ", " "),
+                ("か", "ка"),
+                ("_い", "ї"),
+                ("え", "є")
+            ]
+        );
+    }
 }
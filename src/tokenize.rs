@@ -14,6 +14,10 @@
 //! assert_eq!(tokenize("私は悲しい"), vec!["私", "は", "悲", "しい"] );
 //! ```
 
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
 use itertools::Itertools;
 
 use crate::utils::is_char_english_punctuation::*;
@@ -25,6 +29,7 @@ use crate::utils::is_char_kanji::*;
 use crate::utils::is_char_katakana::*;
 use crate::utils::is_char_latin_number::*;
 use crate::utils::is_char_romaji::is_char_romaji;
+use crate::utils::voiced_sound_marks::is_char_voiced_sound_mark;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 /// The tokenizer assigns each token a `TokenType`.
@@ -90,6 +95,24 @@ fn get_type(input: char, compact: bool) -> TokenType {
     }
 }
 
+/// Returns a function which returns the type of each char. Voiced sound marks, like U+3099 in
+/// NFD text, get the type of the kana they follow.
+fn get_types(compact: bool) -> impl FnMut(&char) -> TokenType {
+    let mut previous = None;
+    move |&input| {
+        let token_type = match previous {
+            Some(kana @ (TokenType::Hiragana | TokenType::Katakana))
+                if is_char_voiced_sound_mark(input) =>
+            {
+                kana
+            }
+            _ => get_type(input, compact),
+        };
+        previous = Some(token_type);
+        token_type
+    }
+}
+
 /// Tokenizes the text. Splits input into array of strings separated by opinionated
 /// [`TokenType`](crate::tokenize::TokenType).
 ///
@@ -113,7 +136,7 @@ pub fn tokenize(input: &str) -> Vec<String> {
 /// numeral + punctuation).
 pub fn tokenize_with_opt(input: &str, compact: bool) -> Vec<String> {
     let mut result = vec![];
-    for (_, group) in &input.chars().group_by(|elt| get_type(*elt, compact)) {
+    for (_, group) in &input.chars().group_by(get_types(compact)) {
         result.push(group.collect());
     }
     result
@@ -144,7 +167,7 @@ pub fn tokenize_with_opt(input: &str, compact: bool) -> Vec<String> {
 /// ```
 pub fn tokenize_detailed(input: &str, compact: bool) -> Vec<(TokenType, String)> {
     let mut result = vec![];
-    for (token_type, group) in &input.chars().group_by(|elt| get_type(*elt, compact)) {
+    for (token_type, group) in &input.chars().group_by(get_types(compact)) {
         result.push((token_type, group.collect()));
     }
     result
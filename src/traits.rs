@@ -22,11 +22,21 @@
 #[cfg(feature = "enable_regex")]
 use regex::Regex;
 
-use crate::Options;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::{ConversionError, NormalizeOptions, Options, WidthOptions};
+
+/// Byte range of the input and byte range of the output it was converted to
+pub type Span = (Range<usize>, Range<usize>);
 
 /// The `wana_kana::ConvertJapanese` trait is implemented for `&str`, which allows
 /// conversion between kana and romaji.
 ///
+/// The conversions never panic, characters which can't be converted are passed through.
+///
 /// # Examples
 ///
 /// ```
@@ -57,6 +67,7 @@ pub trait ConvertJapanese {
     /// assert_eq!("ONAJI buttsuuji".to_kana(), "オナジ ぶっつうじ");
     /// assert_eq!("座禅‘zazen’スタイル".to_kana(), "座禅「ざぜん」スタイル");
     /// assert_eq!("!?./,~-‘’“”[](){}".to_kana(), "！？。・、〜ー「」『』［］（）｛｝");
+    /// assert_eq!("tōkyō KŌHĪ".to_kana(), "とうきょう コーヒー");
     /// ```
     fn to_kana(self) -> String;
 
@@ -67,9 +78,46 @@ pub trait ConvertJapanese {
     /// use wana_kana::Options;
     /// assert_eq!("batsuge-mu".to_kana_with_opt(Options {use_obsolete_kana: true, ..Default::default() } ), "ばつげーむ");
     /// assert_eq!("we".to_kana_with_opt(Options {use_obsolete_kana: true, ..Default::default() } ), "ゑ");
+    /// assert_eq!("ｋａｎａ　ＫＡＮＡ".to_kana_with_opt(Options {fullwidth_romaji: true, ..Default::default() } ), "かな　カナ");
     /// ```
     fn to_kana_with_opt(self, options: Options) -> String;
 
+    /// Convert [Romaji](https://en.wikipedia.org/wiki/Romaji) to [Kana](https://en.wikipedia.org/wiki/Kana) with Options, appending the kana to `ouput` instead of allocating a new `String`.
+    /// # Examples
+    /// ```
+    /// use wana_kana::ConvertJapanese;
+    /// use wana_kana::Options;
+    /// let mut kana = String::new();
+    /// "kana".to_kana_into(Options::default(), &mut kana);
+    /// " KANA".to_kana_into(Options::default(), &mut kana);
+    /// assert_eq!(kana, "かな カナ");
+    /// ```
+    fn to_kana_into(self, options: Options, ouput: &mut String);
+
+    /// Convert [Romaji](https://en.wikipedia.org/wiki/Romaji) to [Kana](https://en.wikipedia.org/wiki/Kana) with Options, and return the segments of the input and of the output which correspond, as byte ranges.
+    /// # Examples
+    /// ```
+    /// use wana_kana::ConvertJapanese;
+    /// use wana_kana::Options;
+    /// let (kana, spans) = "kyou da".to_kana_with_spans(Options::default());
+    /// assert_eq!(kana, "きょう だ");
+    /// assert_eq!(spans, vec![(0..3, 0..6), (3..4, 6..9), (4..5, 9..10), (5..7, 10..13)]);
+    /// ```
+    fn to_kana_with_spans(self, options: Options) -> (String, Vec<Span>);
+
+    /// Convert [Romaji](https://en.wikipedia.org/wiki/Romaji) to [Kana](https://en.wikipedia.org/wiki/Kana) with Options, failing with the parts of the input which are not romaji syllables (like "kq") or not kana (like kanji).
+    /// # Examples
+    /// ```
+    /// use wana_kana::{ConvertJapanese, ErrorKind, Options};
+    /// assert_eq!("kyou da!".try_to_kana(Options::default()).unwrap(), "きょう だ！");
+    /// let error = "kq 漢字".try_to_kana(Options::default()).unwrap_err();
+    /// assert_eq!(error.invalid[0].text, "kq");
+    /// assert_eq!(error.invalid[0].kind, ErrorKind::InvalidRomaji);
+    /// assert_eq!(error.invalid[1].range, 3..9);
+    /// assert_eq!(error.invalid[1].kind, ErrorKind::UnsupportedChar);
+    /// ```
+    fn try_to_kana(self, options: Options) -> Result<String, ConversionError>;
+
     /// Convert input to [Hiragana](https://en.wikipedia.org/wiki/Hiragana)
     /// # Examples
     /// ```
@@ -89,12 +137,105 @@ pub trait ConvertJapanese {
     /// ```
     fn to_hiragana_with_opt(self, options: Options) -> String;
 
+    /// Convert input to [Hiragana](https://en.wikipedia.org/wiki/Hiragana) with Options, appending to `ouput`
+    /// # Examples
+    /// ```
+    /// use wana_kana::ConvertJapanese;
+    /// use wana_kana::Options;
+    /// let mut hiragana = String::from("かな: ");
+    /// "カナ".to_hiragana_into(Options::default(), &mut hiragana);
+    /// assert_eq!(hiragana, "かな: かな");
+    /// ```
+    fn to_hiragana_into(self, options: Options, ouput: &mut String);
+
     /// Convert input to [Katakana](https://en.wikipedia.org/wiki/Katakana)
     fn to_katakana(self) -> String;
 
     /// Convert input to [Katakana](https://en.wikipedia.org/wiki/Katakana)
     fn to_katakana_with_opt(self, options: Options) -> String;
 
+    /// Convert input to [Katakana](https://en.wikipedia.org/wiki/Katakana) with Options, appending to `ouput`
+    /// # Examples
+    /// ```
+    /// use wana_kana::ConvertJapanese;
+    /// use wana_kana::Options;
+    /// let mut katakana = String::new();
+    /// "かな".to_katakana_into(Options::default(), &mut katakana);
+    /// assert_eq!(katakana, "カナ");
+    /// ```
+    fn to_katakana_into(self, options: Options, ouput: &mut String);
+
+    /// Convert ASCII letters, digits, punctuation and half-width katakana to their full-width
+    /// forms
+    /// # Examples
+    /// ```
+    /// use wana_kana::ConvertJapanese;
+    /// assert_eq!("ｶﾞｯｺｳ ABC-123".to_fullwidth(), "ガッコウ　ＡＢＣ－１２３");
+    /// ```
+    fn to_fullwidth(self) -> String;
+
+    /// Convert the classes of chars set in WidthOptions to their full-width forms
+    /// # Examples
+    /// ```
+    /// use wana_kana::{ConvertJapanese, WidthOptions};
+    /// let kana_only = WidthOptions { letters: false, digits: false, punctuation: false, katakana: true };
+    /// assert_eq!("ｶﾞｯｺｳ ABC-123".to_fullwidth_with_opt(kana_only), "ガッコウ ABC-123");
+    /// ```
+    fn to_fullwidth_with_opt(self, options: WidthOptions) -> String;
+
+    /// Convert full-width letters, digits, punctuation and katakana to ASCII and half-width
+    /// katakana
+    /// # Examples
+    /// ```
+    /// use wana_kana::ConvertJapanese;
+    /// assert_eq!("ガッコウ　ＡＢＣ－１２３".to_halfwidth(), "ｶﾞｯｺｳ ABC-123");
+    /// ```
+    fn to_halfwidth(self) -> String;
+
+    /// Convert the classes of chars set in WidthOptions to their half-width forms
+    /// # Examples
+    /// ```
+    /// use wana_kana::{ConvertJapanese, WidthOptions};
+    /// let ascii_only = WidthOptions { katakana: false, ..Default::default() };
+    /// assert_eq!("ガッコウ　ＡＢＣ－１２３".to_halfwidth_with_opt(ascii_only), "ガッコウ ABC-123");
+    /// ```
+    fn to_halfwidth_with_opt(self, options: WidthOptions) -> String;
+
+    /// Normalize Japanese text for searching: fold katakana to hiragana, full-width ASCII and
+    /// half-width katakana to their usual width, and unify dashes, wave dashes, ヶ and ヵ
+    /// # Examples
+    /// ```
+    /// use wana_kana::ConvertJapanese;
+    /// assert_eq!("ｺ-ﾋ-".normalize(), "こーひー");
+    /// assert_eq!("コ―ヒ―　ＡＢＣ～".normalize(), "こーひー ABC〜");
+    /// ```
+    fn normalize(self) -> String;
+
+    /// Normalize Japanese text with the steps set in NormalizeOptions
+    /// # Examples
+    /// ```
+    /// use wana_kana::{ConvertJapanese, NormalizeOptions};
+    /// let options = NormalizeOptions { remove_long_vowel_mark: true, ..Default::default() };
+    /// assert_eq!("スーパー".normalize_with_opt(options), "すぱ");
+    /// ```
+    fn normalize_with_opt(self, options: NormalizeOptions) -> String;
+
+    /// Combine kana followed by a combining or spacing voiced sound mark, as in Unicode NFD
+    /// # Examples
+    /// ```
+    /// use wana_kana::ConvertJapanese;
+    /// assert_eq!("か\u{3099}は\u{309A}カ゛".compose_kana(), "がぱガ");
+    /// ```
+    fn compose_kana(self) -> String;
+
+    /// Split kana with voiced sound mark into the kana and the combining mark, as in Unicode NFD
+    /// # Examples
+    /// ```
+    /// use wana_kana::ConvertJapanese;
+    /// assert_eq!("がぱ".decompose_kana(), "か\u{3099}は\u{309A}");
+    /// ```
+    fn decompose_kana(self) -> String;
+
     /// Convert kana to romaji
     /// # Examples
     /// ```
@@ -112,6 +253,42 @@ pub trait ConvertJapanese {
     /// ```
     fn to_romaji_with_opt(self, options: Options) -> String;
 
+    /// Convert kana to romaji with Options, appending the romaji to `ouput`.
+    /// # Examples
+    /// ```
+    /// use wana_kana::ConvertJapanese;
+    /// use wana_kana::Options;
+    /// let mut romaji = String::new();
+    /// for kana in ["ひらがな", "　", "カタカナ"] {
+    ///     kana.to_romaji_into(Options::default(), &mut romaji);
+    /// }
+    /// assert_eq!(romaji, "hiragana katakana");
+    /// ```
+    fn to_romaji_into(self, options: Options, ouput: &mut String);
+
+    /// Convert kana to romaji with Options, and return the segments of the input and of the
+    /// output which correspond, as byte ranges.
+    /// # Examples
+    /// ```
+    /// use wana_kana::ConvertJapanese;
+    /// use wana_kana::Options;
+    /// let (romaji, spans) = "きょうは".to_romaji_with_spans(Options::default());
+    /// assert_eq!(romaji, "kyouha");
+    /// assert_eq!(spans, vec![(0..6, 0..3), (6..9, 3..4), (9..12, 4..6)]);
+    /// ```
+    fn to_romaji_with_spans(self, options: Options) -> (String, Vec<Span>);
+
+    /// Convert kana to romaji with Options, failing with the parts of the input which are not
+    /// kana (like kanji).
+    /// # Examples
+    /// ```
+    /// use wana_kana::{ConvertJapanese, Options};
+    /// assert_eq!("ひらがな　カタカナ".try_to_romaji(Options::default()).unwrap(), "hiragana katakana");
+    /// let error = "日本ご".try_to_romaji(Options::default()).unwrap_err();
+    /// assert_eq!(error.invalid[0].text, "日本");
+    /// ```
+    fn try_to_romaji(self, options: Options) -> Result<String, ConversionError>;
+
 
     /// Convert kana to ukrainian
     /// # Examples
@@ -129,6 +306,18 @@ pub trait ConvertJapanese {
     /// assert_eq!("ひらがな　カタカナ".to_ukrainian_with_opt(Options {upcase_katakana: true, ..Default::default() } ), "хіраґана КАТАКАНА");
     /// ```
     fn to_ukrainian_with_opt(self, options: Options) -> String;
+
+    /// Convert kana to ukrainian with Options, and return the segments of the input and of the
+    /// output which correspond, as byte ranges.
+    /// # Examples
+    /// ```
+    /// use wana_kana::ConvertJapanese;
+    /// use wana_kana::Options;
+    /// let (ukrainian, spans) = "かに".to_ukrainian_with_spans(Options::default());
+    /// assert_eq!(ukrainian, "кані");
+    /// assert_eq!(spans, vec![(0..3, 0..4), (3..6, 4..8)]);
+    /// ```
+    fn to_ukrainian_with_spans(self, options: Options) -> (String, Vec<Span>);
 }
 
 impl ConvertJapanese for &str {
@@ -142,6 +331,21 @@ impl ConvertJapanese for &str {
         crate::to_kana::to_kana_with_opt(self, options)
     }
 
+    #[inline]
+    fn to_kana_into(self, options: Options, ouput: &mut String) {
+        crate::to_kana::to_kana_into(self, options, ouput)
+    }
+
+    #[inline]
+    fn to_kana_with_spans(self, options: Options) -> (String, Vec<Span>) {
+        crate::to_kana::to_kana_with_spans(self, options)
+    }
+
+    #[inline]
+    fn try_to_kana(self, options: Options) -> Result<String, ConversionError> {
+        crate::to_kana::try_to_kana(self, options)
+    }
+
     #[inline]
     fn to_hiragana(self) -> String {
         crate::to_hiragana::to_hiragana(self)
@@ -152,6 +356,11 @@ impl ConvertJapanese for &str {
         crate::to_hiragana::to_hiragana_with_opt(self, options)
     }
 
+    #[inline]
+    fn to_hiragana_into(self, options: Options, ouput: &mut String) {
+        crate::to_hiragana::to_hiragana_into(self, options, ouput)
+    }
+
     #[inline]
     fn to_katakana(self) -> String {
         crate::to_katakana::to_katakana(self)
@@ -162,6 +371,51 @@ impl ConvertJapanese for &str {
         crate::to_katakana::to_katakana_with_opt(self, options)
     }
 
+    #[inline]
+    fn to_katakana_into(self, options: Options, ouput: &mut String) {
+        crate::to_katakana::to_katakana_into(self, options, ouput)
+    }
+
+    #[inline]
+    fn to_fullwidth(self) -> String {
+        crate::to_fullwidth::to_fullwidth(self)
+    }
+
+    #[inline]
+    fn to_fullwidth_with_opt(self, options: WidthOptions) -> String {
+        crate::to_fullwidth::to_fullwidth_with_opt(self, options)
+    }
+
+    #[inline]
+    fn to_halfwidth(self) -> String {
+        crate::to_halfwidth::to_halfwidth(self)
+    }
+
+    #[inline]
+    fn to_halfwidth_with_opt(self, options: WidthOptions) -> String {
+        crate::to_halfwidth::to_halfwidth_with_opt(self, options)
+    }
+
+    #[inline]
+    fn normalize(self) -> String {
+        crate::normalize::normalize(self)
+    }
+
+    #[inline]
+    fn normalize_with_opt(self, options: NormalizeOptions) -> String {
+        crate::normalize::normalize_with_opt(self, options)
+    }
+
+    #[inline]
+    fn compose_kana(self) -> String {
+        crate::utils::voiced_sound_marks::compose_kana(self)
+    }
+
+    #[inline]
+    fn decompose_kana(self) -> String {
+        crate::utils::voiced_sound_marks::decompose_kana(self)
+    }
+
     #[inline]
     fn to_romaji(self) -> String {
         crate::to_romaji::to_romaji(self)
@@ -172,6 +426,21 @@ impl ConvertJapanese for &str {
         crate::to_romaji::to_romaji_with_opt(self, options)
     }
 
+    #[inline]
+    fn to_romaji_into(self, options: Options, ouput: &mut String) {
+        crate::to_romaji::to_romaji_into(self, options, ouput)
+    }
+
+    #[inline]
+    fn to_romaji_with_spans(self, options: Options) -> (String, Vec<Span>) {
+        crate::to_romaji::to_romaji_with_spans(self, options)
+    }
+
+    #[inline]
+    fn try_to_romaji(self, options: Options) -> Result<String, ConversionError> {
+        crate::to_romaji::try_to_romaji(self, options)
+    }
+
     #[inline]
     fn to_ukrainian(self) -> String {
         crate::to_ukrainian::to_ukrainian(self)
@@ -181,6 +450,82 @@ impl ConvertJapanese for &str {
     fn to_ukrainian_with_opt(self, options: Options) -> String {
         crate::to_ukrainian::to_ukrainian_with_opt(self, options)
     }
+
+    #[inline]
+    fn to_ukrainian_with_spans(self, options: Options) -> (String, Vec<Span>) {
+        crate::to_ukrainian::to_ukrainian_with_spans(self, options)
+    }
+}
+
+/// The `wana_kana::ConvertJapaneseCow` trait is implemented for `&str`, its conversions return
+/// the input itself, without allocating, when there is nothing to convert.
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+/// use wana_kana::{ConvertJapaneseCow, Options};
+/// assert!(matches!("漢字".to_hiragana_cow(Options::default()), Cow::Borrowed("漢字")));
+/// assert!(matches!("カナ".to_hiragana_cow(Options::default()), Cow::Owned(_)));
+/// ```
+pub trait ConvertJapaneseCow<'a> {
+    /// Convert [Romaji](https://en.wikipedia.org/wiki/Romaji) to [Kana](https://en.wikipedia.org/wiki/Kana) with Options, borrowing the input if nothing changes.
+    /// # Examples
+    /// ```
+    /// use wana_kana::{ConvertJapaneseCow, Options};
+    /// assert_eq!("かな".to_kana_cow(Options::default()), "かな");
+    /// assert_eq!("kana".to_kana_cow(Options::default()), "かな");
+    /// ```
+    fn to_kana_cow(self, options: Options) -> Cow<'a, str>;
+
+    /// Convert input to [Hiragana](https://en.wikipedia.org/wiki/Hiragana) with Options, borrowing the input if nothing changes.
+    /// # Examples
+    /// ```
+    /// use wana_kana::{ConvertJapaneseCow, Options};
+    /// assert_eq!("ひらがな".to_hiragana_cow(Options::default()), "ひらがな");
+    /// assert_eq!("カナ".to_hiragana_cow(Options::default()), "かな");
+    /// ```
+    fn to_hiragana_cow(self, options: Options) -> Cow<'a, str>;
+
+    /// Convert input to [Katakana](https://en.wikipedia.org/wiki/Katakana) with Options, borrowing the input if nothing changes.
+    /// # Examples
+    /// ```
+    /// use wana_kana::{ConvertJapaneseCow, Options};
+    /// assert_eq!("カタカナ".to_katakana_cow(Options::default()), "カタカナ");
+    /// assert_eq!("かな".to_katakana_cow(Options::default()), "カナ");
+    /// ```
+    fn to_katakana_cow(self, options: Options) -> Cow<'a, str>;
+
+    /// Convert kana to romaji with Options, borrowing the input if nothing changes.
+    /// # Examples
+    /// ```
+    /// use wana_kana::{ConvertJapaneseCow, Options};
+    /// assert_eq!("romaji".to_romaji_cow(Options::default()), "romaji");
+    /// assert_eq!("かな".to_romaji_cow(Options::default()), "kana");
+    /// ```
+    fn to_romaji_cow(self, options: Options) -> Cow<'a, str>;
+}
+
+impl<'a> ConvertJapaneseCow<'a> for &'a str {
+    #[inline]
+    fn to_kana_cow(self, options: Options) -> Cow<'a, str> {
+        crate::to_kana::to_kana_cow(self, options)
+    }
+
+    #[inline]
+    fn to_hiragana_cow(self, options: Options) -> Cow<'a, str> {
+        crate::to_hiragana::to_hiragana_cow(self, options)
+    }
+
+    #[inline]
+    fn to_katakana_cow(self, options: Options) -> Cow<'a, str> {
+        crate::to_katakana::to_katakana_cow(self, options)
+    }
+
+    #[inline]
+    fn to_romaji_cow(self, options: Options) -> Cow<'a, str> {
+        crate::to_romaji::to_romaji_cow(self, options)
+    }
 }
 
 /// The `wana_kana::IsJapaneseStr` trait is implemented for `&str`, which allows easy
@@ -238,6 +583,18 @@ pub trait IsJapaneseStr {
     /// ```
     fn is_romaji(self) -> bool;
 
+    /// Test if every char in `input` is [Romaji](https://en.wikipedia.org/wiki/Romaji) with
+    /// Options, full-width letters and punctuation are romaji with `fullwidth_romaji`
+    ///
+    /// # Examples
+    /// ```
+    /// use wana_kana::{IsJapaneseStr, Options};
+    /// let options = Options { fullwidth_romaji: true, ..Default::default() };
+    /// assert_eq!("ｈｅｌｌｏ！".is_romaji_with_opt(options), true);
+    /// assert_eq!("ｈｅｌｌｏ！".is_romaji_with_opt(Options::default()), false);
+    /// ```
+    fn is_romaji_with_opt(self, options: Options) -> bool;
+
     #[cfg_attr(docsrs, doc(cfg(feature = "enable_regex")))]
     #[cfg(feature = "enable_regex")]
     /// Test if every char in `input` is [Romaji](https://en.wikipedia.org/wiki/Romaji) (allowing [Hepburn romanisation](https://en.wikipedia.org/wiki/Hepburn_romanization)
@@ -332,6 +689,18 @@ pub trait IsJapaneseStr {
     /// assert_eq!("あア".is_mixed_pass_kanji(true), false);
     /// ```
     fn is_mixed_pass_kanji(self, pass_kanji: bool) -> bool;
+
+    /// Test if `input` is unchanged after a conversion to [`Romanization::Strict`](crate::Romanization::Strict)
+    /// romaji and back to kana. Romaji `input` is converted to kana and back to strict romaji.
+    ///
+    /// # Examples
+    /// ```
+    /// use wana_kana::IsJapaneseStr;
+    /// assert_eq!("ちぢむ　スーパー".round_trips(), true);
+    /// assert_eq!("tidimu SUPA-".round_trips(), true);
+    /// assert_eq!("chijimu".round_trips(), false);
+    /// ```
+    fn round_trips(self) -> bool;
 }
 
 impl IsJapaneseStr for &str {
@@ -376,6 +745,11 @@ impl IsJapaneseStr for &str {
         crate::is_romaji::is_romaji(self)
     }
 
+    #[inline]
+    fn is_romaji_with_opt(self, options: Options) -> bool {
+        crate::is_romaji::is_romaji_with_opt(self, options)
+    }
+
     #[cfg(feature = "enable_regex")]
     #[inline]
     fn is_romaji_with_whitelist(self, allowed: Option<&Regex>) -> bool {
@@ -391,6 +765,11 @@ impl IsJapaneseStr for &str {
     fn is_mixed_pass_kanji(self, pass_kanji: bool) -> bool {
         crate::is_mixed::is_mixed_pass_kanji(self, pass_kanji)
     }
+
+    #[inline]
+    fn round_trips(self) -> bool {
+        crate::round_trips::round_trips(self)
+    }
 }
 
 /// The `wana_kana::IsJapaneseChar` trait is implemented for the `char`type,
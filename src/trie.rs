@@ -0,0 +1,90 @@
+//! Flat transition tables generated by `upstream_conversions`, see `src/tables`.
+
+use core::fmt;
+
+/// Nodes with up to this many transitions are searched linearly
+const LINEAR_SEARCH_MAX: usize = 64;
+
+/// A tree of char transitions stored as flat arrays. The transitions leaving a node are a range
+/// of `transitions`, sorted by char.
+pub(crate) struct Trie {
+    /// The range of the transitions leaving the root
+    pub(crate) root: (u16, u16),
+    /// The char of each transition and the range of the transitions leaving its target
+    pub(crate) transitions: &'static [(char, u16, u16)],
+    /// The output of the target of each transition
+    pub(crate) outputs: &'static [Option<&'static str>],
+}
+
+/// A node of a [`Trie`]
+#[derive(Clone, Copy)]
+pub(crate) struct TrieNode {
+    trie: &'static Trie,
+    start: u16,
+    end: u16,
+    output: Option<&'static str>,
+}
+
+impl TrieNode {
+    pub(crate) const fn root(trie: &'static Trie) -> TrieNode {
+        TrieNode {
+            trie,
+            start: trie.root.0,
+            end: trie.root.1,
+            output: None,
+        }
+    }
+
+    pub(crate) fn output(self) -> Option<&'static str> {
+        self.output
+    }
+
+    pub(crate) fn has_transitions(self) -> bool {
+        self.start != self.end
+    }
+
+    /// The transitions of this node, sorted by char
+    pub(crate) fn transitions(self) -> impl Iterator<Item = (char, TrieNode)> {
+        (self.start..self.end)
+            .map(move |index| (self.trie.transitions[index as usize].0, self.target(index)))
+    }
+
+    #[inline]
+    pub(crate) fn find_transition_node(self, char: char) -> Option<TrieNode> {
+        let transitions = &self.trie.transitions[self.start as usize..self.end as usize];
+        // A scan is faster than a binary search for the short ranges of most nodes
+        let position = if transitions.len() <= LINEAR_SEARCH_MAX {
+            transitions.iter().position(|t| t.0 == char)
+        } else {
+            transitions.binary_search_by_key(&char, |t| t.0).ok()
+        };
+        position.map(|position| self.target(self.start + position as u16))
+    }
+
+    fn target(self, index: u16) -> TrieNode {
+        let (_, start, end) = self.trie.transitions[index as usize];
+        TrieNode {
+            trie: self.trie,
+            start,
+            end,
+            output: self.trie.outputs[index as usize],
+        }
+    }
+
+    /// Returns the number of chars of the longest path below this node
+    pub(crate) fn depth(self) -> usize {
+        self.transitions()
+            .map(|(_, node)| node.depth() + 1)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl fmt::Debug for TrieNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TrieNode")
+            .field("transitions", &(self.start..self.end))
+            .field("output", &self.output)
+            .finish()
+    }
+}
@@ -77,7 +77,6 @@ pub fn trim_okurigana_with_opt<'a>(
     } else {
         tokenize(input)
     };
-    dbg!(&tokens);
 
     if trim_from_start {
         input.trim_start_matches(&tokens[0])
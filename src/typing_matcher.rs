@@ -0,0 +1,374 @@
+//! Matches typed romaji against a kana target, accepting every spelling to_kana() understands.
+//!
+//! The [`TypingMatcher`] is meant for typing games: each key is checked as it is typed, so "sha"
+//! and "sya", "cchi" and "tti" or "xtu" and "ltu" are all accepted for the same kana.
+//!
+//! # Examples
+//! ```
+//! use wana_kana::typing_matcher::TypingMatcher;
+//! let mut matcher = TypingMatcher::new("しゃっちょう");
+//! assert_eq!(matcher.suggestion(), "shacchou");
+//! assert!(matcher.push('s'));
+//! assert!(matcher.push('y'));
+//! assert!(!matcher.push('i'));
+//! assert!(matcher.push('a'));
+//! assert_eq!(matcher.completed(), 2);
+//! assert_eq!(matcher.suggestion(), "cchou");
+//! for key in "ltutyou".chars() {
+//!     assert!(matcher.push(key));
+//! }
+//! assert!(matcher.is_finished());
+//! ```
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::tables::spellings::{MAX_KANA_LEN, SPELLINGS};
+use crate::to_kana_node_tree::TO_KANA_NODE_TREE;
+use crate::to_romaji::to_romaji;
+use crate::utils::katakana_to_hiragana::katakana_to_hiragana_keep_long_dash;
+
+/// Returns every romaji spelling of `kana`
+fn spellings(kana: &str) -> &'static [&'static str] {
+    SPELLINGS
+        .binary_search_by_key(&kana, |&(kana, _)| kana)
+        .map_or(&[], |index| SPELLINGS[index].1)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct State {
+    /// Number of target chars which have been typed
+    pos: usize,
+    /// Spelling being typed, with the number of target chars it covers and of keys typed
+    spelling: Option<(&'static str, usize, usize)>,
+    /// Spelling which has just been completed, the next spelling must not extend it
+    previous: Option<&'static str>,
+}
+
+/// Checks romaji keystrokes against a kana target.
+///
+/// Katakana in the target are matched like hiragana, chars without romaji spelling (like kanji)
+/// have to be typed as they are. ん may be typed "nn", as with an IME.
+#[derive(Debug, Clone)]
+pub struct TypingMatcher {
+    target: Vec<char>,
+    typed: String,
+    states: Vec<State>,
+}
+
+impl TypingMatcher {
+    /// Creates a matcher for the kana `target`
+    pub fn new(target: &str) -> Self {
+        TypingMatcher {
+            target: katakana_to_hiragana_keep_long_dash(target)
+                .chars()
+                .collect(),
+            typed: String::new(),
+            states: vec![State {
+                pos: 0,
+                spelling: None,
+                previous: None,
+            }],
+        }
+    }
+
+    /// Checks a typed key. Returns false and ignores the key if it doesn't continue any spelling
+    /// of the target.
+    pub fn push(&mut self, key: char) -> bool {
+        let key = key.to_ascii_lowercase();
+        let mut states = Vec::new();
+        for state in &self.states {
+            for next in self.advance(*state, key) {
+                if !states.contains(&next) {
+                    states.push(next);
+                }
+            }
+        }
+        if states.is_empty() {
+            return false;
+        }
+        self.states = states;
+        self.typed.push(key);
+        true
+    }
+
+    /// Returns the accepted keys
+    pub fn typed(&self) -> &str {
+        &self.typed
+    }
+
+    /// Returns the number of target chars which are typed completely, whatever the spelling
+    /// which is finally used
+    pub fn completed(&self) -> usize {
+        if self.is_finished() {
+            return self.target.len();
+        }
+        self.states
+            .iter()
+            .filter(|state| !self.extends_finished_spelling(state))
+            .map(|state| state.pos)
+            .min()
+            .unwrap_or(0)
+    }
+
+    /// Returns true if `state` is typing a longer spelling of kana which another state has
+    /// finished, like "nn" after "n" for ん
+    fn extends_finished_spelling(&self, state: &State) -> bool {
+        match state.spelling {
+            Some((spelling, kana_len, typed)) => self.states.iter().any(|other| {
+                other.spelling.is_none()
+                    && other.pos == state.pos + kana_len
+                    && other.previous == Some(&spelling[..typed])
+            }),
+            None => false,
+        }
+    }
+
+    /// Returns true if the whole target has been typed
+    pub fn is_finished(&self) -> bool {
+        self.states
+            .iter()
+            .any(|state| state.spelling.is_none() && state.pos == self.target.len())
+    }
+
+    /// Returns the keys which are left to type, following the spelling already started
+    pub fn suggestion(&self) -> String {
+        self.states
+            .iter()
+            .map(|state| {
+                let mut suggestion = String::new();
+                let mut previous = state.previous;
+                let mut pos = state.pos;
+                if let Some((spelling, kana_len, typed)) = state.spelling {
+                    suggestion.push_str(&spelling[typed..]);
+                    previous = Some(spelling);
+                    pos += kana_len;
+                }
+                suggestion.push_str(&self.suggest_from(pos, previous));
+                suggestion
+            })
+            .min_by_key(|suggestion| suggestion.len())
+            .unwrap_or_default()
+    }
+
+    /// Returns the states reached by typing `key` in `state`
+    fn advance(&self, state: State, key: char) -> Vec<State> {
+        if let Some((spelling, kana_len, typed)) = state.spelling {
+            if spelling[typed..].starts_with(key) {
+                return vec![Self::typed_state(state.pos, spelling, kana_len, typed + 1)];
+            }
+            return vec![];
+        }
+        let mut states = Vec::new();
+        if self.target.get(state.pos).map(char::to_ascii_lowercase) == Some(key) {
+            states.push(State {
+                pos: state.pos + 1,
+                spelling: None,
+                previous: None,
+            });
+        }
+        for (spelling, kana_len) in self.candidates(state.pos, state.previous) {
+            if spelling.starts_with(key) {
+                states.push(Self::typed_state(state.pos, spelling, kana_len, 1));
+            }
+        }
+        states
+    }
+
+    fn typed_state(pos: usize, spelling: &'static str, kana_len: usize, typed: usize) -> State {
+        if typed == spelling.len() {
+            State {
+                pos: pos + kana_len,
+                spelling: None,
+                previous: Some(spelling),
+            }
+        } else {
+            State {
+                pos,
+                spelling: Some((spelling, kana_len, typed)),
+                previous: None,
+            }
+        }
+    }
+
+    /// Returns the spellings of the kana at `pos` with the number of target chars they cover.
+    /// Spellings which would make to_kana() read `previous` differently (ん + "a" is な) are
+    /// left out.
+    fn candidates(&self, pos: usize, previous: Option<&'static str>) -> Vec<(&'static str, usize)> {
+        let extends_previous = |spelling: &str| {
+            let previous = match previous {
+                Some(previous) => previous.chars().collect::<Vec<_>>(),
+                None => return false,
+            };
+            let (node, walked) = TO_KANA_NODE_TREE.walk(&previous);
+            walked == previous.len()
+                && spelling
+                    .chars()
+                    .next()
+                    .is_some_and(|char| node.find_transition_node(char).is_some())
+        };
+        let mut candidates = Vec::new();
+        for kana_len in 1..=MAX_KANA_LEN.min(self.target.len() - pos) {
+            let kana = self.target[pos..pos + kana_len].iter().collect::<String>();
+            for &spelling in spellings(&kana) {
+                if !extends_previous(spelling) {
+                    candidates.push((spelling, kana_len));
+                }
+            }
+        }
+        candidates
+    }
+
+    /// Returns a spelling of the target from `pos`, preferring the longest kana and the
+    /// spelling of to_romaji()
+    fn suggest_from(&self, mut pos: usize, mut previous: Option<&'static str>) -> String {
+        let mut suggestion = String::new();
+        while pos < self.target.len() {
+            let candidates = self.candidates(pos, previous);
+            // Spelling which to_kana() reads first in the to_romaji() output
+            let romaji = to_romaji(&self.target[pos..].iter().collect::<String>());
+            let romaji = romaji.chars().collect::<Vec<_>>();
+            let first_len = TO_KANA_NODE_TREE.get(&romaji).1;
+            let first = romaji[..first_len].iter().collect::<String>();
+            let longest = candidates.iter().map(|c| c.1).max();
+            let best = longest.and_then(|longest| {
+                let same_len = candidates.iter().filter(|c| c.1 == longest);
+                same_len
+                    .clone()
+                    .find(|c| c.0 == first)
+                    .or_else(|| same_len.min_by_key(|c| c.0.len()))
+                    .copied()
+            });
+            match best {
+                Some((spelling, kana_len)) => {
+                    suggestion.push_str(spelling);
+                    previous = Some(spelling);
+                    pos += kana_len;
+                }
+                None => {
+                    suggestion.push(self.target[pos]);
+                    previous = None;
+                    pos += 1;
+                }
+            }
+        }
+        suggestion
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::to_kana::to_kana;
+
+    fn accepts(target: &str, keys: &str) -> bool {
+        let mut matcher = TypingMatcher::new(target);
+        keys.chars().all(|key| matcher.push(key)) && matcher.is_finished()
+    }
+
+    #[test]
+    fn accepts_every_spelling() {
+        assert!(accepts("しゃ", "sha"));
+        assert!(accepts("しゃ", "sya"));
+        assert!(accepts("しゃ", "shixya"));
+        assert!(accepts("っ", "xtu"));
+        assert!(accepts("っ", "ltsu"));
+        assert!(accepts("ちょ", "cho"));
+        assert!(accepts("ちょ", "tyo"));
+        assert!(accepts("っちょ", "ccho"));
+        assert!(accepts("っちょ", "ttyo"));
+        assert!(accepts("ふじ", "huzi"));
+        assert!(accepts("ふじ", "fuji"));
+    }
+
+    #[test]
+    fn rejects_wrong_keys() {
+        let mut matcher = TypingMatcher::new("か");
+        assert!(!matcher.push('s'));
+        assert!(matcher.push('k'));
+        assert!(!matcher.push('i'));
+        assert_eq!(matcher.typed(), "k");
+        assert!(!matcher.is_finished());
+        assert!(matcher.push('a'));
+        assert!(matcher.is_finished());
+        assert!(!matcher.push('a'));
+    }
+
+    #[test]
+    fn n_before_vowels_needs_two_keys() {
+        assert!(accepts("かんい", "kan'i"));
+        assert!(accepts("かんい", "kanni"));
+        assert!(!accepts("かんい", "kani"));
+        assert!(accepts("ほんや", "honnya"));
+        assert!(!accepts("ほんや", "honya"));
+        assert!(accepts("ほんだ", "honda"));
+        assert!(accepts("ほん", "hon"));
+        assert!(accepts("ほん", "honn"));
+        assert!(accepts("こんにちは", "konnichiha"));
+        assert!(accepts("こんにちは", "konnnitiha"));
+    }
+
+    #[test]
+    fn katakana_and_others() {
+        assert!(accepts("スーパー", "su-pa-"));
+        assert!(accepts("SUPER スーパー", "SUPER su-pa-"));
+        assert!(accepts("日本です。", "日本desu."));
+    }
+
+    #[test]
+    fn reports_completed_kana() {
+        let mut matcher = TypingMatcher::new("きゃく");
+        assert!(matcher.push('k'));
+        assert_eq!(matcher.completed(), 0);
+        assert!(matcher.push('i'));
+        assert_eq!(matcher.completed(), 1);
+        assert!(matcher.push('x'));
+        assert!(matcher.push('y'));
+        assert!(matcher.push('a'));
+        assert_eq!(matcher.completed(), 2);
+    }
+
+    #[test]
+    fn completed_ignores_longer_spellings_of_typed_kana() {
+        let mut matcher = TypingMatcher::new("ん");
+        assert!(matcher.push('n'));
+        assert!(matcher.is_finished());
+        assert_eq!(matcher.completed(), 1);
+
+        let mut matcher = TypingMatcher::new("ほん");
+        for key in "hon".chars() {
+            assert!(matcher.push(key));
+        }
+        assert_eq!(matcher.completed(), 2);
+        assert!(matcher.push('n'));
+        assert_eq!(matcher.completed(), 2);
+    }
+
+    #[test]
+    fn suggests_remaining_keys() {
+        let mut matcher = TypingMatcher::new("しゃっちょう");
+        assert_eq!(matcher.suggestion(), "shacchou");
+        matcher.push('s');
+        matcher.push('y');
+        assert_eq!(matcher.suggestion(), "acchou");
+        assert_eq!(TypingMatcher::new("かんい").suggestion(), "kan'i");
+        assert_eq!(TypingMatcher::new("ほんや").suggestion(), "hon'ya");
+        assert_eq!(TypingMatcher::new("").suggestion(), "");
+    }
+
+    #[test]
+    fn suggestions_convert_back_to_target() {
+        for target in [
+            "しゃっちょう",
+            "きっぷ",
+            "こんにちは",
+            "ぢゃ",
+            "ゔぁいおりん",
+        ] {
+            let suggestion = TypingMatcher::new(target).suggestion();
+            assert_eq!(to_kana(&suggestion), target);
+            assert!(accepts(target, &suggestion));
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use crate::options::CustomMapping;
+use crate::utils::katakana_to_hiragana::katakana_to_hiragana;
+
+/// Returns the value of the longest key of `mapping` which `chars` start with, and the number of
+/// chars of that key. Keys are compared ignoring ASCII case.
+pub(crate) fn find_custom_mapping<'a>(
+    mapping: &'a CustomMapping,
+    chars: &[char],
+) -> Option<(&'a str, usize)> {
+    let mut longest: Option<(&str, usize)> = None;
+    for (key, value) in mapping {
+        let len = key.chars().count();
+        if len == 0 || len > chars.len() || longest.is_some_and(|(_, l)| l >= len) {
+            continue;
+        }
+        if key
+            .chars()
+            .zip(chars)
+            .all(|(k, c)| k.eq_ignore_ascii_case(c))
+        {
+            longest = Some((value, len));
+        }
+    }
+    longest
+}
+
+/// Returns true if `chars` are the beginning of a longer key of `mapping`, compared ignoring
+/// ASCII case.
+pub(crate) fn is_custom_mapping_prefix(mapping: &CustomMapping, chars: &[char]) -> bool {
+    mapping.keys().any(|key| {
+        key.chars().count() > chars.len()
+            && key
+                .chars()
+                .zip(chars)
+                .all(|(k, c)| k.eq_ignore_ascii_case(c))
+    })
+}
+
+/// Returns `mapping` with its kana keys converted to hiragana, to match kana which have been
+/// converted to hiragana before the lookup.
+pub(crate) fn to_hiragana_keys(mapping: &CustomMapping) -> CustomMapping {
+    mapping
+        .iter()
+        .map(|(key, value)| (katakana_to_hiragana(key), value.clone()))
+        .collect()
+}
+
+/// Returns the mapping of `pairs`, to set up the custom mappings in tests
+#[cfg(test)]
+pub(crate) fn mapping(pairs: &[(&str, &str)]) -> CustomMapping {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn find_custom_mapping_test() {
+    let mapping = mapping(&[("ts", "つ"), ("tsi", "つぃ")]);
+    let chars = |s: &str| s.chars().collect::<Vec<_>>();
+    assert_eq!(
+        find_custom_mapping(&mapping, &chars("tsia")),
+        Some(("つぃ", 3))
+    );
+    assert_eq!(
+        find_custom_mapping(&mapping, &chars("TSu")),
+        Some(("つ", 2))
+    );
+    assert_eq!(find_custom_mapping(&mapping, &chars("t")), None);
+    assert_eq!(
+        find_custom_mapping(&CustomMapping::new(), &chars("tsi")),
+        None
+    );
+    assert!(is_custom_mapping_prefix(&mapping, &chars("Ts")));
+    assert!(!is_custom_mapping_prefix(&mapping, &chars("tsi")));
+    assert!(!is_custom_mapping_prefix(&mapping, &chars("tu")));
+}
+
+#[test]
+fn to_hiragana_keys_test() {
+    let mapping = mapping(&[("ヴ", "v"), ("じ", "zi")]);
+    let keys = to_hiragana_keys(&mapping);
+    assert_eq!(keys.get("ゔ").map(String::as_str), Some("v"));
+    assert_eq!(keys.get("じ").map(String::as_str), Some("zi"));
+}
@@ -0,0 +1,220 @@
+//! Convert half-width (hankaku) [Katakana](https://en.wikipedia.org/wiki/Katakana) to full-width
+//! (zenkaku) Katakana
+//!
+//! The voiced sound marks ﾞ and ﾟ are separate chars in half-width, they are combined with the
+//! kana before them, like the combining and spacing marks after any kana. Passes through any
+//! other chars.
+//!
+//! # Examples
+//!
+//! hankaku_katakana_to_zenkaku('ｶﾞｲｺｸｺﾞ')
+//!
+//! // => "ガイコクゴ"
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use core::str::CharIndices;
+
+use crate::utils::voiced_sound_marks::{add_voiced_mark, can_take_voiced_mark};
+
+/// The full-width chars of U+FF61 (｡) to U+FF9F (ﾟ)
+pub(crate) static ZENKAKU: [char; 63] = [
+    '。', '「', '」', '、', '・', 'ヲ', 'ァ', 'ィ', 'ゥ', 'ェ', 'ォ', 'ャ', 'ュ', 'ョ', 'ッ', 'ー',
+    'ア', 'イ', 'ウ', 'エ', 'オ', 'カ', 'キ', 'ク', 'ケ', 'コ', 'サ', 'シ', 'ス', 'セ', 'ソ', 'タ',
+    'チ', 'ツ', 'テ', 'ト', 'ナ', 'ニ', 'ヌ', 'ネ', 'ノ', 'ハ', 'ヒ', 'フ', 'ヘ', 'ホ', 'マ', 'ミ',
+    'ム', 'メ', 'モ', 'ヤ', 'ユ', 'ヨ', 'ラ', 'リ', 'ル', 'レ', 'ロ', 'ワ', 'ン', '゛', '゜',
+];
+
+pub(crate) const HANKAKU_START: u32 = 0xFF61;
+pub(crate) const HANKAKU_VOICED_MARK: char = 'ﾞ';
+pub(crate) const HANKAKU_SEMI_VOICED_MARK: char = 'ﾟ';
+
+/// Returns the full-width char of a half-width katakana or kana punctuation
+pub(crate) fn hankaku_to_zenkaku(char: char) -> Option<char> {
+    let index = (char as u32).checked_sub(HANKAKU_START)?;
+    ZENKAKU.get(index as usize).copied()
+}
+
+/// Returns the kana `kana` and the next char `mark` are written as. The half-width marks only
+/// follow half-width kana.
+fn combine(kana: char, is_hankaku: bool, mark: char) -> Option<char> {
+    let is_hankaku_mark = mark == HANKAKU_VOICED_MARK || mark == HANKAKU_SEMI_VOICED_MARK;
+    if is_hankaku_mark && !is_hankaku {
+        return None;
+    }
+    add_voiced_mark(kana, mark)
+}
+
+pub fn hankaku_katakana_to_zenkaku(input: &str) -> String {
+    zenkaku_char_indices(input).map(|(_, char)| char).collect()
+}
+
+/// Returns false if `input` has no half-width katakana and no voiced sound marks. The former all
+/// start with the byte 0xEF in UTF-8, the latter (U+3099 to U+309C) end with 0x82 0x99 to 0x82
+/// 0x9C, which is quicker to search than decoding the chars.
+pub(crate) fn may_need_zenkaku(input: &str) -> bool {
+    let bytes = input.as_bytes();
+    bytes.contains(&0xEF)
+        || bytes
+            .windows(2)
+            .any(|pair| pair[0] == 0x82 && (0x99..=0x9C).contains(&pair[1]))
+}
+
+/// Converts like hankaku_katakana_to_zenkaku(), borrowing `input` if it has no half-width chars
+/// and no voiced sound marks
+pub(crate) fn zenkaku_katakana_cow(input: &str) -> Cow<'_, str> {
+    if may_need_zenkaku(input) {
+        Cow::Owned(hankaku_katakana_to_zenkaku(input))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+/// Returns the chars of `input` like `char_indices()`, with half-width katakana replaced by
+/// full-width ones. A kana and its voiced sound mark are one char at the index of the kana.
+pub(crate) fn zenkaku_char_indices(input: &str) -> ZenkakuCharIndices<'_> {
+    ZenkakuCharIndices {
+        chars: input.char_indices(),
+        widen: true,
+    }
+}
+
+/// Returns the chars of `input` like `char_indices()`, with kana and their combining or spacing
+/// voiced sound mark as one char at the index of the kana. Half-width katakana are kept.
+pub(crate) fn composed_char_indices(input: &str) -> ZenkakuCharIndices<'_> {
+    ZenkakuCharIndices {
+        chars: input.char_indices(),
+        widen: false,
+    }
+}
+
+/// Iterator created by [`zenkaku_char_indices`] and [`composed_char_indices`]
+#[derive(Debug, Clone)]
+pub(crate) struct ZenkakuCharIndices<'a> {
+    chars: CharIndices<'a>,
+    /// Replace half-width katakana
+    widen: bool,
+}
+
+impl Iterator for ZenkakuCharIndices<'_> {
+    type Item = (usize, char);
+
+    fn next(&mut self) -> Option<(usize, char)> {
+        let (index, char) = self.chars.next()?;
+        let (kana, is_hankaku) = match hankaku_to_zenkaku(char).filter(|_| self.widen) {
+            Some(zenkaku) => (zenkaku, true),
+            None if can_take_voiced_mark(char) => (char, false),
+            None => return Some((index, char)),
+        };
+        let mut rest = self.chars.clone();
+        let voiced = rest
+            .next()
+            .and_then(|(_, mark)| combine(kana, is_hankaku, mark));
+        if voiced.is_some() {
+            self.chars = rest;
+        }
+        Some((index, voiced.unwrap_or(kana)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every second char may be a voiced sound mark
+        let (lower, upper) = self.chars.size_hint();
+        (lower.div_ceil(2), upper)
+    }
+}
+
+/// Converts half-width katakana pushed one char at a time, like zenkaku_char_indices(). A kana
+/// is held back until the next char shows if it is a voiced sound mark.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct HankakuKatakanaToZenkaku {
+    /// The full-width kana and whether it was half-width
+    kana: Option<(char, bool)>,
+}
+
+impl HankakuKatakanaToZenkaku {
+    /// Adds the next char of the input, returns the chars which are complete
+    pub(crate) fn push(&mut self, char: char) -> impl Iterator<Item = char> {
+        let held = self.kana.take();
+        if let Some(voiced) = held.and_then(|(kana, is_hankaku)| combine(kana, is_hankaku, char)) {
+            return Some(voiced).into_iter().chain(None);
+        }
+        let complete = match hankaku_to_zenkaku(char) {
+            Some(zenkaku) => {
+                self.kana = Some((zenkaku, true));
+                None
+            }
+            None if can_take_voiced_mark(char) => {
+                self.kana = Some((char, false));
+                None
+            }
+            None => Some(char),
+        };
+        held.map(|(kana, _)| kana).into_iter().chain(complete)
+    }
+
+    /// Returns the held back kana at the end of the input
+    pub(crate) fn finish(&mut self) -> Option<char> {
+        self.kana.take().map(|(kana, _)| kana)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hankaku_katakana_to_zenkaku_test() {
+        assert_eq!(hankaku_katakana_to_zenkaku("ｶﾀｶﾅ"), "カタカナ");
+        assert_eq!(hankaku_katakana_to_zenkaku("ｶﾞｲｺｸｺﾞ"), "ガイコクゴ");
+        assert_eq!(hankaku_katakana_to_zenkaku("ﾊﾟﾋﾞﾌﾟｳﾞｧﾜﾞｦﾞ"), "パビプヴァヷヺ");
+        assert_eq!(
+            hankaku_katakana_to_zenkaku("｢ｽｰﾊﾟｰ｣､ｱ･ｲ｡"),
+            "「スーパー」、ア・イ。"
+        );
+        assert_eq!(hankaku_katakana_to_zenkaku("ﾞｱﾞﾏﾟ"), "゛ア゛マ゜");
+        assert_eq!(hankaku_katakana_to_zenkaku("カﾞ ｶ ﾞ"), "カ゛ カ ゛");
+        assert_eq!(hankaku_katakana_to_zenkaku("abc かな"), "abc かな");
+    }
+
+    #[test]
+    fn every_hankaku_char_has_a_zenkaku_char() {
+        let hankaku = (0xFF61..=0xFF9F).filter_map(core::char::from_u32);
+        assert!(hankaku.map(hankaku_to_zenkaku).all(|char| char.is_some()));
+        assert_eq!(hankaku_to_zenkaku('ｦ'), Some('ヲ'));
+        assert_eq!(hankaku_to_zenkaku('ﾝ'), Some('ン'));
+        assert_eq!(hankaku_to_zenkaku('ﾠ'), None);
+        assert_eq!(hankaku_to_zenkaku('ア'), None);
+    }
+
+    #[test]
+    fn keeps_the_index_of_the_kana() {
+        let indices = zenkaku_char_indices("aｶﾞｷ").collect::<Vec<_>>();
+        assert_eq!(indices, [(0, 'a'), (1, 'ガ'), (7, 'キ')]);
+    }
+
+    #[test]
+    fn combines_voiced_sound_marks_after_any_kana() {
+        assert_eq!(
+            hankaku_katakana_to_zenkaku("ｶ\u{3099}か\u{3099}は゜"),
+            "ガがぱ"
+        );
+        assert_eq!(
+            composed_char_indices("ｶﾞか\u{3099}ﾊ").collect::<Vec<_>>(),
+            [(0, 'ｶ'), (3, 'ﾞ'), (6, 'が'), (12, 'ﾊ')]
+        );
+        assert_eq!(may_need_zenkaku("かな漢字"), false);
+        assert_eq!(may_need_zenkaku("か\u{3099}"), true);
+        assert_eq!(may_need_zenkaku("ｶ"), true);
+    }
+
+    #[test]
+    fn converts_pushed_chars() {
+        let mut converter = HankakuKatakanaToZenkaku::default();
+        let mut zenkaku = String::new();
+        for char in "ｶﾞｷaﾊﾟﾎか\u{3099}カﾞは".chars() {
+            zenkaku.extend(converter.push(char));
+        }
+        zenkaku.extend(converter.finish());
+        assert_eq!(zenkaku, "ガキaパホがカ゛は");
+    }
+}
@@ -1,3 +1,5 @@
+use alloc::string::String;
+
 use crate::constants::{HIRAGANA_START, KATAKANA_START};
 use crate::utils::is_char_hiragana::*;
 use crate::utils::is_char_long_dash::*;
@@ -19,7 +21,13 @@ use crate::utils::is_char_slash_dot::*;
 /// // => "ヒラガナ is a type of kana"
 
 pub fn hiragana_to_katakana(input: &str) -> String {
-    let mut kata = vec![];
+    let mut kata = String::with_capacity(input.len());
+    hiragana_to_katakana_into(input, &mut kata);
+    kata
+}
+
+/// Convert [Hiragana](https://en.wikipedia.org/wiki/Hiragana) to [Katakana](https://en.wikipedia.org/wiki/Katakana), appending to `kata`
+pub(crate) fn hiragana_to_katakana_into(input: &str, kata: &mut String) {
     for char in input.chars() {
         // Short circuit to avoid incorrect codeshift for 'ー' and '・'
         if is_char_long_dash(char) || is_char_slash_dot(char) {
@@ -28,12 +36,11 @@ pub fn hiragana_to_katakana(input: &str) -> String {
             // Shift charcode.
             let code = char as i32 + (KATAKANA_START as i32 - HIRAGANA_START as i32);
             // let kata_char = String.from_char_code(code);
-            let kata_char = std::char::from_u32(code as u32).unwrap();
+            let kata_char = core::char::from_u32(code as u32).unwrap();
             kata.push(kata_char);
         } else {
             // Pass non-hiragana chars through
             kata.push(char);
         }
     }
-    kata.into_iter().collect()
 }
@@ -12,9 +12,10 @@
 //!
 //! // => "かたかな is a type of kana"
 
-use fnv::FnvHashMap;
+use alloc::string::String;
 
 use crate::constants::{HIRAGANA_START, KATAKANA_START};
+use crate::options::LongOKana;
 use crate::to_romaji::TO_ROMAJI_NODE_TREE;
 use crate::utils::is_char_katakana::*;
 use crate::utils::is_char_long_dash::*;
@@ -29,15 +30,24 @@ pub fn is_char_inner_long_dash(char: char, index: usize) -> bool {
 pub fn is_kana_as_symbol(char: char) -> bool {
     'ヶ' == char || 'ヵ' == char
 }
+/// ヷ, ヸ, ヹ and ヺ have no hiragana, they are kept as they are
+pub fn is_katakana_without_hiragana(char: char) -> bool {
+    ('ヷ'..='ヺ').contains(&char)
+}
 
-lazy_static! {
-    pub static ref LONG_VOWELS: FnvHashMap<char, char> = hashmap! {
-        'a' => 'あ',
-        'i' => 'い',
-        'u' => 'う',
-        'e' => 'え',
-        'o' => 'う',
-    };
+/// Returns the hiragana which lengthens `vowel`, `long_o_kana` is used after o
+pub(crate) fn long_vowel_kana(vowel: char, long_o_kana: LongOKana) -> Option<char> {
+    match vowel {
+        'a' => Some('あ'),
+        'i' => Some('い'),
+        'u' => Some('う'),
+        'e' => Some('え'),
+        'o' => match long_o_kana {
+            LongOKana::Ou => Some('う'),
+            LongOKana::Oo => Some('お'),
+        },
+        _ => None,
+    }
 }
 
 pub fn katakana_to_hiragana(input: &str) -> String {
@@ -45,52 +55,108 @@ pub fn katakana_to_hiragana(input: &str) -> String {
 }
 
 pub(crate) fn katakana_to_hiragana_with_opt(input: &str, is_destination_romaji: bool) -> String {
-    let mut hira = Vec::with_capacity(input.chars().count());
-    let mut previous_kana: Option<char> = None;
-    for (index, char) in input.chars().enumerate() {
+    let mut hira = String::with_capacity(input.len());
+    katakana_to_hiragana_into(input, is_destination_romaji, &mut hira);
+    hira
+}
+
+/// Converts katakana to hiragana like katakana_to_hiragana_with_opt(), appending to `hira`
+pub(crate) fn katakana_to_hiragana_into(
+    input: &str,
+    is_destination_romaji: bool,
+    hira: &mut String,
+) {
+    let mut converter = KatakanaToHiragana::new(is_destination_romaji);
+    hira.extend(input.chars().map(|char| converter.convert(char)));
+}
+
+/// Converts katakana to hiragana one char at a time, each char of the input results in one char.
+#[derive(Debug, Clone)]
+pub(crate) struct KatakanaToHiragana {
+    is_destination_romaji: bool,
+    index: usize,
+    previous_char: Option<char>,
+    previous_kana: Option<char>,
+}
+
+impl KatakanaToHiragana {
+    pub(crate) fn new(is_destination_romaji: bool) -> Self {
+        KatakanaToHiragana {
+            is_destination_romaji,
+            index: 0,
+            previous_char: None,
+            previous_kana: None,
+        }
+    }
+
+    /// Converts the next char of the input
+    pub(crate) fn convert(&mut self, char: char) -> char {
+        let hira_char = self.convert_char(char);
+        self.index += 1;
+        self.previous_char = Some(char);
+        hira_char
+    }
+
+    fn convert_char(&mut self, char: char) -> char {
+        let index = self.index;
         // Short circuit to avoid incorrect codeshift for 'ー' and '・'
         if is_char_slash_dot(char)
             || is_char_initial_long_dash(char, index)
             || is_kana_as_symbol(char)
         {
-            hira.push(char);
+            char
         // Transform long vowels: 'オー' to 'おう'
         } else if let (Some(previous_kana), true) =
-            (previous_kana, is_char_inner_long_dash(char, index))
+            (self.previous_kana, is_char_inner_long_dash(char, index))
         {
             // Transform previous_kana back to romaji, and slice off the vowel
-            let romaji = TO_ROMAJI_NODE_TREE
+            let vowel = TO_ROMAJI_NODE_TREE
                 .find_transition_node(previous_kana)
-                .unwrap()
-                .output;
-
-            let romaji = romaji.chars().last().unwrap_or_else(|| {
-                panic!("could not find kana {:?} in TO_ROMAJI map", previous_kana)
-            });
+                .and_then(|node| node.output().chars().last());
+            let vowel = match vowel {
+                Some(vowel) => vowel,
+                // Keep the dash if the kana has no vowel (ンー, ッー)
+                None => return char,
+            };
             // However, ensure 'オー' => 'おお' => 'oo' if this is a transform on the way to romaji
-            if let Some(prev_char) = input.chars().nth(index - 1) {
-                if is_char_katakana(prev_char) && romaji == 'o' && is_destination_romaji {
-                    hira.push('お');
-                    continue;
+            if let Some(prev_char) = self.previous_char {
+                if is_char_katakana(prev_char) && vowel == 'o' && self.is_destination_romaji {
+                    return 'お';
                 }
             }
 
-            if let Some(hit) = LONG_VOWELS.get(&romaji) {
-                hira.push(*hit);
-            }
+            long_vowel_kana(vowel, LongOKana::Ou).unwrap_or(char)
+        } else if is_katakana_without_hiragana(char) {
+            self.previous_kana = Some(char);
+            char
         } else if !is_char_long_dash(char) && is_char_katakana(char) {
             // Shift charcode.
             let code = char as i32 + (HIRAGANA_START as i32 - KATAKANA_START as i32);
-            let hira_char = std::char::from_u32(code as u32).unwrap();
-            hira.push(hira_char);
-            previous_kana = Some(hira_char);
+            let hira_char = core::char::from_u32(code as u32).unwrap_or(char);
+            self.previous_kana = Some(hira_char);
+            hira_char
         } else {
             // Pass non katakana chars through
-            hira.push(char);
-            previous_kana = None;
+            self.previous_kana = None;
+            char
+        }
+    }
+}
+
+/// Shifts katakana to hiragana one to one, keeping 'ー' instead of transforming long vowels.
+pub(crate) fn katakana_to_hiragana_keep_long_dash(input: &str) -> String {
+    input.chars().map(shift_katakana).collect()
+}
+
+/// Shifts a katakana char to hiragana, passing any other char through
+pub(crate) fn shift_katakana(char: char) -> char {
+    match char {
+        'ァ'..='ヴ' => {
+            let code = char as u32 - KATAKANA_START + HIRAGANA_START;
+            core::char::from_u32(code).unwrap_or(char)
         }
+        _ => char,
     }
-    hira.into_iter().collect()
 }
 
 #[test]
@@ -101,3 +167,19 @@ fn test_katakana_to_hiragana() {
         "かたかな is a type of kana"
     );
 }
+
+#[test]
+fn test_katakana_to_hiragana_long_dash_after_any_kana() {
+    assert_eq!(katakana_to_hiragana("スーパー"), "すうぱあ");
+    assert_eq!(katakana_to_hiragana("ンー"), "んー");
+    assert_eq!(katakana_to_hiragana("ッー"), "っー");
+    assert_eq!(katakana_to_hiragana("ヷーヺー"), "ヷあヺう");
+    assert_eq!(katakana_to_hiragana("ァー゠ー"), "ぁあ゠ー");
+    assert_eq!(katakana_to_hiragana("ーー"), "ーー");
+}
+
+#[test]
+fn test_katakana_to_hiragana_keep_long_dash() {
+    assert_eq!(katakana_to_hiragana_keep_long_dash("スーパー"), "すーぱー");
+    assert_eq!(katakana_to_hiragana_keep_long_dash("ヶ・ヴ"), "ヶ・ゔ");
+}
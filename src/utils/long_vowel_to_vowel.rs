@@ -0,0 +1,20 @@
+/// Returns the plain lowercase vowel of a vowel with macron or circumflex, like 'ō' or 'Ô'
+pub fn long_vowel_to_vowel(char: char) -> Option<char> {
+    match char {
+        'ā' | 'Ā' | 'â' | 'Â' => Some('a'),
+        'ī' | 'Ī' | 'î' | 'Î' => Some('i'),
+        'ū' | 'Ū' | 'û' | 'Û' => Some('u'),
+        'ē' | 'Ē' | 'ê' | 'Ê' => Some('e'),
+        'ō' | 'Ō' | 'ô' | 'Ô' => Some('o'),
+        _ => None,
+    }
+}
+
+#[test]
+fn long_vowel_to_vowel_test() {
+    assert_eq!(long_vowel_to_vowel('ō'), Some('o'));
+    assert_eq!(long_vowel_to_vowel('Ā'), Some('a'));
+    assert_eq!(long_vowel_to_vowel('ê'), Some('e'));
+    assert_eq!(long_vowel_to_vowel('o'), None);
+    assert_eq!(long_vowel_to_vowel('ー'), None);
+}
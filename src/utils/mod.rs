@@ -1,6 +1,11 @@
+#[cfg(feature = "std")]
 #[macro_use]
 pub(crate) mod hashmap_macro;
+pub(crate) mod custom_mapping;
+pub(crate) mod utf8_len;
+pub(crate) mod utf8_ranges;
 pub mod get_chunk;
+pub mod hankaku_katakana_to_zenkaku;
 pub mod hiragana_to_katakana;
 pub mod is_char_consonant;
 pub mod is_char_english_punctuation;
@@ -20,9 +25,14 @@ pub mod is_char_slash_dot;
 pub mod is_char_upper_case;
 pub mod is_char_vowel;
 pub mod katakana_to_hiragana;
+pub mod long_vowel_to_vowel;
 pub mod romaji_to_hiragana;
+pub mod voiced_sound_marks;
+pub mod zenkaku_katakana_to_hankaku;
 
 pub use get_chunk::*;
+pub use hankaku_katakana_to_zenkaku::*;
+#[cfg(feature = "std")]
 pub use hashmap_macro::*;
 pub use hiragana_to_katakana::*;
 pub use is_char_consonant::*;
@@ -43,7 +53,10 @@ pub use is_char_slash_dot::*;
 pub use is_char_upper_case::*;
 pub use is_char_vowel::*;
 pub use katakana_to_hiragana::*;
+pub use long_vowel_to_vowel::*;
 pub use romaji_to_hiragana::*;
+pub use voiced_sound_marks::*;
+pub use zenkaku_katakana_to_hankaku::*;
 
 #[cfg(test)]
 mod tests {
@@ -1,4 +1,6 @@
-use crate::options::Options;
+use alloc::string::String;
+
+use crate::options::{ImeMode, Options};
 use crate::to_kana::*;
 
 /// Convert [Romaji](https://en.wikipedia.org/wiki/Romaji) to [Hiragana](https://en.wikipedia.org/wiki/Hiragana)
@@ -7,5 +9,9 @@ use crate::to_kana::*;
 /// => "ひらがな"
 pub fn romaji_to_hiragana(input: &str, options: Options) -> String {
     let text = input.to_lowercase(); // ensure hiragana
-    to_kana_with_opt(&text, options)
+    let imemode = match options.imemode {
+        ImeMode::ToKatakana => ImeMode::ToHiragana,
+        imemode => imemode,
+    };
+    to_kana_with_opt(&text, Options { imemode, ..options })
 }
@@ -0,0 +1,10 @@
+/// Returns the number of bytes of `chars` encoded as UTF-8
+pub(crate) fn utf8_len(chars: &[char]) -> usize {
+    chars.iter().map(|char| char.len_utf8()).sum()
+}
+
+#[test]
+fn utf8_len_test() {
+    assert_eq!(utf8_len(&[]), 0);
+    assert_eq!(utf8_len(&['a', 'ō', 'あ']), 6);
+}
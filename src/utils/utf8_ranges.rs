@@ -0,0 +1,65 @@
+//! Range checks on the UTF-8 bytes of a str, without decoding it char by char.
+//!
+//! All kana, kanji and zenkaku ranges are encoded with 3 bytes, so text made of them can be
+//! checked in fixed size steps. The checks don't branch per char, only per block of chars.
+
+/// Number of bytes checked before deciding whether to go on, a multiple of 3
+const BLOCK_LEN: usize = 24;
+
+/// Returns true if `code` is in any of `ranges`. Unlike `ranges.iter().any()` every range is
+/// checked without branching.
+#[inline]
+pub(crate) fn is_code_in_ranges(code: u32, ranges: &[[u32; 2]]) -> bool {
+    ranges.iter().fold(false, |is_in_range, range| {
+        is_in_range | ((range[0] <= code) & (code <= range[1]))
+    })
+}
+
+/// Returns true if every char of `input` is encoded with 3 bytes and `is_code_in_range` holds
+/// for its code point. Returns false for other input, including the empty str.
+#[inline]
+pub(crate) fn are_all_3_byte_chars_in(input: &str, is_code_in_range: impl Fn(u32) -> bool) -> bool {
+    let bytes = input.as_bytes();
+    // Valid UTF-8, so if the first byte of every 3 starts a 3 byte char the steps stay aligned
+    !bytes.is_empty()
+        && bytes.chunks_exact(3).remainder().is_empty()
+        && bytes.chunks(BLOCK_LEN).all(|block| {
+            // Stops right away for text starting with ASCII
+            block[0] & 0xF0 == 0xE0
+                && block.chunks_exact(3).fold(true, |all_in_range, char| {
+                    let is_3_byte_char = char[0] & 0xF0 == 0xE0;
+                    let code = (char[0] as u32 & 0x0F) << 12
+                        | (char[1] as u32 & 0x3F) << 6
+                        | (char[2] as u32 & 0x3F);
+                    all_in_range & is_3_byte_char & is_code_in_range(code)
+                })
+        })
+}
+
+#[test]
+fn is_code_in_ranges_test() {
+    let ranges = [[0x3041, 0x3096], [0x30A1, 0x30FC]];
+    assert_eq!(is_code_in_ranges('あ' as u32, &ranges), true);
+    assert_eq!(is_code_in_ranges('ア' as u32, &ranges), true);
+    assert_eq!(is_code_in_ranges('a' as u32, &ranges), false);
+    assert_eq!(is_code_in_ranges('a' as u32, &[]), false);
+}
+
+#[test]
+fn are_all_3_byte_chars_in_test() {
+    let is_hiragana = |code| (0x3041..=0x3096).contains(&code);
+    assert_eq!(are_all_3_byte_chars_in("あいう", is_hiragana), true);
+    assert_eq!(
+        are_all_3_byte_chars_in(&"あ".repeat(100), is_hiragana),
+        true
+    );
+    assert_eq!(are_all_3_byte_chars_in("", is_hiragana), false);
+    assert_eq!(are_all_3_byte_chars_in("あア", is_hiragana), false);
+    // Same length as one 3 byte char
+    assert_eq!(are_all_3_byte_chars_in("abc", |_| true), false);
+    assert_eq!(are_all_3_byte_chars_in("aō", |_| true), false);
+    assert_eq!(are_all_3_byte_chars_in("ōōō", |_| true), false);
+    assert_eq!(are_all_3_byte_chars_in("😀ab", |_| true), false);
+    let after_block = format!("{}ア", "あ".repeat(16));
+    assert_eq!(are_all_3_byte_chars_in(&after_block, is_hiragana), false);
+}
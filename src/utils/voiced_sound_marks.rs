@@ -0,0 +1,193 @@
+//! Compose and decompose the voiced sound marks (dakuten ゛ and handakuten ゜) of
+//! [Kana](https://en.wikipedia.org/wiki/Kana)
+//!
+//! Text in Unicode NFD, like file names on macOS, writes が as か followed by the combining mark
+//! U+3099. The spacing marks ゛ and ゜ are composed like the combining ones.
+//!
+//! # Examples
+//!
+//! compose_kana('か\u{3099}は\u{309A}')
+//!
+//! // => "がぱ"
+//!
+//! decompose_kana('がぱ')
+//!
+//! // => "か\u{3099}は\u{309A}"
+
+use alloc::string::String;
+
+use crate::constants::{HIRAGANA_START, KATAKANA_START};
+use crate::utils::hankaku_katakana_to_zenkaku::{HANKAKU_SEMI_VOICED_MARK, HANKAKU_VOICED_MARK};
+
+pub(crate) const COMBINING_VOICED_MARK: char = '\u{3099}';
+pub(crate) const COMBINING_SEMI_VOICED_MARK: char = '\u{309A}';
+pub(crate) const VOICED_MARK: char = '゛';
+pub(crate) const SEMI_VOICED_MARK: char = '゜';
+
+/// Returns true if `char` is a combining or spacing voiced sound mark (U+3099 to U+309C)
+pub fn is_char_voiced_sound_mark(char: char) -> bool {
+    (COMBINING_VOICED_MARK..=SEMI_VOICED_MARK).contains(&char)
+}
+
+/// Returns the kana `kana` + `mark` are written as. `mark` may be a combining, spacing or
+/// half-width voiced sound mark.
+pub(crate) fn add_voiced_mark(kana: char, mark: char) -> Option<char> {
+    let is_semi_voiced = match mark {
+        COMBINING_VOICED_MARK | VOICED_MARK | HANKAKU_VOICED_MARK => false,
+        COMBINING_SEMI_VOICED_MARK | SEMI_VOICED_MARK | HANKAKU_SEMI_VOICED_MARK => true,
+        _ => return None,
+    };
+    // Hiragana are looked up as the katakana at the same place of their block
+    let shift = KATAKANA_START - HIRAGANA_START;
+    let is_hiragana = ('ぁ'..='ゖ').contains(&kana) || kana == 'ゝ';
+    let katakana = if is_hiragana {
+        core::char::from_u32(kana as u32 + shift)?
+    } else {
+        kana
+    };
+    let offset = match (is_semi_voiced, katakana) {
+        (false, 'ウ') => 'ヴ' as u32 - 'ウ' as u32,
+        (false, 'ワ' | 'ヰ' | 'ヱ' | 'ヲ') => 'ヷ' as u32 - 'ワ' as u32,
+        // The voiced kana follow these, the semi-voiced ones follow those of ハ to ホ
+        (false, 'カ' | 'キ' | 'ク' | 'ケ' | 'コ')
+        | (false, 'サ' | 'シ' | 'ス' | 'セ' | 'ソ')
+        | (false, 'タ' | 'チ' | 'ツ' | 'テ' | 'ト')
+        | (false, 'ハ' | 'ヒ' | 'フ' | 'ヘ' | 'ホ')
+        | (false, 'ヽ') => 1,
+        (true, 'ハ' | 'ヒ' | 'フ' | 'ヘ' | 'ホ') => 2,
+        _ => return None,
+    };
+    let voiced = core::char::from_u32(katakana as u32 + offset)?;
+    if !is_hiragana {
+        return Some(voiced);
+    }
+    // ヷ, ヸ, ヹ and ヺ have no hiragana
+    core::char::from_u32(voiced as u32 - shift)
+        .filter(|&hiragana| ('ぁ'..='ゖ').contains(&hiragana) || hiragana == 'ゞ')
+}
+
+/// Returns the kana `char` is written with and true if its mark is semi-voiced, if `char` is a
+/// kana with voiced sound mark
+pub(crate) fn remove_voiced_mark(char: char) -> Option<(char, bool)> {
+    if !('ぁ'..='ヾ').contains(&char) {
+        return None;
+    }
+    let code = char as u32;
+    let unvoiced = [
+        (code - 1, false),
+        (code - 2, true),
+        (code - ('ヴ' as u32 - 'ウ' as u32), false),
+        (code - ('ヷ' as u32 - 'ワ' as u32), false),
+    ];
+    unvoiced.iter().find_map(|&(kana, is_semi_voiced)| {
+        let kana = core::char::from_u32(kana)?;
+        let mark = if is_semi_voiced {
+            COMBINING_SEMI_VOICED_MARK
+        } else {
+            COMBINING_VOICED_MARK
+        };
+        (add_voiced_mark(kana, mark) == Some(char)).then_some((kana, is_semi_voiced))
+    })
+}
+
+/// Returns true if `char` is a kana which may be followed by a voiced sound mark
+pub(crate) fn can_take_voiced_mark(char: char) -> bool {
+    ('ぁ'..='ヽ').contains(&char)
+        && (add_voiced_mark(char, COMBINING_VOICED_MARK).is_some()
+            || add_voiced_mark(char, COMBINING_SEMI_VOICED_MARK).is_some())
+}
+
+/// Returns true if `is_char_kana` holds for every char of `input`, but for voiced sound marks
+/// which follow such a char
+pub(crate) fn all_kana_with_marks(input: &str, is_char_kana: impl Fn(char) -> bool) -> bool {
+    let mut is_after_kana = false;
+    input.chars().all(|char| {
+        is_after_kana = if is_char_voiced_sound_mark(char) {
+            is_after_kana
+        } else {
+            is_char_kana(char)
+        };
+        is_after_kana
+    })
+}
+
+/// Combines kana followed by a combining or spacing voiced sound mark into one char. Other chars
+/// are passed through.
+pub fn compose_kana(input: &str) -> String {
+    let mut composed = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    while let Some(char) = chars.next() {
+        let voiced = chars
+            .peek()
+            .filter(|&&mark| is_char_voiced_sound_mark(mark))
+            .and_then(|&mark| add_voiced_mark(char, mark));
+        if voiced.is_some() {
+            chars.next();
+        }
+        composed.push(voiced.unwrap_or(char));
+    }
+    composed
+}
+
+/// Splits kana with voiced sound mark into the kana and the combining mark (U+3099 or U+309A),
+/// as in Unicode NFD. Other chars are passed through.
+pub fn decompose_kana(input: &str) -> String {
+    let mut decomposed = String::with_capacity(input.len() + input.len() / 2);
+    for char in input.chars() {
+        match remove_voiced_mark(char) {
+            Some((kana, false)) => decomposed.extend([kana, COMBINING_VOICED_MARK]),
+            Some((kana, true)) => decomposed.extend([kana, COMBINING_SEMI_VOICED_MARK]),
+            None => decomposed.push(char),
+        }
+    }
+    decomposed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compose_kana_test() {
+        assert_eq!(compose_kana("か\u{3099}は\u{309A}"), "がぱ");
+        assert_eq!(
+            compose_kana("カ\u{3099}ハ\u{309A}ウ\u{3099}ワ\u{3099}"),
+            "ガパヴヷ"
+        );
+        assert_eq!(compose_kana("か゛は゜う゛ゝ゛"), "がぱゔゞ");
+        assert_eq!(
+            compose_kana("わ\u{3099}あ\u{3099}が\u{3099}"),
+            "わ\u{3099}あ\u{3099}が\u{3099}"
+        );
+        assert_eq!(compose_kana("ｶﾞ \u{3099}漢字"), "ｶﾞ \u{3099}漢字");
+    }
+
+    #[test]
+    fn decompose_kana_test() {
+        assert_eq!(decompose_kana("がぱ"), "か\u{3099}は\u{309A}");
+        assert_eq!(
+            decompose_kana("ヴヷヺゞ"),
+            "ウ\u{3099}ワ\u{3099}ヲ\u{3099}ゝ\u{3099}"
+        );
+        assert_eq!(decompose_kana("かな カナ 漢字"), "かな カナ 漢字");
+    }
+
+    #[test]
+    fn every_voiced_kana_round_trips() {
+        let voiced = ('ぁ'..='ヾ').filter(|&char| remove_voiced_mark(char).is_some());
+        // The K, S, T and H rows and the H row with ゜, ゔ and ヴ, ヷ to ヺ, ゞ and ヾ
+        assert_eq!(voiced.clone().count(), 2 * (20 + 5) + 2 + 4 + 2);
+        for char in voiced {
+            let decomposed = decompose_kana(&char.to_string());
+            assert_eq!(compose_kana(&decomposed), char.to_string());
+        }
+    }
+
+    #[test]
+    fn is_char_voiced_sound_mark_test() {
+        assert_eq!(is_char_voiced_sound_mark('\u{3099}'), true);
+        assert_eq!(is_char_voiced_sound_mark('゜'), true);
+        assert_eq!(is_char_voiced_sound_mark('ﾞ'), false);
+        assert_eq!(is_char_voiced_sound_mark('が'), false);
+    }
+}
@@ -0,0 +1,98 @@
+//! Convert full-width (zenkaku) [Katakana](https://en.wikipedia.org/wiki/Katakana) to half-width
+//! (hankaku) Katakana
+//!
+//! Voiced kana become the kana followed by ﾞ or ﾟ. Katakana without half-width form (ヮ, ヰ,
+//! ヱ, ヵ, ヶ, ヸ, ヹ), hiragana and any other chars are passed through.
+//!
+//! # Examples
+//!
+//! zenkaku_katakana_to_hankaku('ガイコクゴ')
+//!
+//! // => "ｶﾞｲｺｸｺﾞ"
+
+use alloc::string::String;
+
+use crate::utils::hankaku_katakana_to_zenkaku::{
+    composed_char_indices, HANKAKU_SEMI_VOICED_MARK, HANKAKU_START, HANKAKU_VOICED_MARK, ZENKAKU,
+};
+use crate::utils::voiced_sound_marks::remove_voiced_mark;
+
+/// Returns the half-width char of a full-width katakana or kana punctuation without voiced mark
+fn zenkaku_to_hankaku_char(char: char) -> Option<char> {
+    let index = ZENKAKU.iter().position(|&zenkaku| zenkaku == char)?;
+    core::char::from_u32(HANKAKU_START + index as u32)
+}
+
+/// Returns the half-width chars a full-width katakana or kana punctuation is written as: the
+/// kana and its voiced sound mark, if it has one
+pub(crate) fn zenkaku_to_hankaku(char: char) -> Option<(char, Option<char>)> {
+    // All the chars of ZENKAKU are in the CJK punctuation and katakana blocks
+    if !('\u{3000}'..='\u{30FF}').contains(&char) {
+        return None;
+    }
+    if let Some(hankaku) = zenkaku_to_hankaku_char(char) {
+        return Some((hankaku, None));
+    }
+    let (kana, is_semi_voiced) = remove_voiced_mark(char)?;
+    let mark = if is_semi_voiced {
+        HANKAKU_SEMI_VOICED_MARK
+    } else {
+        HANKAKU_VOICED_MARK
+    };
+    Some((zenkaku_to_hankaku_char(kana)?, Some(mark)))
+}
+
+pub fn zenkaku_katakana_to_hankaku(input: &str) -> String {
+    let mut hankaku = String::with_capacity(input.len());
+    zenkaku_katakana_to_hankaku_into(input, &mut hankaku);
+    hankaku
+}
+
+/// Convert full-width katakana to half-width katakana, appending to `hankaku`. Katakana followed
+/// by a combining voiced sound mark are converted like the composed katakana.
+pub(crate) fn zenkaku_katakana_to_hankaku_into(input: &str, hankaku: &mut String) {
+    for (_, char) in composed_char_indices(input) {
+        match zenkaku_to_hankaku(char) {
+            Some((kana, mark)) => {
+                hankaku.push(kana);
+                hankaku.extend(mark);
+            }
+            None => hankaku.push(char),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::hankaku_katakana_to_zenkaku::hankaku_katakana_to_zenkaku;
+
+    #[test]
+    fn zenkaku_katakana_to_hankaku_test() {
+        assert_eq!(zenkaku_katakana_to_hankaku("カタカナ"), "ｶﾀｶﾅ");
+        assert_eq!(zenkaku_katakana_to_hankaku("ガイコクゴ"), "ｶﾞｲｺｸｺﾞ");
+        assert_eq!(zenkaku_katakana_to_hankaku("パビプヴァヷヺ"), "ﾊﾟﾋﾞﾌﾟｳﾞｧﾜﾞｦﾞ");
+        assert_eq!(
+            zenkaku_katakana_to_hankaku("「スーパー」、ア・イ。"),
+            "｢ｽｰﾊﾟｰ｣､ｱ･ｲ｡"
+        );
+        assert_eq!(zenkaku_katakana_to_hankaku("ヵヶヰヱヮ"), "ヵヶヰヱヮ");
+        assert_eq!(
+            zenkaku_katakana_to_hankaku("カ\u{3099}ハ\u{309A}か\u{3099}"),
+            "ｶﾞﾊﾟが"
+        );
+        assert_eq!(
+            zenkaku_katakana_to_hankaku("abc かな　漢字"),
+            "abc かな　漢字"
+        );
+    }
+
+    #[test]
+    fn round_trips_every_katakana() {
+        let katakana = ('ァ'..='ヺ').chain(['ー', '。', '「', '」', '、', '・']);
+        for char in katakana {
+            let hankaku = zenkaku_katakana_to_hankaku(&char.to_string());
+            assert_eq!(hankaku_katakana_to_zenkaku(&hankaku), char.to_string());
+        }
+    }
+}
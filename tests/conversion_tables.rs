@@ -355,7 +355,7 @@ pub const ROMA_TO_HIRA_KATA: [[&str; 3]; 338] = [
 ];
 
 #[allow(dead_code)]
-pub const HIRA_KATA_TO_ROMA: [[&str; 3]; 192] = [
+pub const HIRA_KATA_TO_ROMA: [[&str; 3]; 199] = [
     // symbols that should all be the same after conversion
     ["ヶ", "ヶ", "ヶ"],
     ["ヵ", "ヵ", "ヵ"],
@@ -550,6 +550,14 @@ pub const HIRA_KATA_TO_ROMA: [[&str; 3]; 192] = [
     ["でんぽう", "", "denpou"],
     ["きんようび", "", "kin'youbi"],
     ["こうし", "", "koushi"],
+    // ゔ and its combinations
+    ["ゔ", "ヴ", "vu"],
+    ["ゔぁ", "ヴァ", "va"],
+    ["ゔぃ", "ヴィ", "vi"],
+    ["ゔぇ", "ヴェ", "ve"],
+    ["ゔぉ", "ヴォ", "vo"],
+    ["ゔゃ", "ヴャ", "vya"],
+    ["っゔぃ", "ッヴィ", "vvi"],
     // kata long vowels
     ["", "セーラー", "seeraa"],
     ["", "パーティー", "paateii"],
@@ -559,3 +567,504 @@ pub const HIRA_KATA_TO_ROMA: [[&str; 3]; 192] = [
     ["", "バレーボール", "bareebooru"],
     ["", "ソール", "sooru"],
 ];
+
+#[allow(dead_code)]
+pub const HIRA_KATA_TO_KUNREI: [[&str; 3]; 195] = [
+    // symbols that should all be the same after conversion
+    ["ヶ", "ヶ", "ヶ"],
+    ["ヵ", "ヵ", "ヵ"],
+    ["1", "1", "1"],
+    ["@", "@", "@"],
+    ["#", "#", "#"],
+    ["$", "$", "$"],
+    ["%", "%", "%"],
+    // quick brown fox / iroha
+    ["いろはにほへと", "イロハニホヘト", "irohanihoheto"],
+    ["ちりぬるを", "チリヌルヲ", "tirinuruo"],
+    ["わかよたれそ", "ワカヨタレソ", "wakayotareso"],
+    ["つねならむ", "ツネナラム", "tunenaramu"],
+    ["うゐのおくやま", "ウヰノオクヤマ", "uinookuyama"],
+    ["けふこえて", "ケフコエテ", "kehukoete"],
+    ["あさきゆめみし", "アサキユメミシ", "asakiyumemisi"],
+    ["ゑひもせすん", "ヱヒモセスン", "ehimosesun"],
+    // should all convert
+    ["！", "！", "!"],
+    ["？", "？", "?"],
+    ["。", "。", "."],
+    ["：", "：", ":"],
+    ["・", "・", "/"],
+    ["、", "、", ","],
+    ["〜", "〜", "~"],
+    ["ー", "ー", "-"],
+    ["「", "「", "‘"],
+    ["」", "」", "’"],
+    ["『", "『", "“"],
+    ["』", "』", "”"],
+    ["［", "［", "["],
+    ["］", "］", "]"],
+    ["（", "（", "("],
+    ["）", "）", ")"],
+    ["｛", "｛", "{"],
+    ["｝", "｝", "}"],
+    // kunrei-shiki
+    ["か", "カ", "ka"],
+    ["き", "キ", "ki"],
+    ["く", "ク", "ku"],
+    ["け", "ケ", "ke"],
+    ["こ", "コ", "ko"],
+    ["きゃ", "キャ", "kya"],
+    ["きゅ", "キュ", "kyu"],
+    ["きょ", "キョ", "kyo"],
+    ["さ", "サ", "sa"],
+    ["し", "シ", "si"],
+    ["す", "ス", "su"],
+    ["せ", "セ", "se"],
+    ["そ", "ソ", "so"],
+    ["しゃ", "シャ", "sya"],
+    ["しゅ", "シュ", "syu"],
+    ["しょ", "ショ", "syo"],
+    ["た", "タ", "ta"],
+    ["ち", "チ", "ti"],
+    ["つ", "ツ", "tu"],
+    ["て", "テ", "te"],
+    ["と", "ト", "to"],
+    ["ちゃ", "チャ", "tya"],
+    ["ちゅ", "チュ", "tyu"],
+    ["ちょ", "チョ", "tyo"],
+    ["な", "ナ", "na"],
+    ["に", "ニ", "ni"],
+    ["ぬ", "ヌ", "nu"],
+    ["ね", "ネ", "ne"],
+    ["の", "ノ", "no"],
+    ["にゃ", "ニャ", "nya"],
+    ["にゅ", "ニュ", "nyu"],
+    ["にょ", "ニョ", "nyo"],
+    ["は", "ハ", "ha"],
+    ["ひ", "ヒ", "hi"],
+    ["ふ", "フ", "hu"],
+    ["へ", "ヘ", "he"],
+    ["ほ", "ホ", "ho"],
+    ["ひゃ", "ヒャ", "hya"],
+    ["ひゅ", "ヒュ", "hyu"],
+    ["ひょ", "ヒョ", "hyo"],
+    ["ま", "マ", "ma"],
+    ["み", "ミ", "mi"],
+    ["む", "ム", "mu"],
+    ["め", "メ", "me"],
+    ["も", "モ", "mo"],
+    ["みゃ", "ミャ", "mya"],
+    ["みゅ", "ミュ", "myu"],
+    ["みょ", "ミョ", "myo"],
+    ["ら", "ラ", "ra"],
+    ["り", "リ", "ri"],
+    ["る", "ル", "ru"],
+    ["れ", "レ", "re"],
+    ["ろ", "ロ", "ro"],
+    ["りゃ", "リャ", "rya"],
+    ["りゅ", "リュ", "ryu"],
+    ["りょ", "リョ", "ryo"],
+    ["や", "ヤ", "ya"],
+    ["ゆ", "ユ", "yu"],
+    ["よ", "ヨ", "yo"],
+    ["わ", "ワ", "wa"],
+    ["ゐ", "ヰ", "i"],
+    ["ゑ", "ヱ", "e"],
+    ["を", "ヲ", "o"],
+    // dakuten
+    ["が", "ガ", "ga"],
+    ["ぎ", "ギ", "gi"],
+    ["ぐ", "グ", "gu"],
+    ["げ", "ゲ", "ge"],
+    ["ご", "ゴ", "go"],
+    ["ぎゃ", "ギャ", "gya"],
+    ["ぎゅ", "ギュ", "gyu"],
+    ["ぎょ", "ギョ", "gyo"],
+    ["ざ", "ザ", "za"],
+    ["じ", "ジ", "zi"],
+    ["ず", "ズ", "zu"],
+    ["ぜ", "ゼ", "ze"],
+    ["ぞ", "ゾ", "zo"],
+    ["じゃ", "ジャ", "zya"],
+    ["じゅ", "ジュ", "zyu"],
+    ["じょ", "ジョ", "zyo"],
+    ["だ", "ダ", "da"],
+    ["ぢ", "ヂ", "zi"],
+    ["づ", "ヅ", "zu"],
+    ["で", "デ", "de"],
+    ["ど", "ド", "do"],
+    ["ぢゃ", "ヂャ", "zya"],
+    ["ぢゅ", "ヂュ", "zyu"],
+    ["ぢょ", "ヂョ", "zyo"],
+    ["ば", "バ", "ba"],
+    ["び", "ビ", "bi"],
+    ["ぶ", "ブ", "bu"],
+    ["べ", "ベ", "be"],
+    ["ぼ", "ボ", "bo"],
+    ["びゃ", "ビャ", "bya"],
+    ["びゅ", "ビュ", "byu"],
+    ["びょ", "ビョ", "byo"],
+    ["ぱ", "パ", "pa"],
+    ["ぴ", "ピ", "pi"],
+    ["ぷ", "プ", "pu"],
+    ["ぺ", "ペ", "pe"],
+    ["ぽ", "ポ", "po"],
+    ["ぴゃ", "ピャ", "pya"],
+    ["ぴゅ", "ピュ", "pyu"],
+    ["ぴょ", "ピョ", "pyo"],
+    // little kana
+    ["ぁ", "ァ", "a"],
+    ["ぃ", "ィ", "i"],
+    ["ぅ", "ゥ", "u"],
+    ["ぇ", "ェ", "e"],
+    ["ぉ", "ォ", "o"],
+    ["っ", "ッ", ""],
+    ["ゃ", "ャ", "ya"],
+    ["ゅ", "ュ", "yu"],
+    ["ょ", "ョ", "yo"],
+    // n
+    ["ん", "ン", "n"],
+    ["んん", "ンン", "nn"],
+    ["あんない", "アンナイ", "annai"],
+    ["ぐんま", "グンマ", "gunma"],
+    // double consonants
+    ["あった", "アッタ", "atta"],
+    ["がっこうなかった", "ガッコウナカッタ", "gakkounakatta"],
+    ["けっか", "ケッカ", "kekka"],
+    ["さっさと", "サッサト", "sassato"],
+    ["ずっと", "ズット", "zutto"],
+    ["きっぷ", "キップ", "kippu"],
+    ["ざっし", "ザッシ", "zassi"],
+    ["いっしょ", "イッショ", "issyo"],
+    ["こっち", "コッチ", "kotti"],
+    ["まっちゃ", "マッチャ", "mattya"],
+    ["みっつ", "ミッツ", "mittu"],
+    ["ばっば", "バッバ", "babba"],
+    ["かっか", "カッカ", "kakka"],
+    ["ちゃっちゃ", "チャッチャ", "tyattya"],
+    ["だっだ", "ダッダ", "dadda"],
+    ["っゐ", "ッヰ", "ii"],
+    ["っゑ", "ッヱ", "ee"],
+    ["っを", "ッヲ", "oo"],
+    ["ふっふ", "フッフ", "huhhu"],
+    ["がっが", "ガッガ", "gagga"],
+    ["はっは", "ハッハ", "hahha"],
+    ["じゃっじゃ", "ジャッジャ", "zyazzya"],
+    ["かっか", "カッカ", "kakka"],
+    ["まっま", "マッマ", "mamma"],
+    ["なんな", "ナンナ", "nanna"],
+    ["ぱっぱ", "パッパ", "pappa"],
+    ["らっら", "ラッラ", "rarra"],
+    ["さっさ", "サッサ", "sassa"],
+    ["しゃっしゃ", "シャッシャ", "syassya"],
+    ["たった", "タッタ", "tatta"],
+    ["つっつ", "ツッツ", "tuttu"],
+    ["わっわ", "ワッワ", "wawwa"],
+    ["ざっざ", "ザッザ", "zazza"],
+    // hira long vowels
+    ["がっこう", "", "gakkou"],
+    ["とうきょう", "", "toukyou"],
+    ["べんきょう", "", "benkyou"],
+    ["でんぽう", "", "denpou"],
+    ["きんようび", "", "kin'youbi"],
+    ["こうし", "", "kousi"],
+    // kata long vowels
+    ["", "セーラー", "seeraa"],
+    ["", "パーティー", "paateii"],
+    ["", "ヒーター", "hiitaa"],
+    ["", "タクシー", "takusii"],
+    ["", "スーパーマン", "suupaaman"],
+    ["", "バレーボール", "bareebooru"],
+    ["", "ソール", "sooru"],
+];
+
+#[allow(dead_code)]
+pub const HIRA_KATA_TO_NIHON: [[&str; 3]; 195] = [
+    // symbols that should all be the same after conversion
+    ["ヶ", "ヶ", "ヶ"],
+    ["ヵ", "ヵ", "ヵ"],
+    ["1", "1", "1"],
+    ["@", "@", "@"],
+    ["#", "#", "#"],
+    ["$", "$", "$"],
+    ["%", "%", "%"],
+    // quick brown fox / iroha
+    ["いろはにほへと", "イロハニホヘト", "irohanihoheto"],
+    ["ちりぬるを", "チリヌルヲ", "tirinuruwo"],
+    ["わかよたれそ", "ワカヨタレソ", "wakayotareso"],
+    ["つねならむ", "ツネナラム", "tunenaramu"],
+    ["うゐのおくやま", "ウヰノオクヤマ", "uwinookuyama"],
+    ["けふこえて", "ケフコエテ", "kehukoete"],
+    ["あさきゆめみし", "アサキユメミシ", "asakiyumemisi"],
+    ["ゑひもせすん", "ヱヒモセスン", "wehimosesun"],
+    // should all convert
+    ["！", "！", "!"],
+    ["？", "？", "?"],
+    ["。", "。", "."],
+    ["：", "：", ":"],
+    ["・", "・", "/"],
+    ["、", "、", ","],
+    ["〜", "〜", "~"],
+    ["ー", "ー", "-"],
+    ["「", "「", "‘"],
+    ["」", "」", "’"],
+    ["『", "『", "“"],
+    ["』", "』", "”"],
+    ["［", "［", "["],
+    ["］", "］", "]"],
+    ["（", "（", "("],
+    ["）", "）", ")"],
+    ["｛", "｛", "{"],
+    ["｝", "｝", "}"],
+    // nihon-shiki
+    ["か", "カ", "ka"],
+    ["き", "キ", "ki"],
+    ["く", "ク", "ku"],
+    ["け", "ケ", "ke"],
+    ["こ", "コ", "ko"],
+    ["きゃ", "キャ", "kya"],
+    ["きゅ", "キュ", "kyu"],
+    ["きょ", "キョ", "kyo"],
+    ["さ", "サ", "sa"],
+    ["し", "シ", "si"],
+    ["す", "ス", "su"],
+    ["せ", "セ", "se"],
+    ["そ", "ソ", "so"],
+    ["しゃ", "シャ", "sya"],
+    ["しゅ", "シュ", "syu"],
+    ["しょ", "ショ", "syo"],
+    ["た", "タ", "ta"],
+    ["ち", "チ", "ti"],
+    ["つ", "ツ", "tu"],
+    ["て", "テ", "te"],
+    ["と", "ト", "to"],
+    ["ちゃ", "チャ", "tya"],
+    ["ちゅ", "チュ", "tyu"],
+    ["ちょ", "チョ", "tyo"],
+    ["な", "ナ", "na"],
+    ["に", "ニ", "ni"],
+    ["ぬ", "ヌ", "nu"],
+    ["ね", "ネ", "ne"],
+    ["の", "ノ", "no"],
+    ["にゃ", "ニャ", "nya"],
+    ["にゅ", "ニュ", "nyu"],
+    ["にょ", "ニョ", "nyo"],
+    ["は", "ハ", "ha"],
+    ["ひ", "ヒ", "hi"],
+    ["ふ", "フ", "hu"],
+    ["へ", "ヘ", "he"],
+    ["ほ", "ホ", "ho"],
+    ["ひゃ", "ヒャ", "hya"],
+    ["ひゅ", "ヒュ", "hyu"],
+    ["ひょ", "ヒョ", "hyo"],
+    ["ま", "マ", "ma"],
+    ["み", "ミ", "mi"],
+    ["む", "ム", "mu"],
+    ["め", "メ", "me"],
+    ["も", "モ", "mo"],
+    ["みゃ", "ミャ", "mya"],
+    ["みゅ", "ミュ", "myu"],
+    ["みょ", "ミョ", "myo"],
+    ["ら", "ラ", "ra"],
+    ["り", "リ", "ri"],
+    ["る", "ル", "ru"],
+    ["れ", "レ", "re"],
+    ["ろ", "ロ", "ro"],
+    ["りゃ", "リャ", "rya"],
+    ["りゅ", "リュ", "ryu"],
+    ["りょ", "リョ", "ryo"],
+    ["や", "ヤ", "ya"],
+    ["ゆ", "ユ", "yu"],
+    ["よ", "ヨ", "yo"],
+    ["わ", "ワ", "wa"],
+    ["ゐ", "ヰ", "wi"],
+    ["ゑ", "ヱ", "we"],
+    ["を", "ヲ", "wo"],
+    // dakuten
+    ["が", "ガ", "ga"],
+    ["ぎ", "ギ", "gi"],
+    ["ぐ", "グ", "gu"],
+    ["げ", "ゲ", "ge"],
+    ["ご", "ゴ", "go"],
+    ["ぎゃ", "ギャ", "gya"],
+    ["ぎゅ", "ギュ", "gyu"],
+    ["ぎょ", "ギョ", "gyo"],
+    ["ざ", "ザ", "za"],
+    ["じ", "ジ", "zi"],
+    ["ず", "ズ", "zu"],
+    ["ぜ", "ゼ", "ze"],
+    ["ぞ", "ゾ", "zo"],
+    ["じゃ", "ジャ", "zya"],
+    ["じゅ", "ジュ", "zyu"],
+    ["じょ", "ジョ", "zyo"],
+    ["だ", "ダ", "da"],
+    ["ぢ", "ヂ", "di"],
+    ["づ", "ヅ", "du"],
+    ["で", "デ", "de"],
+    ["ど", "ド", "do"],
+    ["ぢゃ", "ヂャ", "dya"],
+    ["ぢゅ", "ヂュ", "dyu"],
+    ["ぢょ", "ヂョ", "dyo"],
+    ["ば", "バ", "ba"],
+    ["び", "ビ", "bi"],
+    ["ぶ", "ブ", "bu"],
+    ["べ", "ベ", "be"],
+    ["ぼ", "ボ", "bo"],
+    ["びゃ", "ビャ", "bya"],
+    ["びゅ", "ビュ", "byu"],
+    ["びょ", "ビョ", "byo"],
+    ["ぱ", "パ", "pa"],
+    ["ぴ", "ピ", "pi"],
+    ["ぷ", "プ", "pu"],
+    ["ぺ", "ペ", "pe"],
+    ["ぽ", "ポ", "po"],
+    ["ぴゃ", "ピャ", "pya"],
+    ["ぴゅ", "ピュ", "pyu"],
+    ["ぴょ", "ピョ", "pyo"],
+    // little kana
+    ["ぁ", "ァ", "a"],
+    ["ぃ", "ィ", "i"],
+    ["ぅ", "ゥ", "u"],
+    ["ぇ", "ェ", "e"],
+    ["ぉ", "ォ", "o"],
+    ["っ", "ッ", ""],
+    ["ゃ", "ャ", "ya"],
+    ["ゅ", "ュ", "yu"],
+    ["ょ", "ョ", "yo"],
+    // n
+    ["ん", "ン", "n"],
+    ["んん", "ンン", "nn"],
+    ["あんない", "アンナイ", "annai"],
+    ["ぐんま", "グンマ", "gunma"],
+    // double consonants
+    ["あった", "アッタ", "atta"],
+    ["がっこうなかった", "ガッコウナカッタ", "gakkounakatta"],
+    ["けっか", "ケッカ", "kekka"],
+    ["さっさと", "サッサト", "sassato"],
+    ["ずっと", "ズット", "zutto"],
+    ["きっぷ", "キップ", "kippu"],
+    ["ざっし", "ザッシ", "zassi"],
+    ["いっしょ", "イッショ", "issyo"],
+    ["こっち", "コッチ", "kotti"],
+    ["まっちゃ", "マッチャ", "mattya"],
+    ["みっつ", "ミッツ", "mittu"],
+    ["ばっば", "バッバ", "babba"],
+    ["かっか", "カッカ", "kakka"],
+    ["ちゃっちゃ", "チャッチャ", "tyattya"],
+    ["だっだ", "ダッダ", "dadda"],
+    ["ふっふ", "フッフ", "huhhu"],
+    ["がっが", "ガッガ", "gagga"],
+    ["はっは", "ハッハ", "hahha"],
+    ["じゃっじゃ", "ジャッジャ", "zyazzya"],
+    ["かっか", "カッカ", "kakka"],
+    ["まっま", "マッマ", "mamma"],
+    ["なんな", "ナンナ", "nanna"],
+    ["ぱっぱ", "パッパ", "pappa"],
+    ["らっら", "ラッラ", "rarra"],
+    ["さっさ", "サッサ", "sassa"],
+    ["しゃっしゃ", "シャッシャ", "syassya"],
+    ["たった", "タッタ", "tatta"],
+    ["つっつ", "ツッツ", "tuttu"],
+    ["わっわ", "ワッワ", "wawwa"],
+    ["ざっざ", "ザッザ", "zazza"],
+    // hira long vowels
+    ["がっこう", "", "gakkou"],
+    ["とうきょう", "", "toukyou"],
+    ["べんきょう", "", "benkyou"],
+    ["でんぽう", "", "denpou"],
+    ["きんようび", "", "kin'youbi"],
+    ["こうし", "", "kousi"],
+    // kata long vowels
+    ["", "セーラー", "seeraa"],
+    ["", "パーティー", "paateii"],
+    ["", "ヒーター", "hiitaa"],
+    ["", "タクシー", "takusii"],
+    ["", "スーパーマン", "suupaaman"],
+    ["", "バレーボール", "bareebooru"],
+    ["", "ソール", "sooru"],
+    // only distinguished by nihon-shiki
+    ["くゎ", "クヮ", "kwa"],
+    ["ぐゎ", "グヮ", "gwa"],
+    ["つづく", "ツヅク", "tuduku"],
+];
+
+#[allow(dead_code)]
+pub const NAMES_TO_PASSPORT: [[&str; 3]; 32] = [
+    // long vowels are dropped
+    ["ようこ", "ヨウコ", "yoko"],
+    ["ゆうき", "ユウキ", "yuki"],
+    ["しょうた", "ショウタ", "shota"],
+    ["こうの", "コウノ", "kono"],
+    ["おおの", "オオノ", "ono"],
+    ["おおた", "オオタ", "ota"],
+    ["ゆう", "ユウ", "yu"],
+    ["ゆうすけ", "ユウスケ", "yusuke"],
+    ["りゅうじ", "リュウジ", "ryuji"],
+    ["", "ジョージ", "joji"],
+    // except "oh" at the end of a name
+    ["さとう", "サトウ", "satoh"],
+    ["いとう", "イトウ", "itoh"],
+    ["かとう", "カトウ", "katoh"],
+    ["りょう", "リョウ", "ryoh"],
+    ["おおの　りょう", "オオノ　リョウ", "ono ryoh"],
+    ["さいとう　ようこ", "サイトウ　ヨウコ", "saitoh yoko"],
+    // a, i and e are kept
+    ["さあや", "サアヤ", "saaya"],
+    ["にいな", "ニイナ", "niina"],
+    ["けいこ", "ケイコ", "keiko"],
+    // ん before b, m and p
+    ["なんば", "ナンバ", "namba"],
+    ["ほんま", "ホンマ", "homma"],
+    ["しゅんぺい", "シュンペイ", "shumpei"],
+    ["かんべ", "カンベ", "kambe"],
+    ["えんどう", "エンドウ", "endoh"],
+    // ん without apostrophe
+    ["じゅんいち", "ジュンイチ", "junichi"],
+    ["しんや", "シンヤ", "shinya"],
+    ["けんいち", "ケンイチ", "kenichi"],
+    ["じゅんや", "ジュンヤ", "junya"],
+    // double consonants
+    ["はっとり", "ハットリ", "hattori"],
+    ["きっかわ", "キッカワ", "kikkawa"],
+    ["えっちゅう", "エッチュウ", "etchu"],
+    ["ほっち", "ホッチ", "hotchi"],
+];
+
+#[allow(dead_code)]
+pub const HIRA_KATA_TO_STRICT: [[&str; 3]; 30] = [
+    // kana which other systems merge
+    ["ぢ", "ヂ", "di"],
+    ["づ", "ヅ", "du"],
+    ["ぢゃ", "ヂャ", "dya"],
+    ["を", "ヲ", "wo"],
+    ["ゐ", "ヰ", "wi"],
+    ["ゑ", "ヱ", "we"],
+    ["ちぢむ", "チヂム", "tidimu"],
+    ["つづく", "ツヅク", "tuduku"],
+    ["ふじさん", "フジサン", "huzisan"],
+    // small kana
+    ["ぁ", "ァ", "xa"],
+    ["ゃ", "ャ", "xya"],
+    ["ゎ", "ヮ", "xwa"],
+    ["っ", "ッ", "xtu"],
+    ["くゎ", "クヮ", "kuxwa"],
+    ["くぃ", "クィ", "kuxi"],
+    ["ふぁ", "ファ", "huxa"],
+    ["ゔぁ", "ヴァ", "va"],
+    ["ゔ", "ヴ", "vu"],
+    // double consonants
+    ["いっしょ", "イッショ", "issyo"],
+    ["まっちゃ", "マッチャ", "mattya"],
+    ["っあ", "ッア", "xtua"],
+    ["っな", "ッナ", "xtuna"],
+    // n
+    ["んあ", "ンア", "n'a"],
+    ["しんよう", "シンヨウ", "sin'you"],
+    ["きんようび", "キンヨウビ", "kin'youbi"],
+    // long vowels are kept as they are
+    ["とうきょう", "トウキョウ", "toukyou"],
+    ["すーぱー", "スーパー", "su-pa-"],
+    ["ぱーてぃー", "パーティー", "pa-texi-"],
+    // full width space converts back to itself
+    ["\u{3000}", "\u{3000}", "\u{3000}"],
+    ["、。", "、。", ",."],
+];
@@ -1,4 +1,4 @@
-use wana_kana::Options;
+use wana_kana::{ImeMode, Options, Romanization};
 
 mod conversion_tables;
 use conversion_tables::*;
@@ -52,6 +52,70 @@ mod tests {
                     }
                 }
             }
+
+            #[test]
+            fn kana_input_to_kunrei() {
+                let options = Options {
+                    romanization: Romanization::Kunrei,
+                    ..Default::default()
+                };
+                for &[hiragana, katakana, romaji] in HIRA_KATA_TO_KUNREI.iter() {
+                    if !hiragana.is_empty() {
+                        assert_eq!(hiragana.to_romaji_with_opt(options.clone()), romaji);
+                    }
+                    if !katakana.is_empty() {
+                        assert_eq!(katakana.to_romaji_with_opt(options.clone()), romaji);
+                    }
+                }
+            }
+
+            #[test]
+            fn names_to_passport() {
+                let options = Options {
+                    romanization: Romanization::Passport,
+                    ..Default::default()
+                };
+                for &[hiragana, katakana, romaji] in NAMES_TO_PASSPORT.iter() {
+                    if !hiragana.is_empty() {
+                        assert_eq!(hiragana.to_romaji_with_opt(options.clone()), romaji);
+                    }
+                    if !katakana.is_empty() {
+                        assert_eq!(katakana.to_romaji_with_opt(options.clone()), romaji);
+                    }
+                }
+            }
+
+            #[test]
+            fn kana_input_to_strict() {
+                let options = Options {
+                    romanization: Romanization::Strict,
+                    upcase_katakana: true,
+                    ..Default::default()
+                };
+                for &[hiragana, katakana, romaji] in HIRA_KATA_TO_STRICT.iter() {
+                    assert_eq!(hiragana.to_romaji_with_opt(options.clone()), romaji);
+                    assert_eq!(
+                        katakana.to_romaji_with_opt(options.clone()),
+                        romaji.to_uppercase()
+                    );
+                }
+            }
+
+            #[test]
+            fn kana_input_to_nihon() {
+                let options = Options {
+                    romanization: Romanization::Nihon,
+                    ..Default::default()
+                };
+                for &[hiragana, katakana, romaji] in HIRA_KATA_TO_NIHON.iter() {
+                    if !hiragana.is_empty() {
+                        assert_eq!(hiragana.to_romaji_with_opt(options.clone()), romaji);
+                    }
+                    if !katakana.is_empty() {
+                        assert_eq!(katakana.to_romaji_with_opt(options.clone()), romaji);
+                    }
+                }
+            }
         }
 
         mod converting_kana_to_kana {
@@ -169,6 +233,40 @@ mod tests {
         }
     }
 
+    mod round_trips {
+        use wana_kana::IsJapaneseStr;
+
+        use super::*;
+
+        fn kana() -> Vec<char> {
+            let mut kana: Vec<char> = ('ぁ'..='ゔ').chain('ァ'..='ヺ').collect();
+            kana.extend([
+                'ー', '、', '。', '・', '　', '「', '」', '〜', 'ゝ', 'ゞ', 'ヽ', 'ヾ',
+            ]);
+            kana
+        }
+
+        #[test]
+        fn every_kana_pair_round_trips() {
+            let kana = kana();
+            for first in kana.iter() {
+                assert!(first.to_string().round_trips(), "{}", first);
+                for second in kana.iter() {
+                    let input = format!("{}{}", first, second);
+                    assert!(input.round_trips(), "{}", input);
+                }
+            }
+        }
+
+        #[test]
+        fn conversion_tables_round_trip() {
+            for &[hiragana, katakana, _] in HIRA_KATA_TO_ROMA.iter() {
+                assert!(hiragana.round_trips(), "{}", hiragana);
+                assert!(katakana.round_trips(), "{}", katakana);
+            }
+        }
+    }
+
     mod case_sensitivity {
 
         use super::*;
@@ -275,4 +373,51 @@ mod tests {
             assert_eq!("ltsb".to_kana(), "ltsb");
         }
     }
+
+    mod no_panics {
+        use super::*;
+
+        fn all_conversions(input: &str) {
+            let options = [
+                Options::default(),
+                Options {
+                    use_obsolete_kana: true,
+                    upcase_katakana: true,
+                    ..Default::default()
+                },
+                Options {
+                    imemode: ImeMode::ToKatakana,
+                    pass_romaji: true,
+                    romanization: Romanization::Strict,
+                    ..Default::default()
+                },
+            ];
+            for options in options {
+                input.to_kana_with_opt(options.clone());
+                input.to_hiragana_with_opt(options.clone());
+                input.to_katakana_with_opt(options.clone());
+                input.to_romaji_with_opt(options.clone());
+                input.to_ukrainian_with_opt(options.clone());
+                let _ = input.try_to_kana(options.clone());
+                let _ = input.try_to_romaji(options);
+            }
+        }
+
+        fn chars() -> Vec<char> {
+            let mut chars: Vec<char> = ('\u{3000}'..='\u{30FF}').collect();
+            chars.extend(" -_'aikmnotuyzAKNOTŌ漢ｶﾞ！".chars());
+            chars
+        }
+
+        #[test]
+        fn every_char_with_long_dash() {
+            let chars = chars();
+            for first in chars.iter() {
+                for second in chars.iter() {
+                    all_conversions(&format!("{}{}ー", first, second));
+                }
+                all_conversions(&format!("ー{}ー", first));
+            }
+        }
+    }
 }
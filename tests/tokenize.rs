@@ -119,4 +119,20 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn keeps_voiced_sound_marks_with_their_kana() {
+        assert_eq!(
+            tokenize("か\u{3099}っこう ハ\u{309A}ン"),
+            vec!["か\u{3099}っこう", " ", "ハ\u{309A}ン"]
+        );
+        assert_eq!(
+            tokenize_detailed("漢字か゛カ゛", false),
+            vec![
+                (TokenType::Kanji, "漢字".to_string()),
+                (TokenType::Hiragana, "か゛".to_string()),
+                (TokenType::Katakana, "カ゛".to_string()),
+            ]
+        );
+    }
 }
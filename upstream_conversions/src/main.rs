@@ -0,0 +1,382 @@
+//! Generates the transition tables in `src/tables` of wana_kana from the JSON files in `data`.
+//!
+//! The JSON files are nested objects with one char per key, the output of a node is stored
+//! under the empty key: `{"k": {"a": {"": "か"}}}`. The variants of the trees (obsolete kana,
+//! IME mode, the romanization systems) are derived from them below.
+//!
+//! Run `cargo run` in this directory after changing the JSON files or the overrides.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::convert::TryFrom;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use serde_json::Value;
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    transitions: BTreeMap<char, Node>,
+    output: Option<String>,
+}
+
+impl Node {
+    fn leaf(output: &str) -> Node {
+        Node {
+            transitions: BTreeMap::new(),
+            output: Some(output.to_string()),
+        }
+    }
+
+    fn from_json(value: &Value) -> Node {
+        let object = value.as_object().expect("nodes are objects");
+        let mut node = Node::default();
+        for (key, value) in object {
+            if key.is_empty() {
+                let output = value.as_str().expect("outputs are strings");
+                node.output = Some(output.to_string());
+                continue;
+            }
+            let mut chars = key.chars();
+            let char = chars.next().unwrap();
+            assert!(chars.next().is_none(), "keys are single chars: {:?}", key);
+            node.transitions.insert(char, Node::from_json(value));
+        }
+        node
+    }
+
+    fn get_mut(&mut self, path: &str) -> &mut Node {
+        path.chars().fold(self, |node, char| {
+            node.transitions
+                .get_mut(&char)
+                .unwrap_or_else(|| panic!("missing {:?}", path))
+        })
+    }
+
+    /// Sets the output for `path`, adding missing nodes on the way.
+    fn insert(&mut self, path: &str, output: &str) {
+        let node = path
+            .chars()
+            .fold(self, |node, char| node.transitions.entry(char).or_default());
+        node.output = Some(output.to_string());
+    }
+
+    fn insert_all(&mut self, overrides: &[(&str, &str)]) {
+        for (path, output) in overrides {
+            self.insert(path, output);
+        }
+    }
+}
+
+fn main() {
+    let data = Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
+    let tables = Path::new(env!("CARGO_MANIFEST_DIR")).join("../src/tables");
+    let read = |name: &str| {
+        let json = fs::read_to_string(data.join(name)).unwrap();
+        Node::from_json(&serde_json::from_str(&json).unwrap())
+    };
+
+    let kana = read("to_kana.json");
+    let mut kana_obsolete = kana.clone();
+    let w = kana_obsolete.get_mut("w");
+    w.transitions.insert('i', Node::leaf("ゐ"));
+    w.transitions.insert('e', Node::leaf("ゑ"));
+    // An IME waits for the next key after "n", "nn" and "n " are ん
+    let mut kana_imemode = kana.clone();
+    let n = kana_imemode.get_mut("n");
+    n.transitions.insert('n', Node::leaf("ん"));
+    n.transitions.insert(' ', Node::leaf("ん"));
+    n.output = None;
+    write_tables(
+        &tables.join("to_kana.rs"),
+        "to_kana.json",
+        &[
+            ("TO_KANA_TRIE", &kana),
+            ("TO_KANA_TRIE_OBSOLETE", &kana_obsolete),
+            ("TO_KANA_TRIE_IMEMODE", &kana_imemode),
+        ],
+    );
+    write_spellings(&tables.join("spellings.rs"), &[&kana, &kana_imemode]);
+
+    let mut romaji = read("to_romaji.json");
+    romaji.insert_all(KATAKANA_WITHOUT_HIRAGANA);
+    let mut kunrei = romaji.clone();
+    kunrei.insert_all(KUNREI_OVERRIDES);
+    let mut nihon = kunrei.clone();
+    nihon.insert_all(NIHON_OVERRIDES);
+    let mut passport = romaji.clone();
+    passport.insert_all(PASSPORT_OVERRIDES);
+    let strict = strict_tree(&nihon);
+    write_tables(
+        &tables.join("to_romaji.rs"),
+        "to_romaji.json",
+        &[
+            ("TO_ROMAJI_TRIE", &romaji),
+            ("TO_ROMAJI_TRIE_KUNREI", &kunrei),
+            ("TO_ROMAJI_TRIE_NIHON", &nihon),
+            ("TO_ROMAJI_TRIE_PASSPORT", &passport),
+            ("TO_ROMAJI_TRIE_STRICT", &strict),
+        ],
+    );
+
+    let ukrainian = read("to_ukrainian.json");
+    write_tables(
+        &tables.join("to_ukrainian.rs"),
+        "to_ukrainian.json",
+        &[("TO_UKRAINIAN_TRIE", &ukrainian)],
+    );
+}
+
+fn strict_tree(nihon: &Node) -> Node {
+    let mut tree = nihon.clone();
+    tree.insert_all(STRICT_OVERRIDES);
+    // ヷ, ヸ, ヹ and ヺ would be read back as ヴァ, ヴィ, ヴェ and ヴォ, so they are kept as they are
+    for (kana, _) in KATAKANA_WITHOUT_HIRAGANA {
+        let char = kana.chars().next().unwrap();
+        tree.transitions.remove(&char);
+    }
+    // Combinations of く and ぐ are written like those of き and ぎ (くゃ => kya)
+    for kana in ["く", "ぐ", "っく", "っぐ"] {
+        tree.get_mut(kana).transitions.clear();
+    }
+    // Only keep っ combinations, which are written as a double consonant. Everything else is
+    // written as "xtu" followed by the next kana.
+    tree.get_mut("っ").transitions.retain(|kana, next| {
+        let mut romaji = next.output.as_deref().unwrap_or("").chars();
+        let is_double_consonant = match (romaji.next(), romaji.next()) {
+            (Some(first), Some(second)) => first == second && !"aeiouy".contains(first),
+            _ => false,
+        };
+        is_double_consonant && *kana != 'ゐ' && *kana != 'ゑ'
+    });
+    tree
+}
+
+/// Writes the trees as flat tables. The transitions leaving a node are a range of one array,
+/// sorted by char, and every transition stores the range of its target and its output.
+fn write_tables(path: &Path, source: &str, trees: &[(&str, &Node)]) {
+    let mut out = String::new();
+    writeln!(
+        out,
+        "//! Generated by upstream_conversions from data/{}, do not edit.\n",
+        source
+    )
+    .unwrap();
+    writeln!(out, "use crate::trie::Trie;").unwrap();
+    for (name, tree) in trees {
+        assert!(tree.output.is_none(), "the root has no output");
+        let (root_len, transitions, outputs) = flatten(tree);
+        writeln!(out, "\npub(crate) static {}: Trie = Trie {{", name).unwrap();
+        writeln!(out, "    root: (0, {}),", root_len).unwrap();
+        writeln!(out, "    transitions: &[").unwrap();
+        for (char, start, end) in transitions {
+            writeln!(out, "        ({:?}, {}, {}),", char, start, end).unwrap();
+        }
+        writeln!(out, "    ],\n    outputs: &[").unwrap();
+        for output in outputs {
+            writeln!(out, "        {:?},", output).unwrap();
+        }
+        writeln!(out, "    ],\n}};").unwrap();
+    }
+    fs::write(path, out).unwrap();
+}
+
+/// Writes every romaji spelling of the kana of the trees, sorted by kana, for the typing matcher
+fn write_spellings(path: &Path, trees: &[&Node]) {
+    let mut spellings = BTreeMap::new();
+    for tree in trees {
+        collect_spellings(tree, &mut String::new(), &mut spellings);
+    }
+    let max_kana_len = spellings.keys().map(|kana| kana.chars().count()).max();
+    let mut out = String::new();
+    writeln!(
+        out,
+        "//! Generated by upstream_conversions from data/to_kana.json, do not edit.\n"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "/// Number of chars of the longest kana in SPELLINGS\npub(crate) const MAX_KANA_LEN: usize = {};",
+        max_kana_len.unwrap_or(0)
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "\n/// Every romaji spelling of the kana of TO_KANA_TRIE, by kana, with \"nn\" of the IME mode for ん"
+    )
+    .unwrap();
+    writeln!(out, "pub(crate) static SPELLINGS: &[(&str, &[&str])] = &[").unwrap();
+    for (kana, kana_spellings) in spellings {
+        writeln!(out, "    ({:?}, &{:?}),", kana, kana_spellings).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    fs::write(path, out).unwrap();
+}
+
+/// Collects the paths leading to every output of `node`. Paths with a space, like "n " of the
+/// IME mode, are left out.
+fn collect_spellings(
+    node: &Node,
+    path: &mut String,
+    spellings: &mut BTreeMap<String, Vec<String>>,
+) {
+    if let Some(kana) = &node.output {
+        if !path.contains(' ') {
+            let kana_spellings = spellings.entry(kana.clone()).or_default();
+            if !kana_spellings.contains(path) {
+                kana_spellings.push(path.clone());
+            }
+        }
+    }
+    for (char, child) in &node.transitions {
+        path.push(*char);
+        collect_spellings(child, path, spellings);
+        path.pop();
+    }
+}
+
+/// Numbers the nodes breadth first, so the transitions of every node are contiguous.
+#[allow(clippy::type_complexity)]
+fn flatten(root: &Node) -> (u16, Vec<(char, u16, u16)>, Vec<Option<&str>>) {
+    let mut targets = Vec::new();
+    let mut queue = VecDeque::from(vec![root]);
+    while let Some(node) = queue.pop_front() {
+        for (char, child) in &node.transitions {
+            targets.push((*char, child));
+            queue.push_back(child);
+        }
+    }
+    let to_u16 = |len: usize| u16::try_from(len).expect("too many transitions");
+    // The transitions of the targets follow those of the root in the same order
+    let mut start = root.transitions.len();
+    let mut transitions = Vec::new();
+    let mut outputs = Vec::new();
+    for (char, node) in targets {
+        let end = start + node.transitions.len();
+        transitions.push((char, to_u16(start), to_u16(end)));
+        outputs.push(node.output.as_deref());
+        start = end;
+    }
+    (to_u16(root.transitions.len()), transitions, outputs)
+}
+
+/// Katakana which are not shifted to hiragana before the lookup
+static KATAKANA_WITHOUT_HIRAGANA: &[(&str, &str)] =
+    &[("ヷ", "va"), ("ヸ", "vi"), ("ヹ", "ve"), ("ヺ", "vo")];
+
+/// Differences of Kunrei-shiki to the Hepburn tree
+static KUNREI_OVERRIDES: &[(&str, &str)] = &[
+    ("し", "si"),
+    ("しゃ", "sya"),
+    ("しゅ", "syu"),
+    ("しょ", "syo"),
+    ("しぃ", "syi"),
+    ("しぇ", "sye"),
+    ("じ", "zi"),
+    ("じゃ", "zya"),
+    ("じゅ", "zyu"),
+    ("じょ", "zyo"),
+    ("じぃ", "zyi"),
+    ("じぇ", "zye"),
+    ("ち", "ti"),
+    ("ちゃ", "tya"),
+    ("ちゅ", "tyu"),
+    ("ちょ", "tyo"),
+    ("ちぃ", "tyi"),
+    ("ちぇ", "tye"),
+    ("つ", "tu"),
+    ("ふ", "hu"),
+    ("ぢ", "zi"),
+    ("ぢゃ", "zya"),
+    ("ぢゅ", "zyu"),
+    ("ぢょ", "zyo"),
+    ("ぢぃ", "zyi"),
+    ("ぢぇ", "zye"),
+    ("ゐ", "i"),
+    ("ゑ", "e"),
+    ("を", "o"),
+    ("っし", "ssi"),
+    ("っしゃ", "ssya"),
+    ("っしゅ", "ssyu"),
+    ("っしょ", "ssyo"),
+    ("っしぃ", "ssyi"),
+    ("っしぇ", "ssye"),
+    ("っじ", "zzi"),
+    ("っじゃ", "zzya"),
+    ("っじゅ", "zzyu"),
+    ("っじょ", "zzyo"),
+    ("っじぃ", "zzyi"),
+    ("っじぇ", "zzye"),
+    ("っち", "tti"),
+    ("っちゃ", "ttya"),
+    ("っちゅ", "ttyu"),
+    ("っちょ", "ttyo"),
+    ("っちぃ", "ttyi"),
+    ("っちぇ", "ttye"),
+    ("っつ", "ttu"),
+    ("っふ", "hhu"),
+    ("っぢ", "zzi"),
+    ("っぢゃ", "zzya"),
+    ("っぢゅ", "zzyu"),
+    ("っぢょ", "zzyo"),
+    ("っぢぃ", "zzyi"),
+    ("っぢぇ", "zzye"),
+    ("っゐ", "ii"),
+    ("っゑ", "ee"),
+    ("っを", "oo"),
+];
+
+/// Differences of Nihon-shiki to the Kunrei-shiki tree
+static NIHON_OVERRIDES: &[(&str, &str)] = &[
+    ("ぢ", "di"),
+    ("ぢゃ", "dya"),
+    ("ぢゅ", "dyu"),
+    ("ぢょ", "dyo"),
+    ("ぢぃ", "dyi"),
+    ("ぢぇ", "dye"),
+    ("づ", "du"),
+    ("ゐ", "wi"),
+    ("ゑ", "we"),
+    ("を", "wo"),
+    ("くゎ", "kwa"),
+    ("ぐゎ", "gwa"),
+    ("っぢ", "ddi"),
+    ("っぢゃ", "ddya"),
+    ("っぢゅ", "ddyu"),
+    ("っぢょ", "ddyo"),
+    ("っぢぃ", "ddyi"),
+    ("っぢぇ", "ddye"),
+    ("っづ", "ddu"),
+    ("っゐ", "wwi"),
+    ("っゑ", "wwe"),
+    ("っを", "wwo"),
+    ("っくゎ", "kkwa"),
+    ("っぐゎ", "ggwa"),
+];
+
+/// Differences of passport Hepburn to the Hepburn tree
+static PASSPORT_OVERRIDES: &[(&str, &str)] = &[
+    ("んあ", "na"),
+    ("んい", "ni"),
+    ("んう", "nu"),
+    ("んえ", "ne"),
+    ("んお", "no"),
+    ("んや", "nya"),
+    ("んゆ", "nyu"),
+    ("んよ", "nyo"),
+];
+
+/// Differences of the reversible strict romanization to the Nihon-shiki tree
+static STRICT_OVERRIDES: &[(&str, &str)] = &[
+    ("\u{3000}", "\u{3000}"),
+    ("ぁ", "xa"),
+    ("ぃ", "xi"),
+    ("ぅ", "xu"),
+    ("ぇ", "xe"),
+    ("ぉ", "xo"),
+    ("ゃ", "xya"),
+    ("ゅ", "xyu"),
+    ("ょ", "xyo"),
+    ("ゎ", "xwa"),
+    ("っ", "xtu"),
+];